@@ -5,6 +5,12 @@ use core::{alloc::Layout, ptr::{dangling_mut, NonNull}};
 use super::{DetailedAllocationError, Allocator};
 
 /// Represents the default allocator taken from the standard Rust library.
+///
+/// # Notes
+///
+/// [`StdAllocator`] keeps the default [`Allocator::usable_size`]. It forwards to
+/// the global allocator, which can be replaced by `#[global_allocator]`, and so
+/// asking the platform's `malloc` about the block size would not be sound.
 #[derive(Clone, Default, Debug)]
 #[repr(C)]
 #[must_use]
@@ -17,6 +17,22 @@ pub struct DetailedAllocationError<T: Sized> {
 #[must_use]
 pub struct AllocationError;
 
+impl core::fmt::Display for AllocationError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("memory allocation failed")
+    }
+}
+
+impl core::error::Error for AllocationError {}
+
+impl<T: Sized> core::fmt::Display for DetailedAllocationError<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("memory allocation failed")
+    }
+}
+
+impl<T: Sized + Debug> core::error::Error for DetailedAllocationError<T> {}
+
 impl<T: Sized> From<DetailedAllocationError<T>> for AllocationError {
     fn from(_: DetailedAllocationError<T>) -> Self {
         AllocationError
@@ -67,6 +83,24 @@ pub unsafe trait Allocator: Default + Clone + Debug + Send + Sync {
         new_layout: Layout,
     ) -> Result<NonNull<u8>, DetailedAllocationError<Self::ErrorDetails>>;
 
+    /// Returns the number of bytes actually usable in a memory block allocated
+    /// with `layout`. Allocators often round sizes up, and this lets callers
+    /// use the slack instead of reallocating prematurely.
+    ///
+    /// The default implementation returns `layout.size()`.
+    ///
+    /// # Notes
+    ///
+    /// Implementors guarantee that a block allocated or resized with `layout`
+    /// can be passed to [`resize`][`Self::resize`] and [`deallocate`][`Self::deallocate`]
+    /// with any layout of the same alignment and size between `layout.size()`
+    /// and the returned value (inclusive). The returned value is never smaller
+    /// than `layout.size()`.
+    #[inline(always)]
+    fn usable_size(&self, layout: Layout) -> usize {
+        layout.size()
+    }
+
     /// Deallocates the memory block pointed to by `ptr`.
     ///
     /// # Safety
@@ -1,6 +1,6 @@
 #![allow(clippy::cast_possible_truncation, clippy::cast_possible_wrap)]
 
-use core::{alloc::Layout, marker::PhantomData, ops::Deref, ptr::NonNull};
+use core::{alloc::Layout, marker::PhantomData, ops::Deref, ptr::NonNull, slice::SliceIndex};
 
 use osom_lib_alloc::Allocator;
 
@@ -135,12 +135,154 @@ where
         unsafe { core::slice::from_raw_parts(self.ptr(), self.len.into()) }
     }
 
+    /// Returns an iterator over `size` items of the [`Array`] at a time,
+    /// starting at the beginning. The chunks do not overlap, and the last
+    /// chunk is shorter if `size` does not divide the length.
+    ///
+    /// # Panics
+    ///
+    /// When `size` is zero, same as [`slice::chunks`].
+    #[inline(always)]
+    pub fn chunks(&self, size: usize) -> core::slice::Chunks<'_, T> {
+        self.as_slice().chunks(size)
+    }
+
+    /// Returns an iterator over all contiguous windows of length `size`
+    /// of the [`Array`]. The windows overlap. If the [`Array`] is shorter
+    /// than `size`, the iterator yields nothing.
+    ///
+    /// # Panics
+    ///
+    /// When `size` is zero, same as [`slice::windows`].
+    #[inline(always)]
+    pub fn windows(&self, size: usize) -> core::slice::Windows<'_, T> {
+        self.as_slice().windows(size)
+    }
+
+    /// Returns an iterator over `size` items of the [`Array`] at a time,
+    /// starting at the beginning. All chunks have exactly `size` items, and
+    /// the remaining items (less than `size`) are available through
+    /// [`ChunksExact::remainder`][`core::slice::ChunksExact::remainder`].
+    ///
+    /// # Notes
+    ///
+    /// Unlike [`chunks`][`Self::chunks`] the compiler knows the size
+    /// of each chunk, which typically results in better code.
+    ///
+    /// # Panics
+    ///
+    /// When `size` is zero, same as [`slice::chunks_exact`].
+    #[inline(always)]
+    pub fn chunks_exact(&self, size: usize) -> core::slice::ChunksExact<'_, T> {
+        self.as_slice().chunks_exact(size)
+    }
+
     /// Converts the [`Array`] into a mutable slice.
     #[inline(always)]
     pub fn as_slice_mut(&mut self) -> &mut [T] {
         unsafe { core::slice::from_raw_parts_mut(self.ptr(), self.len.into()) }
     }
 
+    /// Returns an iterator over the items of the [`Array`].
+    #[inline(always)]
+    pub fn iter(&self) -> core::slice::Iter<'_, T> {
+        self.as_slice().iter()
+    }
+
+    /// Returns an iterator over the items of the [`Array`] that allows
+    /// modifying each value.
+    #[inline(always)]
+    pub fn iter_mut(&mut self) -> core::slice::IterMut<'_, T> {
+        self.as_slice_mut().iter_mut()
+    }
+
+    /// Sorts the [`Array`] in place, without preserving the order of equal
+    /// items. Same as [`slice::sort_unstable`].
+    #[inline(always)]
+    pub fn sort_unstable(&mut self)
+    where
+        T: Ord,
+    {
+        self.as_slice_mut().sort_unstable();
+    }
+
+    /// Sorts the [`Array`] in place with the `compare` function, without
+    /// preserving the order of equal items. Same as [`slice::sort_unstable_by`].
+    #[inline(always)]
+    pub fn sort_unstable_by<F: FnMut(&T, &T) -> core::cmp::Ordering>(&mut self, compare: F) {
+        self.as_slice_mut().sort_unstable_by(compare);
+    }
+
+    /// Sorts the [`Array`] in place by the key extracted with `f`, without
+    /// preserving the order of equal items. Same as [`slice::sort_unstable_by_key`].
+    #[inline(always)]
+    pub fn sort_unstable_by_key<K: Ord, F: FnMut(&T) -> K>(&mut self, f: F) {
+        self.as_slice_mut().sort_unstable_by_key(f);
+    }
+
+    /// Splits the [`Array`] into a slice of `C`-item arrays and a remainder
+    /// slice shorter than `C`, same as the `slice::as_chunks` method.
+    ///
+    /// # Panics
+    ///
+    /// At compile time, when `C` is `0`.
+    #[inline(always)]
+    #[must_use]
+    pub fn as_chunks<const C: usize>(&self) -> (&[[T; C]], &[T]) {
+        const { assert!(C != 0, "Chunk size must be non-zero.") };
+        let slice = self.as_slice();
+        let chunks_len = slice.len() / C;
+        let (head, tail) = slice.split_at(chunks_len * C);
+        // `head` has exactly `chunks_len * C` items, and `[T; C]` has
+        // the same layout as `C` consecutive items of `T`.
+        let chunks = unsafe { core::slice::from_raw_parts(head.as_ptr().cast::<[T; C]>(), chunks_len) };
+        (chunks, tail)
+    }
+
+    /// Returns the index of the first item of the [`Array`] for which `pred`
+    /// returns `false`, assuming it is partitioned so that all items matching
+    /// `pred` come first. Same as [`slice::partition_point`].
+    ///
+    /// # Returns
+    ///
+    /// * `0` if `pred` is `false` for all items
+    /// * the length of the [`Array`] if `pred` is `true` for all items
+    #[inline(always)]
+    #[must_use]
+    pub fn partition_point<P: FnMut(&T) -> bool>(&self, pred: P) -> usize {
+        self.as_slice().partition_point(pred)
+    }
+
+    /// Returns a reference to an item or a sub-slice, depending on the
+    /// type of `index`, same as [`slice::get`].
+    ///
+    /// # Returns
+    ///
+    /// * `Some` if `index` (a position or a range) is within bounds
+    /// * `None` otherwise
+    #[inline(always)]
+    pub fn get<TIndex>(&self, index: TIndex) -> Option<&TIndex::Output>
+    where
+        TIndex: SliceIndex<[T]>,
+    {
+        self.as_slice().get(index)
+    }
+
+    /// Returns a mutable reference to an item or a sub-slice, depending
+    /// on the type of `index`, same as [`slice::get_mut`].
+    ///
+    /// # Returns
+    ///
+    /// * `Some` if `index` (a position or a range) is within bounds
+    /// * `None` otherwise
+    #[inline(always)]
+    pub fn get_mut<TIndex>(&mut self, index: TIndex) -> Option<&mut TIndex::Output>
+    where
+        TIndex: SliceIndex<[T]>,
+    {
+        self.as_slice_mut().get_mut(index)
+    }
+
     #[inline(always)]
     fn ptr(&self) -> *mut T {
         self.data.as_ptr().cast()
@@ -206,6 +348,84 @@ where
     pub fn from_slice(slice: &[T]) -> Result<Self, ArrayConstructionError> {
         Self::from_slice_and_allocator(slice, TAllocator::default())
     }
+
+    /// Creates a new [`Array`] by repeating the items of `self` `n` times.
+    /// The items are cloned.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ArrayConstructionError::ArrayTooLong`] if the resulting length
+    /// exceeds `MAX_LENGTH`. For other cases see [`ArrayConstructionError`].
+    pub fn repeat(&self, n: usize) -> Result<Self, ArrayConstructionError> {
+        let slice = self.as_slice();
+        let Some(total_len) = slice.len().checked_mul(n) else {
+            return Err(ArrayConstructionError::ArrayTooLong);
+        };
+        Self::from_cloned_iter(total_len, (0..n).flat_map(|_| slice.iter()), self.allocator.clone())
+    }
+
+    /// Creates a new [`Array`] consisting of the items of `self` followed
+    /// by the items of `other`. The items are cloned.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ArrayConstructionError::ArrayTooLong`] if the resulting length
+    /// exceeds `MAX_LENGTH`. For other cases see [`ArrayConstructionError`].
+    pub fn concat(&self, other: &[T]) -> Result<Self, ArrayConstructionError> {
+        let slice = self.as_slice();
+        let Some(total_len) = slice.len().checked_add(other.len()) else {
+            return Err(ArrayConstructionError::ArrayTooLong);
+        };
+        Self::from_cloned_iter(total_len, slice.iter().chain(other), self.allocator.clone())
+    }
+
+    /// Allocates a new [`Array`] of `len` items and fills it with clones of
+    /// items produced by `iter`, which has to produce exactly `len` items.
+    fn from_cloned_iter<'a>(
+        len: usize,
+        iter: impl Iterator<Item = &'a T>,
+        allocator: TAllocator,
+    ) -> Result<Self, ArrayConstructionError>
+    where
+        T: 'a,
+    {
+        if len == 0 {
+            return Ok(Self::empty_with_allocator(allocator));
+        }
+
+        if len > Self::MAX_LENGTH {
+            return Err(ArrayConstructionError::ArrayTooLong);
+        }
+
+        let memory = allocator.allocate(Self::layout(len))?;
+        let array = Self {
+            data: memory,
+            len: unsafe { Length::new_unchecked(len as i32) },
+            allocator: allocator,
+            phantom: PhantomData,
+        };
+
+        unsafe {
+            let mut target = array.ptr();
+            for item in iter.take(len) {
+                target.write(item.clone());
+                target = target.add(1);
+            }
+            debug_assert!(target == array.ptr().add(len), "Iterator produced too few items.");
+        }
+
+        Ok(array)
+    }
+
+    /// Tries to clone the [`Array`].
+    ///
+    /// # Errors
+    ///
+    /// For details see [`ArrayConstructionError`].
+    #[inline(always)]
+    pub fn try_clone(&self) -> Result<Self, ArrayConstructionError> {
+        Self::from_slice_and_allocator(self.as_slice(), self.allocator.clone())
+    }
 }
 
 impl<T, TAllocator> Drop for Array<T, TAllocator>
@@ -240,7 +460,65 @@ where
     TAllocator: Allocator,
 {
     fn clone(&self) -> Self {
-        Self::from_slice_and_allocator(self.as_slice(), self.allocator.clone()).unwrap()
+        self.try_clone().expect("Failed to clone the array")
+    }
+}
+
+impl<T, TAllocator1, TAllocator2> PartialEq<Array<T, TAllocator1>> for Array<T, TAllocator2>
+where
+    T: PartialEq,
+    TAllocator1: Allocator,
+    TAllocator2: Allocator,
+{
+    fn eq(&self, other: &Array<T, TAllocator1>) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+impl<T, TAllocator> Eq for Array<T, TAllocator>
+where
+    T: Eq,
+    TAllocator: Allocator,
+{
+}
+
+impl<T, TAllocator1, TAllocator2> PartialOrd<Array<T, TAllocator1>> for Array<T, TAllocator2>
+where
+    T: PartialOrd,
+    TAllocator1: Allocator,
+    TAllocator2: Allocator,
+{
+    fn partial_cmp(&self, other: &Array<T, TAllocator1>) -> Option<core::cmp::Ordering> {
+        self.as_slice().partial_cmp(other.as_slice())
+    }
+}
+
+impl<T, TAllocator> Ord for Array<T, TAllocator>
+where
+    T: Ord,
+    TAllocator: Allocator,
+{
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        self.as_slice().cmp(other.as_slice())
+    }
+}
+
+impl<T, TAllocator> core::hash::Hash for Array<T, TAllocator>
+where
+    T: core::hash::Hash,
+    TAllocator: Allocator,
+{
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.as_slice().hash(state);
+    }
+}
+
+impl<T, TAllocator> core::borrow::Borrow<[T]> for Array<T, TAllocator>
+where
+    TAllocator: Allocator,
+{
+    fn borrow(&self) -> &[T] {
+        self.as_slice()
     }
 }
 
@@ -291,6 +569,24 @@ where
     }
 }
 
+impl<'a, T, TAllocator: Allocator> IntoIterator for &'a Array<T, TAllocator> {
+    type Item = &'a T;
+    type IntoIter = core::slice::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T, TAllocator: Allocator> IntoIterator for &'a mut Array<T, TAllocator> {
+    type Item = &'a mut T;
+    type IntoIter = core::slice::IterMut<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
 #[cfg(feature = "std_alloc")]
 use osom_lib_alloc::StdAllocator;
 
@@ -0,0 +1,276 @@
+//! A module containing the implementation of the bit array data structure.
+#![allow(clippy::cast_possible_truncation, clippy::cast_possible_wrap, clippy::cast_sign_loss)]
+
+use osom_lib_alloc::Allocator;
+use osom_lib_primitives::Length;
+
+use crate::DynamicArray;
+use crate::errors::ArrayConstructionError;
+
+const WORD_BITS: usize = u64::BITS as usize;
+
+/// Represents a growable array of bits, packed into `u64` words
+/// stored in a [`DynamicArray`].
+///
+/// # Notes
+///
+/// Bits past [`len`][`Self::len`] in the last word are always zero,
+/// and so whole-word operations never see garbage.
+#[must_use]
+pub struct BitArray<TAllocator>
+where
+    TAllocator: Allocator,
+{
+    words: DynamicArray<u64, TAllocator>,
+    len: Length,
+}
+
+impl<TAllocator: Allocator> BitArray<TAllocator> {
+    /// Creates a new empty [`BitArray`].
+    #[inline(always)]
+    pub fn new() -> Self {
+        Self::with_allocator(TAllocator::default())
+    }
+
+    /// Creates a new empty [`BitArray`] with the given allocator.
+    #[inline(always)]
+    pub fn with_allocator(allocator: TAllocator) -> Self {
+        Self {
+            words: DynamicArray::with_allocator(allocator),
+            len: Length::ZERO,
+        }
+    }
+
+    /// Creates a new [`BitArray`] with `len` bits, all of them cleared.
+    ///
+    /// # Errors
+    ///
+    /// For details see [`ArrayConstructionError`].
+    #[inline(always)]
+    pub fn with_len(len: Length) -> Result<Self, ArrayConstructionError> {
+        Self::with_len_and_allocator(len, TAllocator::default())
+    }
+
+    /// Creates a new [`BitArray`] with `len` bits, all of them cleared,
+    /// and the given allocator.
+    ///
+    /// # Errors
+    ///
+    /// For details see [`ArrayConstructionError`].
+    pub fn with_len_and_allocator(len: Length, allocator: TAllocator) -> Result<Self, ArrayConstructionError> {
+        let words_count = Self::words_count(len.into());
+        let mut words =
+            DynamicArray::with_capacity_and_allocator(unsafe { Length::new_unchecked(words_count as i32) }, allocator)?;
+        for _ in 0..words_count {
+            words.push(0)?;
+        }
+        Ok(Self { words, len })
+    }
+
+    /// Returns the number of bits in the [`BitArray`].
+    #[inline(always)]
+    pub const fn len(&self) -> Length {
+        self.len
+    }
+
+    /// Returns `true` if the [`BitArray`] has no bits, `false` otherwise.
+    #[inline(always)]
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.len.value() == 0
+    }
+
+    /// Returns a reference to the allocator of the [`BitArray`].
+    #[inline(always)]
+    pub const fn allocator(&self) -> &TAllocator {
+        self.words.allocator()
+    }
+
+    /// Returns the underlying words of the [`BitArray`]. Bit `i` is
+    /// stored in word `i / 64`, at position `i % 64` counted from
+    /// the least significant bit.
+    #[inline(always)]
+    #[must_use]
+    pub fn as_words(&self) -> &[u64] {
+        self.words.as_slice()
+    }
+
+    /// Appends a bit to the end of the [`BitArray`].
+    ///
+    /// # Errors
+    ///
+    /// For details see [`ArrayConstructionError`].
+    pub fn push(&mut self, bit: bool) -> Result<(), ArrayConstructionError> {
+        let index: usize = self.len.into();
+        if index >= Length::MAX {
+            return Err(ArrayConstructionError::ArrayTooLong);
+        }
+
+        if index % WORD_BITS == 0 {
+            self.words.push(0)?;
+        }
+        self.len += 1;
+        if bit {
+            self.set(index);
+        }
+        Ok(())
+    }
+
+    /// Returns the bit at `index`.
+    ///
+    /// # Panics
+    ///
+    /// When `index` is out of bounds.
+    #[inline(always)]
+    #[must_use]
+    pub fn get(&self, index: usize) -> bool {
+        let (word, mask) = self.locate(index);
+        self.words[word] & mask != 0
+    }
+
+    /// Sets the bit at `index` to `1`.
+    ///
+    /// # Panics
+    ///
+    /// When `index` is out of bounds.
+    #[inline(always)]
+    pub fn set(&mut self, index: usize) {
+        let (word, mask) = self.locate(index);
+        self.words.as_slice_mut()[word] |= mask;
+    }
+
+    /// Sets the bit at `index` to `0`.
+    ///
+    /// # Panics
+    ///
+    /// When `index` is out of bounds.
+    #[inline(always)]
+    pub fn clear(&mut self, index: usize) {
+        let (word, mask) = self.locate(index);
+        self.words.as_slice_mut()[word] &= !mask;
+    }
+
+    /// Returns the number of bits set to `1`.
+    #[must_use]
+    pub fn count_ones(&self) -> usize {
+        self.as_words().iter().map(|word| word.count_ones() as usize).sum()
+    }
+
+    /// Returns an iterator over the indexes of all bits set to `1`,
+    /// in ascending order.
+    pub fn iter_ones(&self) -> impl Iterator<Item = usize> + '_ {
+        self.as_words().iter().enumerate().flat_map(|(word_index, word)| {
+            let mut remaining = *word;
+            core::iter::from_fn(move || {
+                if remaining == 0 {
+                    return None;
+                }
+                let bit = remaining.trailing_zeros() as usize;
+                remaining &= remaining - 1;
+                Some(word_index * WORD_BITS + bit)
+            })
+        })
+    }
+
+    /// Replaces the [`BitArray`] with the bitwise AND of itself and `other`.
+    ///
+    /// # Panics
+    ///
+    /// When the lengths of the bit arrays differ.
+    #[inline(always)]
+    pub fn and<TOtherAllocator: Allocator>(&mut self, other: &BitArray<TOtherAllocator>) {
+        self.combine(other, |left, right| left & right);
+    }
+
+    /// Replaces the [`BitArray`] with the bitwise OR of itself and `other`.
+    ///
+    /// # Panics
+    ///
+    /// When the lengths of the bit arrays differ.
+    #[inline(always)]
+    pub fn or<TOtherAllocator: Allocator>(&mut self, other: &BitArray<TOtherAllocator>) {
+        self.combine(other, |left, right| left | right);
+    }
+
+    /// Replaces the [`BitArray`] with the bitwise XOR of itself and `other`.
+    ///
+    /// # Panics
+    ///
+    /// When the lengths of the bit arrays differ.
+    #[inline(always)]
+    pub fn xor<TOtherAllocator: Allocator>(&mut self, other: &BitArray<TOtherAllocator>) {
+        self.combine(other, |left, right| left ^ right);
+    }
+
+    /// Applies `f` to each pair of words. `f` has to map two zero
+    /// bits to zero, so that the unused tail stays cleared.
+    fn combine<TOtherAllocator: Allocator>(&mut self, other: &BitArray<TOtherAllocator>, f: impl Fn(u64, u64) -> u64) {
+        assert!(self.len == other.len, "BitArray lengths differ.");
+        for (left, right) in self.words.as_slice_mut().iter_mut().zip(other.as_words()) {
+            *left = f(*left, *right);
+        }
+    }
+
+    /// Returns the index of the word holding bit `index`, and the mask
+    /// selecting that bit within the word.
+    #[inline(always)]
+    fn locate(&self, index: usize) -> (usize, u64) {
+        assert!(index < self.len.into(), "BitArray index out of bounds.");
+        (index / WORD_BITS, 1 << (index % WORD_BITS))
+    }
+
+    #[inline(always)]
+    const fn words_count(bits: usize) -> usize {
+        bits.div_ceil(WORD_BITS)
+    }
+}
+
+impl<TAllocator: Allocator> Clone for BitArray<TAllocator> {
+    fn clone(&self) -> Self {
+        Self {
+            words: self.words.clone(),
+            len: self.len,
+        }
+    }
+}
+
+impl<TAllocator: Allocator> Default for BitArray<TAllocator> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<TAllocator: Allocator> core::fmt::Debug for BitArray<TAllocator> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("BitArray(")?;
+        for index in 0..self.len.into() {
+            f.write_str(if self.get(index) { "1" } else { "0" })?;
+        }
+        f.write_str(")")
+    }
+}
+
+impl<TAllocator1: Allocator, TAllocator2: Allocator> PartialEq<BitArray<TAllocator1>> for BitArray<TAllocator2> {
+    fn eq(&self, other: &BitArray<TAllocator1>) -> bool {
+        self.len == other.len && self.as_words() == other.as_words()
+    }
+}
+
+impl<TAllocator: Allocator> Eq for BitArray<TAllocator> {}
+
+impl<TAllocator: Allocator> core::hash::Hash for BitArray<TAllocator> {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.len.hash(state);
+        self.as_words().hash(state);
+    }
+}
+
+#[cfg(feature = "std_alloc")]
+use osom_lib_alloc::StdAllocator;
+
+#[cfg(feature = "std_alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std_alloc")))]
+/// Alias for [`BitArray`] with [`StdAllocator`] as the allocator.
+///
+/// This alias is available only if the `std_alloc` feature is enabled.
+pub type StdBitArray = BitArray<StdAllocator>;
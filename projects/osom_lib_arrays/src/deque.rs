@@ -0,0 +1,380 @@
+//! A module containing the implementation of the double-ended queue.
+#![allow(clippy::cast_possible_truncation, clippy::cast_possible_wrap, clippy::cast_sign_loss)]
+
+use core::mem::MaybeUninit;
+
+use osom_lib_alloc::Allocator;
+use osom_lib_primitives::Length;
+
+use crate::{DynamicArray, errors::ArrayConstructionError};
+
+/// A double-ended queue implemented as a growable ring buffer. Similar to
+/// `std::collections::VecDeque` in its nature.
+///
+/// # Notes
+///
+/// Pushing and popping at both ends is amortized O(1). The items are stored
+/// in a single buffer that wraps around, and so they are not necessarily
+/// contiguous in memory. See [`as_slices`][`Self::as_slices`].
+///
+/// The buffer is a [`DynamicArray`] and grows according to its growth
+/// formula. It never shrinks.
+#[must_use]
+pub struct Deque<T, TAllocator>
+where
+    TAllocator: Allocator,
+{
+    /// Always fully "initialized", i.e. its length is equal to its capacity.
+    /// Only `length` items starting at `head` (and wrapping around) are
+    /// actually initialized.
+    buffer: DynamicArray<MaybeUninit<T>, TAllocator>,
+    head: usize,
+    length: Length,
+}
+
+impl<T, TAllocator: Allocator> Deque<T, TAllocator> {
+    pub const MAX_LENGTH: usize = Length::MAX;
+
+    /// Creates a new empty [`Deque`] with the default allocator.
+    #[inline(always)]
+    pub fn new() -> Self {
+        Self::with_allocator(TAllocator::default())
+    }
+
+    /// Creates a new empty [`Deque`] with the given allocator.
+    #[inline(always)]
+    pub fn with_allocator(allocator: TAllocator) -> Self {
+        Self {
+            buffer: DynamicArray::with_allocator(allocator),
+            head: 0,
+            length: Length::ZERO,
+        }
+    }
+
+    /// Creates a new empty [`Deque`] with the given capacity.
+    ///
+    /// # Errors
+    ///
+    /// For details see [`ArrayConstructionError`].
+    #[inline(always)]
+    pub fn with_capacity(capacity: Length) -> Result<Self, ArrayConstructionError> {
+        Self::with_capacity_and_allocator(capacity, TAllocator::default())
+    }
+
+    /// Creates a new empty [`Deque`] with the given capacity and allocator.
+    ///
+    /// # Errors
+    ///
+    /// For details see [`ArrayConstructionError`].
+    pub fn with_capacity_and_allocator(
+        capacity: Length,
+        allocator: TAllocator,
+    ) -> Result<Self, ArrayConstructionError> {
+        let mut buffer = DynamicArray::with_capacity_and_allocator(capacity, allocator)?;
+        unsafe { buffer.set_len(buffer.capacity()) };
+        Ok(Self {
+            buffer,
+            head: 0,
+            length: Length::ZERO,
+        })
+    }
+
+    /// Returns the length of the [`Deque`].
+    #[inline(always)]
+    pub const fn len(&self) -> Length {
+        self.length
+    }
+
+    /// Returns `true` if the [`Deque`] is empty, `false` otherwise.
+    #[inline(always)]
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.length.value() == 0
+    }
+
+    /// Returns the capacity of the [`Deque`].
+    #[inline(always)]
+    pub const fn capacity(&self) -> Length {
+        self.buffer.capacity()
+    }
+
+    /// Returns a reference to the allocator of the [`Deque`].
+    #[inline(always)]
+    pub const fn allocator(&self) -> &TAllocator {
+        self.buffer.allocator()
+    }
+
+    /// Pushes a new element to the end of the [`Deque`].
+    ///
+    /// # Errors
+    ///
+    /// For details see [`ArrayConstructionError`].
+    pub fn push_back(&mut self, value: T) -> Result<(), ArrayConstructionError> {
+        self.grow_if_full()?;
+        let index = self.physical_index(self.length.value() as usize);
+        self.buffer[index].write(value);
+        self.length += 1;
+        Ok(())
+    }
+
+    /// Pushes a new element to the beginning of the [`Deque`].
+    ///
+    /// # Errors
+    ///
+    /// For details see [`ArrayConstructionError`].
+    pub fn push_front(&mut self, value: T) -> Result<(), ArrayConstructionError> {
+        self.grow_if_full()?;
+        self.head = if self.head == 0 {
+            self.buffer_capacity() - 1
+        } else {
+            self.head - 1
+        };
+        self.buffer[self.head].write(value);
+        self.length += 1;
+        Ok(())
+    }
+
+    /// Pops last element from the [`Deque`], decreasing its size.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(T)` if `self.len() > 0`
+    /// * `None` otherwise
+    pub fn pop_back(&mut self) -> Option<T> {
+        if self.is_empty() {
+            return None;
+        }
+
+        self.length -= 1;
+        let index = self.physical_index(self.length.value() as usize);
+        Some(unsafe { self.buffer[index].assume_init_read() })
+    }
+
+    /// Pops first element from the [`Deque`], decreasing its size.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(T)` if `self.len() > 0`
+    /// * `None` otherwise
+    pub fn pop_front(&mut self) -> Option<T> {
+        if self.is_empty() {
+            return None;
+        }
+
+        let value = unsafe { self.buffer[self.head].assume_init_read() };
+        self.head = self.physical_index(1);
+        self.length -= 1;
+        Some(value)
+    }
+
+    /// Returns a reference to the item at `index`, counting from the front.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(&T)` if `index < self.len()`
+    /// * `None` otherwise
+    #[must_use]
+    pub fn get(&self, index: usize) -> Option<&T> {
+        if index >= self.length.value() as usize {
+            return None;
+        }
+
+        let index = self.physical_index(index);
+        Some(unsafe { self.buffer[index].assume_init_ref() })
+    }
+
+    /// Returns a mutable reference to the item at `index`, counting from the front.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(&mut T)` if `index < self.len()`
+    /// * `None` otherwise
+    #[must_use]
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        if index >= self.length.value() as usize {
+            return None;
+        }
+
+        let index = self.physical_index(index);
+        Some(unsafe { self.buffer[index].assume_init_mut() })
+    }
+
+    /// Returns a reference to the first item of the [`Deque`],
+    /// or `None` if it is empty.
+    #[inline(always)]
+    #[must_use]
+    pub fn front(&self) -> Option<&T> {
+        self.get(0)
+    }
+
+    /// Returns a reference to the last item of the [`Deque`],
+    /// or `None` if it is empty.
+    #[inline(always)]
+    #[must_use]
+    pub fn back(&self) -> Option<&T> {
+        let len = self.length.value() as usize;
+        if len == 0 { None } else { self.get(len - 1) }
+    }
+
+    /// Returns the content of the [`Deque`] as two slices. The first
+    /// one starts at the front, and the second one (potentially empty)
+    /// ends at the back. Concatenated, they hold all items in order.
+    #[must_use]
+    pub fn as_slices(&self) -> (&[T], &[T]) {
+        let (first, second) = self.slice_ranges();
+        let ptr = self.buffer.as_ptr().cast::<T>();
+        unsafe {
+            (
+                core::slice::from_raw_parts(ptr.add(first.start), first.len()),
+                core::slice::from_raw_parts(ptr.add(second.start), second.len()),
+            )
+        }
+    }
+
+    /// Mutable variant of [`as_slices`][`Self::as_slices`].
+    #[must_use]
+    pub fn as_mut_slices(&mut self) -> (&mut [T], &mut [T]) {
+        let (first, second) = self.slice_ranges();
+        let ptr = self.buffer.as_mut_ptr().cast::<T>();
+        unsafe {
+            (
+                core::slice::from_raw_parts_mut(ptr.add(first.start), first.len()),
+                core::slice::from_raw_parts_mut(ptr.add(second.start), second.len()),
+            )
+        }
+    }
+
+    /// Returns an iterator over the items of the [`Deque`], from front to back.
+    #[inline(always)]
+    pub fn iter(&self) -> core::iter::Chain<core::slice::Iter<'_, T>, core::slice::Iter<'_, T>> {
+        let (first, second) = self.as_slices();
+        first.iter().chain(second.iter())
+    }
+
+    /// Returns an iterator over the items of the [`Deque`], from front to back,
+    /// that allows modifying each value.
+    #[inline(always)]
+    pub fn iter_mut(&mut self) -> core::iter::Chain<core::slice::IterMut<'_, T>, core::slice::IterMut<'_, T>> {
+        let (first, second) = self.as_mut_slices();
+        first.iter_mut().chain(second.iter_mut())
+    }
+
+    /// Removes all items from the [`Deque`]. The capacity is not changed.
+    pub fn clear(&mut self) {
+        let (first, second) = self.as_mut_slices();
+        let first = core::ptr::from_mut(first);
+        let second = core::ptr::from_mut(second);
+        // Update the state first, in case a destructor panics.
+        self.head = 0;
+        self.length = Length::ZERO;
+        unsafe {
+            core::ptr::drop_in_place(first);
+            core::ptr::drop_in_place(second);
+        }
+    }
+
+    #[inline(always)]
+    const fn buffer_capacity(&self) -> usize {
+        self.buffer.capacity().value() as usize
+    }
+
+    /// Maps an index counted from the front into an index of the buffer.
+    #[inline(always)]
+    const fn physical_index(&self, index: usize) -> usize {
+        let index = self.head + index;
+        let capacity = self.buffer_capacity();
+        if index >= capacity { index - capacity } else { index }
+    }
+
+    /// Returns the buffer ranges of the two contiguous segments.
+    fn slice_ranges(&self) -> (core::ops::Range<usize>, core::ops::Range<usize>) {
+        let len = self.length.value() as usize;
+        let capacity = self.buffer_capacity();
+        if self.head + len <= capacity {
+            (self.head..self.head + len, 0..0)
+        } else {
+            (self.head..capacity, 0..self.head + len - capacity)
+        }
+    }
+
+    fn grow_if_full(&mut self) -> Result<(), ArrayConstructionError> {
+        let old_capacity = self.buffer_capacity();
+        if (self.length.value() as usize) < old_capacity {
+            return Ok(());
+        }
+
+        self.buffer.reserve(1)?;
+        unsafe { self.buffer.set_len(self.buffer.capacity()) };
+        let new_capacity = self.buffer_capacity();
+
+        // If the content wrapped around, move the front segment to the
+        // end of the new buffer, so that the content is continuous again
+        // modulo the new capacity.
+        let len = self.length.value() as usize;
+        if self.head + len > old_capacity {
+            let front_count = old_capacity - self.head;
+            let new_head = new_capacity - front_count;
+            let ptr = self.buffer.as_mut_ptr();
+            unsafe { ptr.add(new_head).copy_from(ptr.add(self.head), front_count) };
+            self.head = new_head;
+        }
+
+        Ok(())
+    }
+}
+
+impl<T, TAllocator: Allocator> Drop for Deque<T, TAllocator> {
+    fn drop(&mut self) {
+        if core::mem::needs_drop::<T>() {
+            self.clear();
+        }
+    }
+}
+
+impl<T, TAllocator: Allocator> Default for Deque<T, TAllocator> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: core::fmt::Debug, TAllocator: Allocator> core::fmt::Debug for Deque<T, TAllocator> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T: PartialEq, TAllocator: Allocator> PartialEq for Deque<T, TAllocator> {
+    fn eq(&self, other: &Self) -> bool {
+        self.length == other.length && self.iter().eq(other.iter())
+    }
+}
+
+impl<T: Eq, TAllocator: Allocator> Eq for Deque<T, TAllocator> {}
+
+impl<'a, T, TAllocator: Allocator> IntoIterator for &'a Deque<T, TAllocator> {
+    type Item = &'a T;
+    type IntoIter = core::iter::Chain<core::slice::Iter<'a, T>, core::slice::Iter<'a, T>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T, TAllocator: Allocator> IntoIterator for &'a mut Deque<T, TAllocator> {
+    type Item = &'a mut T;
+    type IntoIter = core::iter::Chain<core::slice::IterMut<'a, T>, core::slice::IterMut<'a, T>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+#[cfg(feature = "std_alloc")]
+use osom_lib_alloc::StdAllocator;
+
+#[cfg(feature = "std_alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std_alloc")))]
+/// Alias for [`Deque`] with [`StdAllocator`] as the allocator.
+///
+/// This alias is available only if the `std_alloc` feature is enabled.
+pub type StdDeque<T> = Deque<T, StdAllocator>;
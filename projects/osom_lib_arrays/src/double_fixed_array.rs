@@ -62,10 +62,24 @@ impl<T, const N: usize, const M: usize> DoubleFixedArray<T, N, M> {
     /// Returns [`DoubleFixedArray`] as a mutable slice.
     #[inline(always)]
     pub const fn as_mut_slice(&mut self) -> &mut [T] {
-        let real_ptr = self.real_slice().as_mut_ptr();
+        let real_ptr = self.real_slice_mut().as_mut_ptr();
         unsafe { core::slice::from_raw_parts_mut(real_ptr.cast(), self.length.value() as usize) }
     }
 
+    /// Returns an iterator over the items of the [`DoubleFixedArray`],
+    /// across both halves in logical order.
+    #[inline(always)]
+    pub fn iter(&self) -> core::slice::Iter<'_, T> {
+        self.as_slice().iter()
+    }
+
+    /// Returns an iterator over the items of the [`DoubleFixedArray`] that allows
+    /// modifying each value, across both halves in logical order.
+    #[inline(always)]
+    pub fn iter_mut(&mut self) -> core::slice::IterMut<'_, T> {
+        self.as_mut_slice().iter_mut()
+    }
+
     /// Pushes a value to the [`DoubleFixedArray`].
     ///
     /// # Errors
@@ -88,7 +102,7 @@ impl<T, const N: usize, const M: usize> DoubleFixedArray<T, N, M> {
             return Err(OutOfRangeError);
         }
 
-        let real_slice = self.real_slice();
+        let real_slice = self.real_slice_mut();
 
         let mut idx = len as usize;
         for item in other {
@@ -117,12 +131,18 @@ impl<T, const N: usize, const M: usize> DoubleFixedArray<T, N, M> {
         }
     }
 
-    #[allow(clippy::mut_from_ref)]
+    /// Returns both halves as a single slice. The pointer is derived from
+    /// the whole struct, since the slice spans beyond `first_array`.
     #[inline(always)]
-    const fn real_slice(&self) -> &mut [MaybeUninit<T>] {
-        let ptr = core::ptr::from_ref(&self.first_array)
-            .cast::<MaybeUninit<T>>()
-            .cast_mut();
+    const fn real_slice(&self) -> &[MaybeUninit<T>] {
+        let ptr = core::ptr::from_ref(self).cast::<MaybeUninit<T>>();
+        unsafe { core::slice::from_raw_parts(ptr, N + M) }
+    }
+
+    /// Mutable counterpart of [`DoubleFixedArray::real_slice`].
+    #[inline(always)]
+    const fn real_slice_mut(&mut self) -> &mut [MaybeUninit<T>] {
+        let ptr = core::ptr::from_mut(self).cast::<MaybeUninit<T>>();
         unsafe { core::slice::from_raw_parts_mut(ptr, N + M) }
     }
 }
@@ -130,8 +150,9 @@ impl<T, const N: usize, const M: usize> DoubleFixedArray<T, N, M> {
 impl<T, const N: usize, const M: usize> Drop for DoubleFixedArray<T, N, M> {
     fn drop(&mut self) {
         if core::mem::needs_drop::<T>() {
-            let real_slice = self.real_slice();
-            for i in 0..self.length.value() {
+            let len = self.length.value();
+            let real_slice = self.real_slice_mut();
+            for i in 0..len {
                 unsafe {
                     real_slice[i as usize].assume_init_drop();
                 }
@@ -150,11 +171,11 @@ impl<T: Clone, const N: usize, const M: usize> DoubleFixedArray<T, N, M> {
     /// the length of the other array execeeds the capacity of the [`DoubleFixedArray`].
     pub fn extend_from_slice(&mut self, other: &[T]) -> Result<(), OutOfRangeError> {
         let len = self.length.value();
-        if len + other.len() as i32 > (N + M) as i32 {
+        if other.len() > N + M - len as usize {
             return Err(OutOfRangeError);
         }
 
-        let real_slice = self.real_slice();
+        let real_slice = self.real_slice_mut();
 
         let mut idx = len as usize;
         for item in other {
@@ -177,11 +198,11 @@ impl<T: Clone, const N: usize, const M: usize> Clone for DoubleFixedArray<T, N,
 
         let mut idx = 0;
         while idx < N && idx < real_len {
-            first_array[idx].write(unsafe { real_slice[idx].assume_init_read() });
+            first_array[idx].write(unsafe { real_slice[idx].assume_init_ref() }.clone());
             idx += 1;
         }
         while idx < real_len {
-            second_array[idx - N].write(unsafe { real_slice[idx].assume_init_read() });
+            second_array[idx - N].write(unsafe { real_slice[idx].assume_init_ref() }.clone());
             idx += 1;
         }
 
@@ -226,3 +247,94 @@ impl<T, const N: usize, const M: usize> core::ops::DerefMut for DoubleFixedArray
         self.as_mut_slice()
     }
 }
+
+impl<'a, T, const N: usize, const M: usize> IntoIterator for &'a DoubleFixedArray<T, N, M> {
+    type Item = &'a T;
+    type IntoIter = core::slice::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T, const N: usize, const M: usize> IntoIterator for &'a mut DoubleFixedArray<T, N, M> {
+    type Item = &'a mut T;
+    type IntoIter = core::slice::IterMut<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+/// An owning iterator over the items of a [`DoubleFixedArray`], across
+/// both halves in logical order. See [`DoubleFixedArray::into_iter`].
+///
+/// # Notes
+///
+/// Dropping a partially consumed iterator drops the remaining items.
+#[must_use]
+pub struct DoubleFixedArrayIntoIter<T, const N: usize, const M: usize> {
+    /// Its length is zero, the live items are tracked by `start` and `end` instead.
+    array: DoubleFixedArray<T, N, M>,
+    start: usize,
+    end: usize,
+}
+
+impl<T, const N: usize, const M: usize> Iterator for DoubleFixedArrayIntoIter<T, N, M> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.start == self.end {
+            return None;
+        }
+
+        let value = unsafe { self.array.real_slice()[self.start].assume_init_read() };
+        self.start += 1;
+        Some(value)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.end - self.start;
+        (remaining, Some(remaining))
+    }
+}
+
+impl<T, const N: usize, const M: usize> DoubleEndedIterator for DoubleFixedArrayIntoIter<T, N, M> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.start == self.end {
+            return None;
+        }
+
+        self.end -= 1;
+        Some(unsafe { self.array.real_slice()[self.end].assume_init_read() })
+    }
+}
+
+impl<T, const N: usize, const M: usize> ExactSizeIterator for DoubleFixedArrayIntoIter<T, N, M> {}
+
+impl<T, const N: usize, const M: usize> Drop for DoubleFixedArrayIntoIter<T, N, M> {
+    fn drop(&mut self) {
+        if core::mem::needs_drop::<T>() {
+            let real_slice = self.array.real_slice_mut();
+            for item in &mut real_slice[self.start..self.end] {
+                unsafe { item.assume_init_drop() };
+            }
+        }
+    }
+}
+
+impl<T, const N: usize, const M: usize> IntoIterator for DoubleFixedArray<T, N, M> {
+    type Item = T;
+    type IntoIter = DoubleFixedArrayIntoIter<T, N, M>;
+
+    fn into_iter(mut self) -> Self::IntoIter {
+        let end = self.length.value() as usize;
+        // The iterator takes over the ownership of the items.
+        self.length = Length::ZERO;
+        DoubleFixedArrayIntoIter {
+            array: self,
+            start: 0,
+            end,
+        }
+    }
+}
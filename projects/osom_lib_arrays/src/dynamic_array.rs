@@ -1,6 +1,11 @@
 #![allow(clippy::cast_sign_loss, clippy::cast_possible_truncation, clippy::cast_possible_wrap)]
 
-use core::{alloc::Layout, marker::PhantomData, ops::Deref, ptr::NonNull};
+use core::{
+    alloc::Layout,
+    marker::PhantomData,
+    ops::{Bound, Deref, RangeBounds},
+    ptr::NonNull,
+};
 
 use osom_lib_alloc::{AllocationError, Allocator};
 use osom_lib_primitives::Length;
@@ -27,8 +32,15 @@ where
 }
 
 impl<T, TAllocator: Allocator> DynamicArray<T, TAllocator> {
-    const fn grow_formula(current: i32) -> Length {
-        unsafe { Length::new_unchecked((3 * (current / 2)) + 2) }
+    /// Returns the amortized capacity for at least `required` items,
+    /// capped at `MAX_LENGTH`. The caller has to ensure that `required`
+    /// does not exceed `MAX_LENGTH`.
+    const fn grow_formula(required: usize) -> Length {
+        let mut new_capacity = (3 * (required / 2)) + 2;
+        if new_capacity > Self::MAX_LENGTH {
+            new_capacity = Self::MAX_LENGTH;
+        }
+        unsafe { Length::new_unchecked(new_capacity as i32) }
     }
 
     pub const MAX_LENGTH: usize = Length::MAX;
@@ -115,6 +127,48 @@ impl<T, TAllocator: Allocator> DynamicArray<T, TAllocator> {
         unsafe { core::slice::from_raw_parts(ptr, len) }
     }
 
+    /// Returns an iterator over `size` items of the [`DynamicArray`] at a time,
+    /// starting at the beginning. The chunks do not overlap, and the last
+    /// chunk is shorter if `size` does not divide the length.
+    ///
+    /// # Panics
+    ///
+    /// When `size` is zero, same as [`slice::chunks`].
+    #[inline(always)]
+    pub fn chunks(&self, size: usize) -> core::slice::Chunks<'_, T> {
+        self.as_slice().chunks(size)
+    }
+
+    /// Returns an iterator over all contiguous windows of length `size`
+    /// of the [`DynamicArray`]. The windows overlap. If the [`DynamicArray`] is shorter
+    /// than `size`, the iterator yields nothing.
+    ///
+    /// # Panics
+    ///
+    /// When `size` is zero, same as [`slice::windows`].
+    #[inline(always)]
+    pub fn windows(&self, size: usize) -> core::slice::Windows<'_, T> {
+        self.as_slice().windows(size)
+    }
+
+    /// Returns an iterator over `size` items of the [`DynamicArray`] at a time,
+    /// starting at the beginning. All chunks have exactly `size` items, and
+    /// the remaining items (less than `size`) are available through
+    /// [`ChunksExact::remainder`][`core::slice::ChunksExact::remainder`].
+    ///
+    /// # Notes
+    ///
+    /// Unlike [`chunks`][`Self::chunks`] the compiler knows the size
+    /// of each chunk, which typically results in better code.
+    ///
+    /// # Panics
+    ///
+    /// When `size` is zero, same as [`slice::chunks_exact`].
+    #[inline(always)]
+    pub fn chunks_exact(&self, size: usize) -> core::slice::ChunksExact<'_, T> {
+        self.as_slice().chunks_exact(size)
+    }
+
     /// Represents the [`DynamicArray`] as a mutable slice.
     #[inline(always)]
     pub fn as_slice_mut(&mut self) -> &mut [T] {
@@ -125,6 +179,218 @@ impl<T, TAllocator: Allocator> DynamicArray<T, TAllocator> {
         unsafe { core::slice::from_raw_parts_mut(ptr, len) }
     }
 
+    /// Returns an iterator over the items of the [`DynamicArray`].
+    #[inline(always)]
+    pub fn iter(&self) -> core::slice::Iter<'_, T> {
+        self.as_slice().iter()
+    }
+
+    /// Returns an iterator over the items of the [`DynamicArray`] that allows
+    /// modifying each value.
+    #[inline(always)]
+    pub fn iter_mut(&mut self) -> core::slice::IterMut<'_, T> {
+        self.as_slice_mut().iter_mut()
+    }
+
+    /// Sorts the [`DynamicArray`] in place, without preserving the order of equal
+    /// items. Same as [`slice::sort_unstable`].
+    #[inline(always)]
+    pub fn sort_unstable(&mut self)
+    where
+        T: Ord,
+    {
+        self.as_slice_mut().sort_unstable();
+    }
+
+    /// Sorts the [`DynamicArray`] in place with the `compare` function, without
+    /// preserving the order of equal items. Same as [`slice::sort_unstable_by`].
+    #[inline(always)]
+    pub fn sort_unstable_by<F: FnMut(&T, &T) -> core::cmp::Ordering>(&mut self, compare: F) {
+        self.as_slice_mut().sort_unstable_by(compare);
+    }
+
+    /// Sorts the [`DynamicArray`] in place by the key extracted with `f`, without
+    /// preserving the order of equal items. Same as [`slice::sort_unstable_by_key`].
+    #[inline(always)]
+    pub fn sort_unstable_by_key<K: Ord, F: FnMut(&T) -> K>(&mut self, f: F) {
+        self.as_slice_mut().sort_unstable_by_key(f);
+    }
+
+    /// Splits the [`DynamicArray`] into a slice of `C`-item arrays and a remainder
+    /// slice shorter than `C`, same as the `slice::as_chunks` method.
+    ///
+    /// # Panics
+    ///
+    /// At compile time, when `C` is `0`.
+    #[inline(always)]
+    #[must_use]
+    pub fn as_chunks<const C: usize>(&self) -> (&[[T; C]], &[T]) {
+        const { assert!(C != 0, "Chunk size must be non-zero.") };
+        let slice = self.as_slice();
+        let chunks_len = slice.len() / C;
+        let (head, tail) = slice.split_at(chunks_len * C);
+        // `head` has exactly `chunks_len * C` items, and `[T; C]` has
+        // the same layout as `C` consecutive items of `T`.
+        let chunks = unsafe { core::slice::from_raw_parts(head.as_ptr().cast::<[T; C]>(), chunks_len) };
+        (chunks, tail)
+    }
+
+    /// Binary searches the sorted [`DynamicArray`] for an item whose key, extracted
+    /// with `f`, equals `key`. Same as [`slice::binary_search_by_key`].
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(index)` with the position where an item with matching
+    /// key could be inserted, keeping the order, if there is no such item.
+    /// If there are multiple matches, any of them can be returned.
+    #[inline(always)]
+    pub fn binary_search_by_key<B: Ord, F: FnMut(&T) -> B>(&self, key: &B, f: F) -> Result<usize, usize> {
+        self.as_slice().binary_search_by_key(key, f)
+    }
+
+    /// Returns the index of the first item of the [`DynamicArray`] for which `pred`
+    /// returns `false`, assuming it is partitioned so that all items matching
+    /// `pred` come first. Same as [`slice::partition_point`].
+    ///
+    /// # Returns
+    ///
+    /// * `0` if `pred` is `false` for all items
+    /// * the length of the [`DynamicArray`] if `pred` is `true` for all items
+    #[inline(always)]
+    #[must_use]
+    pub fn partition_point<P: FnMut(&T) -> bool>(&self, pred: P) -> usize {
+        self.as_slice().partition_point(pred)
+    }
+
+    /// Copies the items of the [`DynamicArray`] into `dst`, e.g. a buffer
+    /// provided by foreign code. At most `dst.len()` items are copied.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(n)` if all `n` items fit into `dst`
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(required)` with the length of the [`DynamicArray`] if `dst`
+    /// is too small. In that case the first `dst.len()` items are copied anyway.
+    #[inline]
+    pub fn copy_into(&self, dst: &mut [T]) -> Result<usize, usize>
+    where
+        T: Copy,
+    {
+        let source = self.as_slice();
+        let copied = source.len().min(dst.len());
+        dst[..copied].copy_from_slice(&source[..copied]);
+        if copied == source.len() {
+            Ok(copied)
+        } else {
+            Err(source.len())
+        }
+    }
+
+    /// Returns a raw pointer to the [`DynamicArray`] buffer.
+    ///
+    /// # Notes
+    ///
+    /// The pointer is valid for `self.capacity()` items, but only the first
+    /// `self.len()` of them are initialized. It is invalidated by any operation
+    /// that may reallocate the buffer. For an empty [`DynamicArray`] without
+    /// allocated memory this is a dangling, but well aligned, pointer.
+    #[inline(always)]
+    #[must_use]
+    pub fn as_ptr(&self) -> *const T {
+        self.data_ptr()
+    }
+
+    /// Returns a mutable raw pointer to the [`DynamicArray`] buffer.
+    ///
+    /// # Notes
+    ///
+    /// For details see [`as_ptr`][`Self::as_ptr`].
+    #[inline(always)]
+    #[must_use]
+    pub fn as_mut_ptr(&mut self) -> *mut T {
+        self.data_ptr()
+    }
+
+    /// Swaps two items of the [`DynamicArray`].
+    ///
+    /// # Panics
+    ///
+    /// When either `a` or `b` is out of bounds, i.e. not less than `self.len()`.
+    #[inline(always)]
+    pub fn swap(&mut self, a: usize, b: usize) {
+        self.as_slice_mut().swap(a, b);
+    }
+
+    /// Keeps only the items for which `f` returns `true`, and drops the rest.
+    /// The order of kept items is preserved. Same as `Vec::retain`.
+    #[inline(always)]
+    pub fn retain<F: FnMut(&T) -> bool>(&mut self, mut f: F) {
+        self.retain_mut(|item| f(item));
+    }
+
+    /// Same as [`retain`][`Self::retain`], except `f` can modify the items
+    /// during the pass. Same as `Vec::retain_mut`.
+    ///
+    /// # Notes
+    ///
+    /// This is a single O(n) pass. If `f` panics, the items processed so far
+    /// are already filtered, the remaining ones are kept untouched and
+    /// nothing is dropped twice.
+    pub fn retain_mut<F: FnMut(&mut T) -> bool>(&mut self, mut f: F) {
+        /// Restores the [`DynamicArray`] invariants, also when `f` panics.
+        struct Guard<'a, T, TAllocator: Allocator> {
+            array: &'a mut DynamicArray<T, TAllocator>,
+            original_len: usize,
+            processed: usize,
+            deleted: usize,
+        }
+
+        impl<T, TAllocator: Allocator> Drop for Guard<'_, T, TAllocator> {
+            fn drop(&mut self) {
+                let ptr = self.array.data_ptr();
+                unsafe {
+                    if self.deleted > 0 {
+                        ptr.add(self.processed - self.deleted)
+                            .copy_from(ptr.add(self.processed), self.original_len - self.processed);
+                    }
+                    self.array.length = Length::new_unchecked((self.original_len - self.deleted) as i32);
+                }
+            }
+        }
+
+        let original_len = self.length.value() as usize;
+        // Until the guard restores it, the length is zero. So even a leaked
+        // guard cannot cause a double drop.
+        self.length = Length::ZERO;
+        let mut guard = Guard {
+            array: self,
+            original_len,
+            processed: 0,
+            deleted: 0,
+        };
+
+        let ptr = guard.array.data_ptr();
+        while guard.processed < original_len {
+            unsafe {
+                let current = ptr.add(guard.processed);
+                if f(&mut *current) {
+                    if guard.deleted > 0 {
+                        ptr.add(guard.processed - guard.deleted)
+                            .copy_from_nonoverlapping(current, 1);
+                    }
+                    guard.processed += 1;
+                } else {
+                    // Count the item before dropping it, in case its destructor panics.
+                    guard.processed += 1;
+                    guard.deleted += 1;
+                    core::ptr::drop_in_place(current);
+                }
+            }
+        }
+    }
+
     /// Pushes a new element to the end of the [`DynamicArray`].
     ///
     /// # Errors
@@ -145,20 +411,9 @@ impl<T, TAllocator: Allocator> DynamicArray<T, TAllocator> {
             return Ok(());
         }
 
-        if N > Self::MAX_LENGTH {
-            return Err(ArrayConstructionError::ArrayTooLong);
-        }
+        self.reserve(N)?;
 
         let len = self.length.value() as usize;
-        let capacity = self.capacity.value() as usize;
-
-        if len + N > capacity {
-            let missing = len + N - capacity;
-            let at_least = (capacity + missing) as i32;
-            let new_capacity = Self::grow_formula(at_least);
-            self.grow(new_capacity)?;
-        }
-
         let ptr = self.data_ptr();
         unsafe {
             let end_ptr = ptr.add(len);
@@ -170,6 +425,48 @@ impl<T, TAllocator: Allocator> DynamicArray<T, TAllocator> {
         Ok(())
     }
 
+    /// Ensures that the [`DynamicArray`] can hold at least `additional`
+    /// more items without reallocating. Grows the capacity according
+    /// to the amortized growth formula, so it may reserve more than requested.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ArrayConstructionError::ArrayTooLong`] if `self.len() + additional`
+    /// exceeds `MAX_LENGTH`. This check happens before the allocator is called,
+    /// so this error is never caused by the allocator. Otherwise returns
+    /// [`ArrayConstructionError::AllocationError`] if the allocator failed.
+    pub fn reserve(&mut self, additional: usize) -> Result<(), ArrayConstructionError> {
+        let required = self.required_capacity(additional)?;
+        if required > self.capacity.value() as usize {
+            self.grow(Self::grow_formula(required))?;
+        }
+        Ok(())
+    }
+
+    /// Same as [`reserve`][`Self::reserve`] except it reserves exactly
+    /// `additional` more items, without applying the growth formula.
+    ///
+    /// # Errors
+    ///
+    /// For details see [`reserve`][`Self::reserve`].
+    pub fn reserve_exact(&mut self, additional: usize) -> Result<(), ArrayConstructionError> {
+        let required = self.required_capacity(additional)?;
+        if required > self.capacity.value() as usize {
+            self.grow(unsafe { Length::new_unchecked(required as i32) })?;
+        }
+        Ok(())
+    }
+
+    /// Returns `self.len() + additional`, or [`ArrayConstructionError::ArrayTooLong`]
+    /// if that exceeds `MAX_LENGTH`.
+    #[inline(always)]
+    fn required_capacity(&self, additional: usize) -> Result<usize, ArrayConstructionError> {
+        match (self.length.value() as usize).checked_add(additional) {
+            Some(required) if required <= Self::MAX_LENGTH => Ok(required),
+            _ => Err(ArrayConstructionError::ArrayTooLong),
+        }
+    }
+
     /// Pops last element from the [`DynamicArray`],
     /// decreasing its size.
     ///
@@ -202,6 +499,66 @@ impl<T, TAllocator: Allocator> DynamicArray<T, TAllocator> {
         }
     }
 
+    /// Pushes a new element to the beginning of the [`DynamicArray`].
+    ///
+    /// # Notes
+    ///
+    /// This is O(n), since all items have to be shifted by one.
+    ///
+    /// # Errors
+    ///
+    /// For details see [`ArrayConstructionError`].
+    pub fn push_front(&mut self, value: T) -> Result<(), ArrayConstructionError> {
+        self.reserve(1)?;
+
+        let len = self.length.value() as usize;
+        let ptr = self.data_ptr();
+        unsafe {
+            ptr.add(1).copy_from(ptr, len);
+            ptr.write(value);
+        }
+
+        self.length += 1;
+        Ok(())
+    }
+
+    /// Pops first element from the [`DynamicArray`],
+    /// decreasing its size.
+    ///
+    /// # Notes
+    ///
+    /// This is O(n), since all remaining items have to be shifted by one.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(T)` if `self.len() > 0`
+    /// * `None` otherwise
+    pub fn pop_front(&mut self) -> Option<T> {
+        if self.is_empty() {
+            return None;
+        }
+
+        let ptr = self.data_ptr();
+        unsafe {
+            self.length -= 1;
+            let value = ptr.read();
+            ptr.copy_from(ptr.add(1), self.length.into());
+            Some(value)
+        }
+    }
+
+    /// Overrides the length of the [`DynamicArray`] without touching its content.
+    ///
+    /// # Safety
+    ///
+    /// `new_len` has to be at most `self.capacity()` and all items up to
+    /// `new_len` have to be initialized. Items past `new_len` won't be dropped.
+    #[inline(always)]
+    pub(crate) unsafe fn set_len(&mut self, new_len: Length) {
+        debug_assert!(new_len <= self.capacity, "New length exceeds the capacity.");
+        self.length = new_len;
+    }
+
     #[inline(always)]
     fn data_ptr(&self) -> *mut T {
         self.ptr.as_ptr().cast()
@@ -218,7 +575,9 @@ impl<T, TAllocator: Allocator> DynamicArray<T, TAllocator> {
     ///
     /// # Notes
     ///
-    /// Does nothing if the length and capacity are equal.
+    /// Does nothing if the length and capacity are equal. If the
+    /// [`DynamicArray`] is empty, the memory is deallocated and the
+    /// capacity becomes zero, without calling `resize`.
     ///
     /// # Errors
     ///
@@ -228,8 +587,18 @@ impl<T, TAllocator: Allocator> DynamicArray<T, TAllocator> {
             return Ok(());
         }
 
+        let old_layout = Self::layout(self.capacity.into());
+        if self.length == Length::ZERO {
+            unsafe {
+                self.allocator.deallocate(self.ptr, old_layout);
+                self.ptr = self.allocator.dangling::<T>().cast();
+            }
+            self.capacity = Length::ZERO;
+            return Ok(());
+        }
+
         let new_layout = Self::layout(self.length.into());
-        let new_ptr = unsafe { self.allocator.resize(self.ptr, new_layout, new_layout) }?;
+        let new_ptr = unsafe { self.allocator.resize(self.ptr, old_layout, new_layout) }?;
         self.ptr = new_ptr;
         self.capacity = self.length;
         Ok(())
@@ -243,10 +612,10 @@ impl<T, TAllocator: Allocator> DynamicArray<T, TAllocator> {
     pub fn from_array(array: crate::Array<T, TAllocator>) -> Self {
         let moved = unsafe {
             Self {
-                ptr: core::ptr::read(&array.data),
+                ptr: core::ptr::read(&raw const array.data),
                 length: array.len,
                 capacity: array.len,
-                allocator: core::ptr::read(&array.allocator),
+                allocator: core::ptr::read(&raw const array.allocator),
                 phantom: PhantomData,
             }
         };
@@ -270,9 +639,9 @@ impl<T, TAllocator: Allocator> DynamicArray<T, TAllocator> {
 
         let moved = unsafe {
             crate::Array {
-                data: core::ptr::read(&self.ptr),
+                data: core::ptr::read(&raw const self.ptr),
                 len: self.length,
-                allocator: core::ptr::read(&self.allocator),
+                allocator: core::ptr::read(&raw const self.allocator),
                 phantom: PhantomData,
             }
         };
@@ -280,6 +649,93 @@ impl<T, TAllocator: Allocator> DynamicArray<T, TAllocator> {
         Ok(moved)
     }
 
+    /// Converts [`DynamicArray`] into [`ImmutableArray`][`crate::ImmutableArray`].
+    /// Same as [`ImmutableArray::freeze_from`][`crate::ImmutableArray::freeze_from`].
+    ///
+    /// # Notes
+    ///
+    /// The items are moved (not cloned) into a single, freshly allocated
+    /// ref counted buffer. There is no intermediate [`Array`][`crate::Array`].
+    /// On failure the items are dropped together with the [`DynamicArray`].
+    ///
+    /// # Errors
+    ///
+    /// For details see [`ArrayConstructionError`].
+    #[inline(always)]
+    pub fn try_into_immutable(self) -> Result<crate::ImmutableArray<T, TAllocator>, ArrayConstructionError> {
+        crate::ImmutableArray::freeze_from(self)
+    }
+
+    /// Creates a new [`DynamicArray`] with the given allocator and fills it
+    /// with the items produced by the iterator.
+    ///
+    /// # Notes
+    ///
+    /// This is a fallible counterpart of [`FromIterator`], which cannot
+    /// express allocation failures.
+    ///
+    /// # Errors
+    ///
+    /// For details see [`ArrayConstructionError`].
+    pub fn from_iter_in<TIter>(iter: TIter, allocator: TAllocator) -> Result<Self, ArrayConstructionError>
+    where
+        TIter: IntoIterator<Item = T>,
+    {
+        let iter = iter.into_iter();
+        let mut result = Self::with_allocator(allocator);
+        result.reserve_exact(iter.size_hint().0)?;
+        for item in iter {
+            result.push(item)?;
+        }
+        Ok(result)
+    }
+
+    /// Same as [`try_collect_in`][`Self::try_collect_in`] but with the default allocator.
+    ///
+    /// # Errors
+    ///
+    /// For details see [`try_collect_in`][`Self::try_collect_in`].
+    #[inline(always)]
+    pub fn try_collect<TIter, TError>(iter: TIter) -> Result<Result<Self, ArrayConstructionError>, TError>
+    where
+        TIter: IntoIterator<Item = Result<T, TError>>,
+    {
+        Self::try_collect_in(iter, TAllocator::default())
+    }
+
+    /// Collects fallible items into a new [`DynamicArray`] with the given allocator.
+    /// Stops on the first failure, be it an `Err` produced by the iterator
+    /// or an allocation failure.
+    ///
+    /// # Errors
+    ///
+    /// The first failure wins, and the error kinds are kept apart:
+    /// * `Err(TError)` if the iterator produced an error. Items collected
+    ///   so far are dropped.
+    /// * `Ok(Err(ArrayConstructionError))` if the [`DynamicArray`] failed to
+    ///   grow. The iterator is not polled any further.
+    /// * `Ok(Ok(array))` if all items were collected.
+    pub fn try_collect_in<TIter, TError>(
+        iter: TIter,
+        allocator: TAllocator,
+    ) -> Result<Result<Self, ArrayConstructionError>, TError>
+    where
+        TIter: IntoIterator<Item = Result<T, TError>>,
+    {
+        let iter = iter.into_iter();
+        let mut result = Self::with_allocator(allocator);
+        if let Err(error) = result.reserve_exact(iter.size_hint().0) {
+            return Ok(Err(error));
+        }
+
+        for item in iter {
+            if let Err(error) = result.push(item?) {
+                return Ok(Err(error));
+            }
+        }
+        Ok(Ok(result))
+    }
+
     fn grow(&mut self, new_capacity: Length) -> Result<(), AllocationError> {
         assert!(
             new_capacity > self.capacity,
@@ -299,9 +755,26 @@ impl<T, TAllocator: Allocator> DynamicArray<T, TAllocator> {
             "Newly allocated memory is not aligned correctly."
         );
         self.ptr = new_ptr;
-        self.capacity = new_capacity;
+        self.capacity = self.usable_capacity(new_layout, new_capacity);
         Ok(())
     }
+
+    /// Returns the number of items fitting in a memory block allocated with
+    /// `layout`, according to [`Allocator::usable_size`]. This is never smaller
+    /// than `requested`, and is capped at `MAX_LENGTH`.
+    #[inline(always)]
+    fn usable_capacity(&self, layout: Layout, requested: Length) -> Length {
+        if size_of::<T>() == 0 {
+            return requested;
+        }
+
+        let usable_items = self.allocator.usable_size(layout) / size_of::<T>();
+        let usable_items = usable_items.min(Self::MAX_LENGTH);
+        if usable_items <= requested.into() {
+            return requested;
+        }
+        unsafe { Length::new_unchecked(usable_items as i32) }
+    }
 }
 
 impl<T, TAllocator: Allocator> Drop for DynamicArray<T, TAllocator> {
@@ -339,33 +812,82 @@ impl<T: Clone, TAllocator: Allocator> DynamicArray<T, TAllocator> {
             return Ok(());
         }
 
-        if slice_len > Self::MAX_LENGTH {
-            return Err(ArrayConstructionError::ArrayTooLong);
+        self.reserve(slice_len)?;
+
+        let len = self.length.value() as usize;
+        let ptr = self.data_ptr();
+        unsafe {
+            let mut end_ptr = ptr.add(len);
+            for value in slice {
+                end_ptr.write(value.clone());
+                end_ptr = end_ptr.add(1);
+            }
         }
 
+        self.length += slice_len as i32;
+        Ok(())
+    }
+
+    /// Clones the items from `range` and appends them to the end of the [`DynamicArray`].
+    /// Same as `Vec::extend_from_within`.
+    ///
+    /// # Errors
+    ///
+    /// For details see [`ArrayConstructionError`].
+    ///
+    /// # Panics
+    ///
+    /// When `range` is decreasing or goes past `self.len()`.
+    pub fn extend_from_within<TRange: RangeBounds<usize>>(
+        &mut self,
+        range: TRange,
+    ) -> Result<(), ArrayConstructionError> {
         let len = self.length.value() as usize;
-        let capacity = self.capacity.value() as usize;
+        let start = match range.start_bound() {
+            Bound::Included(&start) => start,
+            Bound::Excluded(&start) => start.checked_add(1).expect("Range start overflows usize."),
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(&end) => end.checked_add(1).expect("Range end overflows usize."),
+            Bound::Excluded(&end) => end,
+            Bound::Unbounded => len,
+        };
+        assert!(start <= end, "Range start is greater than range end.");
+        assert!(end <= len, "Range end is out of bounds.");
 
-        if len + slice_len > capacity {
-            let missing = len + slice_len - capacity;
-            let at_least = (capacity + missing) as i32;
-            let new_capacity = Self::grow_formula(at_least);
-            self.grow(new_capacity)?;
+        let count = end - start;
+        if count == 0 {
+            return Ok(());
         }
 
+        // Reserve first, so that the source pointers below stay valid.
+        self.reserve(count)?;
+
         let ptr = self.data_ptr();
         unsafe {
             let mut end_ptr = ptr.add(len);
-            for value in slice {
-                end_ptr.write(value.clone());
+            for index in start..end {
+                end_ptr.write((*ptr.add(index)).clone());
                 end_ptr = end_ptr.add(1);
             }
         }
 
-        self.length += slice_len as i32;
+        self.length += count as i32;
         Ok(())
     }
 
+    /// Overwrites all items of the [`DynamicArray`] with clones of `value`.
+    ///
+    /// # Notes
+    ///
+    /// Only the first `self.len()` items are overwritten, the length
+    /// and the capacity don't change.
+    #[inline(always)]
+    pub fn fill(&mut self, value: T) {
+        self.as_slice_mut().fill(value);
+    }
+
     /// Tries to clone the [`DynamicArray`].
     ///
     /// # Errors
@@ -395,6 +917,66 @@ impl<T: PartialEq, TAllocator1: Allocator, TAllocator2: Allocator> PartialEq<Dyn
 
 impl<T: Eq, TAllocator: Allocator> Eq for DynamicArray<T, TAllocator> {}
 
+impl<T: PartialOrd, TAllocator1: Allocator, TAllocator2: Allocator> PartialOrd<DynamicArray<T, TAllocator1>>
+    for DynamicArray<T, TAllocator2>
+{
+    fn partial_cmp(&self, other: &DynamicArray<T, TAllocator1>) -> Option<core::cmp::Ordering> {
+        self.as_slice().partial_cmp(other.as_slice())
+    }
+}
+
+impl<T: Ord, TAllocator: Allocator> Ord for DynamicArray<T, TAllocator> {
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        self.as_slice().cmp(other.as_slice())
+    }
+}
+
+impl<T, TAllocator: Allocator> core::borrow::Borrow<[T]> for DynamicArray<T, TAllocator> {
+    fn borrow(&self) -> &[T] {
+        self.as_slice()
+    }
+}
+
+impl<T: PartialEq, TAllocator: Allocator> PartialEq<[T]> for DynamicArray<T, TAllocator> {
+    fn eq(&self, other: &[T]) -> bool {
+        self.as_slice() == other
+    }
+}
+
+impl<T: PartialEq, TAllocator: Allocator> PartialEq<&[T]> for DynamicArray<T, TAllocator> {
+    fn eq(&self, other: &&[T]) -> bool {
+        self.as_slice() == *other
+    }
+}
+
+impl<T: PartialEq, TAllocator: Allocator, const N: usize> PartialEq<[T; N]> for DynamicArray<T, TAllocator> {
+    fn eq(&self, other: &[T; N]) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+impl<T: PartialEq, TAllocator: Allocator, const N: usize> PartialEq<&[T; N]> for DynamicArray<T, TAllocator> {
+    fn eq(&self, other: &&[T; N]) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+impl<T: PartialEq, TAllocator: Allocator, const N: usize> PartialEq<crate::FixedArray<T, N>>
+    for DynamicArray<T, TAllocator>
+{
+    fn eq(&self, other: &crate::FixedArray<T, N>) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+impl<T: PartialEq, TAllocator: Allocator, const N: usize> PartialEq<DynamicArray<T, TAllocator>>
+    for crate::FixedArray<T, N>
+{
+    fn eq(&self, other: &DynamicArray<T, TAllocator>) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
 impl<T: core::hash::Hash, TAllocator: Allocator> core::hash::Hash for DynamicArray<T, TAllocator> {
     fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
         self.as_slice().hash(state);
@@ -438,6 +1020,24 @@ impl<T, TAllocator: Allocator> AsRef<[T]> for DynamicArray<T, TAllocator> {
     }
 }
 
+impl<'a, T, TAllocator: Allocator> IntoIterator for &'a DynamicArray<T, TAllocator> {
+    type Item = &'a T;
+    type IntoIter = core::slice::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T, TAllocator: Allocator> IntoIterator for &'a mut DynamicArray<T, TAllocator> {
+    type Item = &'a mut T;
+    type IntoIter = core::slice::IterMut<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
 unsafe impl<T: Send, TAllocator: Allocator> Send for DynamicArray<T, TAllocator> {}
 unsafe impl<T: Sync, TAllocator: Allocator> Sync for DynamicArray<T, TAllocator> {}
 
@@ -474,3 +1074,94 @@ use osom_lib_alloc::StdAllocator;
 ///
 /// This alias is available only if the `std_alloc` feature is enabled.
 pub type StdDynamicArray<T> = DynamicArray<T, StdAllocator>;
+
+#[cfg(feature = "std_alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std_alloc")))]
+impl<T> DynamicArray<T, StdAllocator> {
+    /// Consumes the [`DynamicArray`] and returns a mutable slice over its
+    /// items, which lives as long as the caller wants, including `'static`.
+    /// Same as [`Vec::leak`][`alloc::vec::Vec::leak`].
+    ///
+    /// # Notes
+    ///
+    /// The memory is intentionally leaked: neither the items are dropped,
+    /// nor the buffer (including the unused capacity) is ever deallocated.
+    /// This is available for [`StdAllocator`] only, since it is the only
+    /// allocator guaranteed to keep the memory valid for the rest of the program.
+    #[must_use]
+    pub fn leak<'a>(self) -> &'a mut [T] {
+        let value = core::mem::ManuallyDrop::new(self);
+        unsafe { core::slice::from_raw_parts_mut(value.data_ptr(), value.length.into()) }
+    }
+}
+
+#[cfg(feature = "std_alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std_alloc")))]
+impl<T> From<alloc::vec::Vec<T>> for DynamicArray<T, StdAllocator> {
+    /// Converts [`Vec`][`alloc::vec::Vec`] into [`StdDynamicArray`].
+    ///
+    /// # Notes
+    ///
+    /// Both use the global allocator with the same memory layout, and so
+    /// this conversion is zero-copy: the buffer is moved as is. The only
+    /// exception is when the capacity of the [`Vec`][`alloc::vec::Vec`]
+    /// exceeds `MAX_LENGTH`. Then the items are moved into a new buffer.
+    ///
+    /// # Panics
+    ///
+    /// When the length of the [`Vec`][`alloc::vec::Vec`] exceeds `MAX_LENGTH`,
+    /// or when `T` is zero-sized, which [`DynamicArray`] does not support.
+    fn from(value: alloc::vec::Vec<T>) -> Self {
+        assert!(size_of::<T>() > 0, "DynamicArray does not support zero-sized types.");
+        assert!(value.len() <= Self::MAX_LENGTH, "Vec is too long for DynamicArray.");
+
+        let mut value = core::mem::ManuallyDrop::new(value);
+        let len = value.len();
+        let capacity = value.capacity();
+        if capacity == 0 {
+            return Self::new();
+        }
+
+        if capacity <= Self::MAX_LENGTH {
+            return Self {
+                ptr: unsafe { NonNull::new_unchecked(value.as_mut_ptr().cast()) },
+                length: unsafe { Length::new_unchecked(len as i32) },
+                capacity: unsafe { Length::new_unchecked(capacity as i32) },
+                allocator: StdAllocator,
+                phantom: PhantomData,
+            };
+        }
+
+        let mut result = Self::with_capacity(unsafe { Length::new_unchecked(len as i32) })
+            .expect("Failed to allocate memory for DynamicArray.");
+        unsafe {
+            result.data_ptr().copy_from_nonoverlapping(value.as_ptr(), len);
+            result.length = Length::new_unchecked(len as i32);
+            // The items are moved already, only the buffer has to be freed.
+            value.set_len(0);
+            core::mem::ManuallyDrop::drop(&mut value);
+        }
+        result
+    }
+}
+
+#[cfg(feature = "std_alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std_alloc")))]
+impl<T> From<DynamicArray<T, StdAllocator>> for alloc::vec::Vec<T> {
+    /// Converts [`StdDynamicArray`] into [`Vec`][`alloc::vec::Vec`].
+    ///
+    /// # Notes
+    ///
+    /// Both use the global allocator with the same memory layout, and so
+    /// this conversion is always zero-copy: the buffer is moved as is.
+    fn from(value: DynamicArray<T, StdAllocator>) -> Self {
+        let value = core::mem::ManuallyDrop::new(value);
+        if value.capacity == Length::ZERO {
+            return alloc::vec::Vec::new();
+        }
+
+        let len: usize = value.length.into();
+        let capacity: usize = value.capacity.into();
+        unsafe { alloc::vec::Vec::from_raw_parts(value.data_ptr(), len, capacity) }
+    }
+}
@@ -13,6 +13,30 @@ pub enum ArrayConstructionError {
     ArrayTooLong,
 }
 
+impl ArrayConstructionError {
+    /// Returns `true` if the error was caused by the allocator, `false` otherwise.
+    ///
+    /// # Notes
+    ///
+    /// Only allocation errors may go away when retried.
+    #[inline(always)]
+    #[must_use]
+    pub const fn is_allocation_error(&self) -> bool {
+        matches!(self, ArrayConstructionError::AllocationError)
+    }
+}
+
+impl core::fmt::Display for ArrayConstructionError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            ArrayConstructionError::AllocationError => f.write_str("failed to allocate memory for the array"),
+            ArrayConstructionError::ArrayTooLong => f.write_str("array length exceeds the maximum length"),
+        }
+    }
+}
+
+impl core::error::Error for ArrayConstructionError {}
+
 impl From<AllocationError> for ArrayConstructionError {
     fn from(_: AllocationError) -> Self {
         ArrayConstructionError::AllocationError
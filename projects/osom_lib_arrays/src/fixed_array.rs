@@ -23,6 +23,14 @@ pub(super) const fn uninit_array<T, const N: usize>() -> [MaybeUninit<T>; N] {
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct OutOfRangeError;
 
+impl core::fmt::Display for OutOfRangeError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("fixed array capacity exceeded")
+    }
+}
+
+impl core::error::Error for OutOfRangeError {}
+
 impl<T, const N: usize> FixedArray<T, N> {
     /// Creates a new [`FixedArray`] from a smaller or equal array.
     pub const fn from_array<const M: usize>(array: [T; M]) -> Self {
@@ -34,7 +42,7 @@ impl<T, const N: usize> FixedArray<T, N> {
         let mut new_array = uninit_array();
         let mut idx = 0;
         while idx < M {
-            new_array[idx].write(unsafe { core::ptr::read(&array[idx]) });
+            new_array[idx].write(unsafe { core::ptr::read(&raw const array[idx]) });
             idx += 1;
         }
 
@@ -94,6 +102,111 @@ impl<T, const N: usize> FixedArray<T, N> {
         unsafe { core::slice::from_raw_parts_mut(self.array.as_mut_ptr().cast(), self.length.value() as usize) }
     }
 
+    /// Returns an iterator over the items of the [`FixedArray`].
+    #[inline(always)]
+    pub fn iter(&self) -> core::slice::Iter<'_, T> {
+        self.as_slice().iter()
+    }
+
+    /// Returns an iterator over the items of the [`FixedArray`] that allows
+    /// modifying each value.
+    #[inline(always)]
+    pub fn iter_mut(&mut self) -> core::slice::IterMut<'_, T> {
+        self.as_mut_slice().iter_mut()
+    }
+
+    /// Sorts the [`FixedArray`] in place, without preserving the order of equal
+    /// items. Same as [`slice::sort_unstable`].
+    #[inline(always)]
+    pub fn sort_unstable(&mut self)
+    where
+        T: Ord,
+    {
+        self.as_mut_slice().sort_unstable();
+    }
+
+    /// Sorts the [`FixedArray`] in place with the `compare` function, without
+    /// preserving the order of equal items. Same as [`slice::sort_unstable_by`].
+    #[inline(always)]
+    pub fn sort_unstable_by<F: FnMut(&T, &T) -> core::cmp::Ordering>(&mut self, compare: F) {
+        self.as_mut_slice().sort_unstable_by(compare);
+    }
+
+    /// Sorts the [`FixedArray`] in place by the key extracted with `f`, without
+    /// preserving the order of equal items. Same as [`slice::sort_unstable_by_key`].
+    #[inline(always)]
+    pub fn sort_unstable_by_key<K: Ord, F: FnMut(&T) -> K>(&mut self, f: F) {
+        self.as_mut_slice().sort_unstable_by_key(f);
+    }
+
+    /// Binary searches the sorted [`FixedArray`] for an item whose key, extracted
+    /// with `f`, equals `key`. Same as [`slice::binary_search_by_key`].
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(index)` with the position where an item with matching
+    /// key could be inserted, keeping the order, if there is no such item.
+    /// If there are multiple matches, any of them can be returned.
+    #[inline(always)]
+    pub fn binary_search_by_key<B: Ord, F: FnMut(&T) -> B>(&self, key: &B, f: F) -> Result<usize, usize> {
+        self.as_slice().binary_search_by_key(key, f)
+    }
+
+    /// Returns the index of the first item of the [`FixedArray`] for which `pred`
+    /// returns `false`, assuming it is partitioned so that all items matching
+    /// `pred` come first. Same as [`slice::partition_point`].
+    ///
+    /// # Returns
+    ///
+    /// * `0` if `pred` is `false` for all items
+    /// * the length of the [`FixedArray`] if `pred` is `true` for all items
+    #[inline(always)]
+    #[must_use]
+    pub fn partition_point<P: FnMut(&T) -> bool>(&self, pred: P) -> usize {
+        self.as_slice().partition_point(pred)
+    }
+
+    /// Returns a raw pointer to the [`FixedArray`] buffer. The pointer
+    /// is valid for `N` items, but only the first `self.len()` are initialized.
+    #[inline(always)]
+    #[must_use]
+    pub const fn as_ptr(&self) -> *const T {
+        self.array.as_ptr().cast()
+    }
+
+    /// Returns a mutable raw pointer to the [`FixedArray`] buffer. The pointer
+    /// is valid for `N` items, but only the first `self.len()` are initialized.
+    #[inline(always)]
+    #[must_use]
+    pub const fn as_mut_ptr(&mut self) -> *mut T {
+        self.array.as_mut_ptr().cast()
+    }
+
+    /// Returns the remaining, uninitialized part of the [`FixedArray`],
+    /// i.e. the slots from `self.len()` up to `N`.
+    ///
+    /// # Notes
+    ///
+    /// After writing to those slots call [`set_len`][`Self::set_len`]
+    /// to mark them as initialized.
+    #[inline(always)]
+    pub fn spare_capacity_mut(&mut self) -> &mut [MaybeUninit<T>] {
+        let len = self.length.value() as usize;
+        &mut self.array[len..]
+    }
+
+    /// Overrides the length of the [`FixedArray`] without touching its content.
+    ///
+    /// # Safety
+    ///
+    /// `new_len` has to be at most `N` and all items up to `new_len` have
+    /// to be initialized. Items past `new_len` won't be dropped.
+    #[inline(always)]
+    pub const unsafe fn set_len(&mut self, new_len: Length) {
+        debug_assert!(new_len.value() as usize <= N, "New length exceeds the capacity.");
+        self.length = new_len;
+    }
+
     /// Pushes a value to the [`FixedArray`].
     ///
     /// # Errors
@@ -158,7 +271,7 @@ impl<T: Clone, const N: usize> FixedArray<T, N> {
     /// the length of the other array execeeds the capacity of the [`FixedArray`].
     pub fn extend_from_slice(&mut self, other: &[T]) -> Result<(), OutOfRangeError> {
         let len = self.length.value();
-        if len + other.len() as i32 > N as i32 {
+        if other.len() > N - len as usize {
             return Err(OutOfRangeError);
         }
 
@@ -247,3 +360,21 @@ impl<T, const N: usize> AsMut<[T]> for FixedArray<T, N> {
         self.as_mut_slice()
     }
 }
+
+impl<'a, T, const N: usize> IntoIterator for &'a FixedArray<T, N> {
+    type Item = &'a T;
+    type IntoIter = core::slice::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T, const N: usize> IntoIterator for &'a mut FixedArray<T, N> {
+    type Item = &'a mut T;
+    type IntoIter = core::slice::IterMut<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
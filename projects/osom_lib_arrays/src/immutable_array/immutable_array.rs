@@ -6,9 +6,10 @@ use osom_lib_alloc::Allocator;
 use osom_lib_primitives::Length;
 
 use crate::errors::ArrayConstructionError;
+use crate::{DynamicArray, FixedArray};
 
-use super::ImmutableWeakArray;
 use super::internal_array::{HeapData, InternalArray, MAX_LENGTH};
+use super::{ImmutableArrayBuilder, ImmutableWeakArray};
 
 /// A smart pointer to an immutable array. It tracks both strong and
 /// weak references to the array, and is thread safe.
@@ -22,7 +23,7 @@ pub struct ImmutableArray<T: Sized, TAllocator>
 where
     TAllocator: Allocator,
 {
-    internal: InternalArray<T, TAllocator>,
+    pub(super) internal: InternalArray<T, TAllocator>,
 }
 
 impl<T: Sized, TAllocator: Allocator> From<InternalArray<T, TAllocator>> for ImmutableArray<T, TAllocator> {
@@ -36,6 +37,17 @@ impl<T: Sized, TAllocator: Allocator> ImmutableArray<T, TAllocator> {
     /// It is guaranteed that [`MAX_LENGTH`][`Self::MAX_LENGTH`] is less than [`i32::MAX`].
     pub const MAX_LENGTH: usize = MAX_LENGTH;
 
+    /// Creates a new [`ImmutableArrayBuilder`] with the default allocator.
+    /// This is the same as [`ImmutableArrayBuilder::new`].
+    ///
+    /// # Errors
+    ///
+    /// For details see [`ArrayConstructionError`].
+    #[inline(always)]
+    pub fn builder() -> Result<ImmutableArrayBuilder<T, TAllocator>, ArrayConstructionError> {
+        ImmutableArrayBuilder::new()
+    }
+
     /// Converts the [`ImmutableArray`] into a slice.
     #[inline(always)]
     #[must_use]
@@ -43,10 +55,34 @@ impl<T: Sized, TAllocator: Allocator> ImmutableArray<T, TAllocator> {
         self.internal.as_slice()
     }
 
+    /// Returns an iterator over `size` items of the [`ImmutableArray`] at a time,
+    /// starting at the beginning. The chunks do not overlap, and the last
+    /// chunk is shorter if `size` does not divide the length.
+    ///
+    /// # Panics
+    ///
+    /// When `size` is zero, same as [`slice::chunks`].
+    #[inline(always)]
+    pub fn chunks(&self, size: usize) -> core::slice::Chunks<'_, T> {
+        self.as_slice().chunks(size)
+    }
+
+    /// Returns an iterator over all contiguous windows of length `size`
+    /// of the [`ImmutableArray`]. The windows overlap. If the [`ImmutableArray`] is shorter
+    /// than `size`, the iterator yields nothing.
+    ///
+    /// # Panics
+    ///
+    /// When `size` is zero, same as [`slice::windows`].
+    #[inline(always)]
+    pub fn windows(&self, size: usize) -> core::slice::Windows<'_, T> {
+        self.as_slice().windows(size)
+    }
+
     /// Returns the length of the [`ImmutableArray`].
     #[inline(always)]
     pub const fn len(&self) -> Length {
-        self.internal.len()
+        self.internal.view_len()
     }
 
     /// Returns `true` if the [`ImmutableArray`] is empty, `false` otherwise.
@@ -106,12 +142,77 @@ impl<T: Sized, TAllocator: Allocator> ImmutableArray<T, TAllocator> {
     /// Otherwise, returns `false`. This is different from `==` comparison, which
     /// checks whether the content of two strings is the same, ragardless of whether
     /// they point to the same memory or not.
+    ///
+    /// # Notes
+    ///
+    /// Sub arrays (see [`sub_array`][`Self::sub_array`]) are ref equal only if
+    /// they share the buffer and see exactly the same part of it.
     #[inline(always)]
     #[must_use]
     pub fn ref_equal(left: &Self, right: &Self) -> bool {
-        let left = core::ptr::from_ref(left.internal.heap_data());
-        let right = core::ptr::from_ref(right.internal.heap_data());
-        core::ptr::addr_eq(left, right)
+        left.internal.ref_equal(&right.internal)
+    }
+
+    /// Creates a new [`ImmutableArray`] that sees only `length` items of `instance`,
+    /// starting at `start`. No data is copied, the new instance shares the
+    /// underlying buffer with `instance` and increments the strong counter.
+    ///
+    /// # Panics
+    ///
+    /// When `start + length` exceeds `instance.len()`.
+    pub fn sub_array(instance: &Self, start: Length, length: Length) -> Self {
+        let end = start.value() as usize + length.value() as usize;
+        assert!(
+            end <= instance.len().value() as usize,
+            "Sub array exceeds the length of the ImmutableArray."
+        );
+        instance
+            .internal
+            .heap_data()
+            .strong_counter()
+            .fetch_add(1, Ordering::SeqCst);
+        let internal = unsafe { instance.internal.sub_view(start, length) };
+        Self { internal }
+    }
+
+    /// Returns a sub array of `instance` without `prefix`, if `instance`
+    /// starts with `prefix`. Same as [`slice::strip_prefix`] except that
+    /// no data is copied, see [`sub_array`][`Self::sub_array`].
+    ///
+    /// # Returns
+    ///
+    /// * `Some` with the remaining items if `instance` starts with `prefix`
+    /// * `None` otherwise
+    pub fn strip_prefix(instance: &Self, prefix: &[T]) -> Option<Self>
+    where
+        T: PartialEq,
+    {
+        if !instance.as_slice().starts_with(prefix) {
+            return None;
+        }
+
+        let start = unsafe { Length::new_unchecked(prefix.len() as i32) };
+        Some(Self::sub_array(instance, start, instance.len() - start.value()))
+    }
+
+    /// Returns a sub array of `instance` without `suffix`, if `instance`
+    /// ends with `suffix`. Same as [`slice::strip_suffix`] except that
+    /// no data is copied, see [`sub_array`][`Self::sub_array`].
+    ///
+    /// # Returns
+    ///
+    /// * `Some` with the remaining items if `instance` ends with `suffix`
+    /// * `None` otherwise
+    pub fn strip_suffix(instance: &Self, suffix: &[T]) -> Option<Self>
+    where
+        T: PartialEq,
+    {
+        if !instance.as_slice().ends_with(suffix) {
+            return None;
+        }
+
+        let length = instance.len() - suffix.len() as i32;
+        Some(Self::sub_array(instance, Length::ZERO, length))
     }
 
     pub(crate) fn internal_release(&mut self) -> Option<ImmutableWeakArray<T, TAllocator>> {
@@ -121,7 +222,7 @@ impl<T: Sized, TAllocator: Allocator> ImmutableArray<T, TAllocator> {
             .strong_counter()
             .fetch_sub(1, Ordering::SeqCst);
         if strong_counter == 1 {
-            let internal = unsafe { core::ptr::read(&self.internal) };
+            let internal = unsafe { core::ptr::read(&raw const self.internal) };
             Some(ImmutableWeakArray::from(internal))
         } else {
             None
@@ -164,16 +265,113 @@ impl<T: Sized, TAllocator: Allocator> ImmutableArray<T, TAllocator> {
             core::mem::forget(array);
         }
 
-        {
-            *internal.heap_data_mut().strong_counter_mut().get_mut() = 1;
-            *internal.heap_data_mut().weak_counter_mut().get_mut() = 1;
+        internal.init_counters();
+
+        Ok(Self { internal })
+    }
+
+    /// Constructs a new [`ImmutableArray`] of length `len` with default allocator,
+    /// where the item at position `i` is `f(i)`. Same as [`core::array::from_fn`],
+    /// except the items are written directly into the shared buffer.
+    ///
+    /// # Errors
+    ///
+    /// For details see [`ArrayConstructionError`].
+    #[inline(always)]
+    pub fn try_from_fn<F: FnMut(usize) -> T>(len: usize, f: F) -> Result<Self, ArrayConstructionError> {
+        Self::try_from_fn_with_allocator(len, f, TAllocator::default())
+    }
+
+    /// Constructs a new [`ImmutableArray`] of length `len` with the given allocator,
+    /// where the item at position `i` is `f(i)`.
+    ///
+    /// # Notes
+    ///
+    /// If `f` panics, then the already created items are dropped
+    /// and the buffer is deallocated.
+    ///
+    /// # Errors
+    ///
+    /// For details see [`ArrayConstructionError`].
+    pub fn try_from_fn_with_allocator<F: FnMut(usize) -> T>(
+        len: usize,
+        mut f: F,
+        allocator: TAllocator,
+    ) -> Result<Self, ArrayConstructionError> {
+        if len > Self::MAX_LENGTH {
+            return Err(ArrayConstructionError::ArrayTooLong);
+        }
+
+        let capacity = unsafe { Length::new_unchecked(len as i32) };
+        let mut builder = ImmutableArrayBuilder::with_capacity_and_allocator(capacity, allocator)?;
+        for index in 0..len {
+            builder.push(f(index))?;
+        }
+        Ok(builder.build())
+    }
+
+    /// Freezes the [`DynamicArray`] into a new [`ImmutableArray`]. The items are
+    /// moved (not cloned) into a freshly allocated, ref counted buffer.
+    ///
+    /// # Notes
+    ///
+    /// The new [`ImmutableArray`] reuses the allocator of the passed [`DynamicArray`].
+    /// The old buffer is deallocated.
+    ///
+    /// # Errors
+    ///
+    /// For details see [`ArrayConstructionError`].
+    pub fn freeze_from(mut array: DynamicArray<T, TAllocator>) -> Result<Self, ArrayConstructionError> {
+        let allocator = array.allocator().clone();
+        let result = unsafe { Self::move_from_slice(array.as_slice(), allocator)? };
+        unsafe { array.set_len(Length::ZERO) };
+        Ok(result)
+    }
+
+    /// Bitwise copies the items of the slice into a new [`ImmutableArray`].
+    ///
+    /// # Safety
+    ///
+    /// On success the items are owned by the new [`ImmutableArray`], and so the
+    /// caller has to ensure that they won't be used nor dropped through `slice`.
+    unsafe fn move_from_slice(slice: &[T], allocator: TAllocator) -> Result<Self, ArrayConstructionError> {
+        let slice_len = slice.len();
+        if slice_len > Self::MAX_LENGTH {
+            return Err(ArrayConstructionError::ArrayTooLong);
         }
 
+        let slice_len = unsafe { Length::new_unchecked(slice_len as i32) };
+        let mut internal: InternalArray<T, TAllocator> = InternalArray::allocate(slice_len, slice_len, allocator)?;
+
+        unsafe {
+            let ptr = internal.heap_data_mut().data().as_ptr();
+            debug_assert!(ptr.is_aligned(), "Data pointer is not aligned.");
+            ptr.copy_from_nonoverlapping(slice.as_ptr(), slice_len.into());
+        }
+
+        internal.init_counters();
+
         Ok(Self { internal })
     }
 }
 
 impl<T: Sized + Clone, TAllocator: Allocator> ImmutableArray<T, TAllocator> {
+    /// Clones the content of the [`ImmutableArray`] into a new [`DynamicArray`],
+    /// which can be mutated and later frozen again with [`ImmutableArray::freeze_from`].
+    ///
+    /// # Notes
+    ///
+    /// This is a deep copy, the [`ImmutableArray`] itself is left untouched.
+    ///
+    /// # Errors
+    ///
+    /// For details see [`ArrayConstructionError`].
+    pub fn to_dynamic(&self) -> Result<DynamicArray<T, TAllocator>, ArrayConstructionError> {
+        let mut result = DynamicArray::with_capacity_and_allocator(self.len(), self.allocator().clone())?;
+        result.extend_from_slice(self.as_slice())?;
+        Ok(result)
+    }
+
     /// Constructs a new [`ImmutableArray`] from a slice with default allocator.
     /// It clones the slice into the new [`ImmutableArray`].
     ///
@@ -210,10 +408,7 @@ impl<T: Sized + Clone, TAllocator: Allocator> ImmutableArray<T, TAllocator> {
             }
         }
 
-        {
-            *internal.heap_data_mut().strong_counter_mut().get_mut() = 1;
-            *internal.heap_data_mut().weak_counter_mut().get_mut() = 1;
-        }
+        internal.init_counters();
 
         Ok(Self { internal })
     }
@@ -237,22 +432,61 @@ impl<T: Sized, TAllocator: Allocator> Clone for ImmutableArray<T, TAllocator> {
     }
 }
 
+/// # Notes
+///
+/// If both instances see exactly the same memory, e.g. one is a clone of
+/// the other, then they are considered equal without comparing items.
+/// As a consequence an array is always equal to its clones, even if it
+/// contains items that are not equal to themselves (like `f64::NAN`).
 impl<T: Sized + PartialEq, TAllocator1: Allocator, TAllocator2: Allocator> PartialEq<ImmutableArray<T, TAllocator1>>
     for ImmutableArray<T, TAllocator2>
 {
     fn eq(&self, other: &ImmutableArray<T, TAllocator1>) -> bool {
-        self.as_slice() == other.as_slice()
+        let left = self.as_slice();
+        let right = other.as_slice();
+        core::ptr::eq(left, right) || left == right
     }
 }
 
 impl<T: Sized + Eq, TAllocator: Allocator> Eq for ImmutableArray<T, TAllocator> {}
 
+impl<T: Sized + PartialEq, TAllocator: Allocator> PartialEq<[T]> for ImmutableArray<T, TAllocator> {
+    fn eq(&self, other: &[T]) -> bool {
+        self.as_slice() == other
+    }
+}
+
+impl<T: Sized + PartialEq, TAllocator: Allocator> PartialEq<&[T]> for ImmutableArray<T, TAllocator> {
+    fn eq(&self, other: &&[T]) -> bool {
+        self.as_slice() == *other
+    }
+}
+
+impl<T: Sized + PartialEq, TAllocator: Allocator, const N: usize> PartialEq<[T; N]> for ImmutableArray<T, TAllocator> {
+    fn eq(&self, other: &[T; N]) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+impl<T: Sized + PartialEq, TAllocator: Allocator, const N: usize> PartialEq<&[T; N]> for ImmutableArray<T, TAllocator> {
+    fn eq(&self, other: &&[T; N]) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
 impl<T: Sized + core::hash::Hash, TAllocator: Allocator> core::hash::Hash for ImmutableArray<T, TAllocator> {
     fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        // Has to be the same as hashing `[T]`, because of the `Borrow<[T]>` implementation.
         self.as_slice().hash(state);
     }
 }
 
+impl<T: Sized, TAllocator: Allocator> core::borrow::Borrow<[T]> for ImmutableArray<T, TAllocator> {
+    fn borrow(&self) -> &[T] {
+        self.as_slice()
+    }
+}
+
 impl<T: Sized, TAllocator: Allocator> core::fmt::Debug for ImmutableArray<T, TAllocator> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         let ptr = core::ptr::from_ref::<HeapData<T>>(self.internal.heap_data());
@@ -280,6 +514,28 @@ impl<T: Sized, TAllocator: Allocator> AsRef<[T]> for ImmutableArray<T, TAllocato
     }
 }
 
+impl<T, const N: usize, TAllocator: Allocator> TryFrom<FixedArray<T, N>> for ImmutableArray<T, TAllocator> {
+    type Error = ArrayConstructionError;
+
+    /// Moves the items of the [`FixedArray`] into a new [`ImmutableArray`]
+    /// with the default allocator. Items are not cloned.
+    fn try_from(mut value: FixedArray<T, N>) -> Result<Self, Self::Error> {
+        let result = unsafe { Self::move_from_slice(value.as_slice(), TAllocator::default())? };
+        unsafe { value.set_len(Length::ZERO) };
+        Ok(result)
+    }
+}
+
+impl<T, TAllocator: Allocator> TryFrom<DynamicArray<T, TAllocator>> for ImmutableArray<T, TAllocator> {
+    type Error = ArrayConstructionError;
+
+    /// Same as [`ImmutableArray::freeze_from`].
+    #[inline(always)]
+    fn try_from(value: DynamicArray<T, TAllocator>) -> Result<Self, Self::Error> {
+        Self::freeze_from(value)
+    }
+}
+
 #[cfg(feature = "std_alloc")]
 use osom_lib_alloc::StdAllocator;
 
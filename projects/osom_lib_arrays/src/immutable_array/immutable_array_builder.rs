@@ -65,8 +65,9 @@ impl<T: Sized, TAllocator: Allocator> ImmutableArrayBuilder<T, TAllocator> {
     /// ownership from mutable builder to immutable array.
     #[inline(always)]
     pub fn build(self) -> ImmutableArray<T, TAllocator> {
-        let internal = unsafe { core::ptr::read(&self.internal) };
+        let mut internal = unsafe { core::ptr::read(&raw const self.internal) };
         core::mem::forget(self);
+        internal.init_counters();
         ImmutableArray::from(internal)
     }
 
@@ -91,6 +92,21 @@ impl<T: Sized, TAllocator: Allocator> ImmutableArrayBuilder<T, TAllocator> {
         Ok(Self { internal })
     }
 
+    /// Creates a new builder with the specified allocator, able to hold
+    /// `capacity` items without reallocating.
+    ///
+    /// # Errors
+    ///
+    /// For details see [`ArrayConstructionError`].
+    #[inline(always)]
+    pub(super) fn with_capacity_and_allocator(
+        capacity: Length,
+        allocator: TAllocator,
+    ) -> Result<Self, ArrayConstructionError> {
+        let internal = InternalArray::allocate(Length::ZERO, capacity, allocator)?;
+        Ok(Self { internal })
+    }
+
     /// Pushes a new value to the end of the builder.
     ///
     /// # Errors
@@ -131,7 +147,41 @@ impl<T: Sized, TAllocator: Allocator> ImmutableArrayBuilder<T, TAllocator> {
             end_ptr.copy_from_nonoverlapping(values.as_ptr(), N);
         }
         core::mem::forget(values);
-        *internal.len_mut() += N as i32;
+        internal.set_len(internal.len() + N as i32);
+        Ok(())
+    }
+
+    /// Extends the builder with the items produced by the iterator.
+    ///
+    /// # Notes
+    ///
+    /// Memory for the lower bound of the iterator's `size_hint` is
+    /// reserved upfront. Items pushed before a failure stay in the builder.
+    ///
+    /// # Errors
+    ///
+    /// For details see [`ArrayConstructionError`].
+    pub fn try_extend<TIter>(&mut self, iter: TIter) -> Result<(), ArrayConstructionError>
+    where
+        TIter: IntoIterator<Item = T>,
+    {
+        let iter = iter.into_iter();
+        self.reserve_exact(iter.size_hint().0)?;
+        for item in iter {
+            self.push(item)?;
+        }
+        Ok(())
+    }
+
+    fn reserve_exact(&mut self, additional: usize) -> Result<(), ArrayConstructionError> {
+        let internal = &mut self.internal;
+        let required = match (internal.len().value() as usize).checked_add(additional) {
+            Some(required) if required <= MAX_LENGTH => required,
+            _ => return Err(ArrayConstructionError::ArrayTooLong),
+        };
+        if required > internal.capacity().value() as usize {
+            internal.grow(unsafe { Length::new_unchecked(required as i32) })?;
+        }
         Ok(())
     }
 
@@ -150,7 +200,8 @@ impl<T: Sized, TAllocator: Allocator> Drop for ImmutableArrayBuilder<T, TAllocat
     fn drop(&mut self) {
         // We still need drop, in case someone crates builder but does not actually
         // call `build` method. Note that the `build` method disables drop.
-        let internal = unsafe { core::ptr::read(&self.internal) };
+        let mut internal = unsafe { core::ptr::read(&raw const self.internal) };
+        internal.init_counters();
         let _ = ImmutableWeakArray::from(internal);
     }
 }
@@ -194,7 +245,7 @@ impl<T: Sized + Clone, TAllocator: Allocator> ImmutableArrayBuilder<T, TAllocato
                 end_ptr = end_ptr.add(1);
             }
         }
-        *internal.len_mut() += slice_len as i32;
+        internal.set_len(internal.len() + slice_len as i32);
         Ok(())
     }
 }
@@ -46,6 +46,22 @@ impl<T: Sized, TAllocator: Allocator> ImmutableWeakArray<T, TAllocator> {
         }
     }
 
+    /// Temporarily upgrades the weak reference and calls `f` with the content
+    /// of the array. The strong reference is released right after `f` returns.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(R)` with the result of `f` if the array is still alive
+    /// * `None` if the array has been deallocated, `f` is not called then
+    #[inline]
+    pub fn with_upgraded<R, F>(&self, f: F) -> Option<R>
+    where
+        F: FnOnce(&[T]) -> R,
+    {
+        let strong = self.upgrade()?;
+        Some(f(strong.as_slice()))
+    }
+
     /// Returns the number of strong references to the string.
     #[must_use]
     pub fn strong_count(&self) -> usize {
@@ -58,6 +74,29 @@ impl<T: Sized, TAllocator: Allocator> ImmutableWeakArray<T, TAllocator> {
         self.internal.heap_data().weak_counter().load(Ordering::SeqCst) as usize
     }
 
+    /// Returns `true` if the two [`ImmutableWeakArray`] instances refer to the same
+    /// memory. Otherwise, returns `false`. This is the weak counterpart of
+    /// [`ImmutableArray::ref_equal`] and works even if the array is no longer alive.
+    ///
+    /// # Notes
+    ///
+    /// Weak references to sub arrays are equal only if they share the buffer
+    /// and see exactly the same part of it.
+    #[inline(always)]
+    #[must_use]
+    pub fn ptr_eq(left: &Self, right: &Self) -> bool {
+        left.internal.ref_equal(&right.internal)
+    }
+
+    /// Returns `true` if `weak` is a weak reference to the same memory `strong` refers
+    /// to, i.e. `weak.upgrade()` would be [`ImmutableArray::ref_equal`] to `strong`.
+    /// Otherwise, returns `false`.
+    #[inline(always)]
+    #[must_use]
+    pub fn ref_equal(weak: &Self, strong: &ImmutableArray<T, TAllocator>) -> bool {
+        weak.internal.ref_equal(&strong.internal)
+    }
+
     /// Returns a reference to the allocator of the [`ImmutableWeakArray`].
     #[inline(always)]
     pub const fn allocator(&self) -> &TAllocator {
@@ -79,7 +118,7 @@ impl<T: Sized, TAllocator: Allocator> ImmutableWeakArray<T, TAllocator> {
         let weak_counter = self.internal.heap_data().weak_counter().fetch_sub(1, Ordering::SeqCst);
         if weak_counter == 1 {
             if core::mem::needs_drop::<T>() {
-                let slice = self.internal.as_slice_mut();
+                let slice = self.internal.full_slice_mut();
                 let mut start = slice.as_mut_ptr();
                 let end = unsafe { start.add(slice.len()) };
                 while start < end {
@@ -90,7 +129,7 @@ impl<T: Sized, TAllocator: Allocator> ImmutableWeakArray<T, TAllocator> {
                 }
             }
 
-            let internal = unsafe { core::ptr::read(&self.internal) };
+            let internal = unsafe { core::ptr::read(&raw const self.internal) };
             internal.deallocate();
             true
         } else {
@@ -126,7 +165,7 @@ impl<T: Sized, TAllocator: Allocator> core::fmt::Debug for ImmutableWeakArray<T,
         f.debug_struct("ImmutableWeakArray")
             .field("strong_count", &self.strong_count())
             .field("weak_count", &self.weak_count())
-            .field("len", &self.internal.len())
+            .field("len", &self.internal.view_len())
             .field("capacity", &self.internal.capacity())
             .field("raw_ptr", &ptr.addr())
             .finish()
@@ -140,12 +140,19 @@ pub(crate) struct InternalArray<T: Sized, TAllocator: Allocator> {
     /// The pointer to the [`HeapData`] struct that holds atomic counters and the actual `[u8]` data.
     data: NonNull<u8>,
 
-    /// The length of the array.
+    /// The length of the array, i.e. the number of initialized items in the buffer.
     length: Length,
 
     /// The capacity of the array. This field will be used by dynamic array builders.
     capacity: Length,
 
+    /// The start of the visible part of the array. Non-zero only for sub views.
+    view_start: Length,
+
+    /// The length of the visible part of the array. Equal to `length` unless
+    /// this is a sub view.
+    view_length: Length,
+
     /// The allocator used to allocate the [`HeapData`] struct.
     allocator: TAllocator,
 
@@ -169,6 +176,8 @@ impl<T: Sized, TAllocator: Allocator> InternalArray<T, TAllocator> {
             data: new_memory,
             length,
             capacity: capacity,
+            view_start: Length::ZERO,
+            view_length: length,
             allocator: allocator,
             phantom: PhantomData,
         })
@@ -189,6 +198,15 @@ impl<T: Sized, TAllocator: Allocator> InternalArray<T, TAllocator> {
         Ok(())
     }
 
+    /// Sets both counters to `1`, since freshly allocated [`InternalArray`]
+    /// has them zeroed. Has to be called once, before the array is shared.
+    #[inline(always)]
+    pub fn init_counters(&mut self) {
+        let heap_data = self.heap_data_mut();
+        *heap_data.strong_counter_mut().get_mut() = 1;
+        *heap_data.weak_counter_mut().get_mut() = 1;
+    }
+
     #[inline(always)]
     pub fn deallocate(self) {
         let heap_data_layout = HeapData::<T>::layout(self.capacity);
@@ -210,14 +228,19 @@ impl<T: Sized, TAllocator: Allocator> InternalArray<T, TAllocator> {
         unsafe { &mut *self.data.as_ptr().cast() }
     }
 
+    /// Returns the number of initialized items in the buffer.
     #[inline(always)]
     pub const fn len(&self) -> Length {
         self.length
     }
 
+    /// Sets the number of initialized items in the buffer,
+    /// and resets the view to cover all of them.
     #[inline(always)]
-    pub const fn len_mut(&mut self) -> &mut Length {
-        &mut self.length
+    pub const fn set_len(&mut self, length: Length) {
+        self.length = length;
+        self.view_start = Length::ZERO;
+        self.view_length = length;
     }
 
     #[inline(always)]
@@ -225,16 +248,56 @@ impl<T: Sized, TAllocator: Allocator> InternalArray<T, TAllocator> {
         self.capacity
     }
 
+    /// Returns the start of the visible part of the array.
+    #[inline(always)]
+    pub const fn view_start(&self) -> Length {
+        self.view_start
+    }
+
+    /// Returns the length of the visible part of the array.
+    #[inline(always)]
+    pub const fn view_len(&self) -> Length {
+        self.view_length
+    }
+
+    /// Creates a shallow copy of `self`, that sees only `length` items
+    /// starting at `start`, relative to the current view.
+    ///
+    /// # Safety
+    ///
+    /// `start + length` has to be at most `self.view_len()`.
+    #[inline(always)]
+    pub unsafe fn sub_view(&self, start: Length, length: Length) -> Self {
+        debug_assert!(
+            start.value() as usize + length.value() as usize <= self.view_length.value() as usize,
+            "Sub view exceeds the current view."
+        );
+        let mut result = self.clone();
+        result.view_start = self.view_start + start;
+        result.view_length = length;
+        result
+    }
+
+    /// Returns `true` if both instances share the same allocation
+    /// and see exactly the same part of it.
+    #[inline(always)]
+    pub fn ref_equal(&self, other: &Self) -> bool {
+        self.data == other.data && self.view_start == other.view_start && self.view_length == other.view_length
+    }
+
+    /// Represents the visible part of the array as a slice.
     #[inline(always)]
     pub fn as_slice(&self) -> &[T] {
         unsafe {
             let data_ptr = self.heap_data().data().as_ptr().cast::<T>();
-            core::slice::from_raw_parts(data_ptr, self.length.value() as usize)
+            let data_ptr = data_ptr.add(self.view_start.value() as usize);
+            core::slice::from_raw_parts(data_ptr, self.view_length.value() as usize)
         }
     }
 
+    /// Represents all initialized items as a mutable slice, regardless of the view.
     #[inline(always)]
-    pub fn as_slice_mut(&mut self) -> &mut [T] {
+    pub fn full_slice_mut(&mut self) -> &mut [T] {
         unsafe {
             let data_ptr = self.heap_data().data().as_ptr().cast::<T>();
             core::slice::from_raw_parts_mut(data_ptr, self.length.value() as usize)
@@ -248,6 +311,8 @@ impl<T: Sized, TAllocator: Allocator> Clone for InternalArray<T, TAllocator> {
             data: self.data,
             length: self.length,
             capacity: self.capacity,
+            view_start: self.view_start,
+            view_length: self.view_length,
             allocator: self.allocator.clone(),
             phantom: PhantomData,
         }
@@ -68,11 +68,6 @@ impl<const N: usize, T, TAllocator: Allocator> InlineDynamicArray<N, T, TAllocat
         Ok(result)
     }
 
-    #[inline(always)]
-    const fn is_inlined(&self) -> bool {
-        self.capacity.value() == N as i32
-    }
-
     #[inline(always)]
     fn data_ptr(&self) -> *mut T {
         unsafe {
@@ -179,6 +174,54 @@ impl<const N: usize, T, TAllocator: Allocator> InlineDynamicArray<N, T, TAllocat
         }
     }
 
+    /// Removes the item at `index` from the [`InlineDynamicArray`] and returns it.
+    /// The last item is moved into its place, and so this is O(1) but doesn't
+    /// preserve the order.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(T)` if `index < self.len()`
+    /// * `None` otherwise
+    pub fn swap_remove(&mut self, index: usize) -> Option<T> {
+        let len: usize = self.length.into();
+        if index >= len {
+            return None;
+        }
+
+        unsafe {
+            let ptr = self.data_ptr();
+            let value = ptr.add(index).read();
+            let last = len - 1;
+            if index != last {
+                ptr.add(index).copy_from_nonoverlapping(ptr.add(last), 1);
+            }
+            self.length -= 1;
+            Some(value)
+        }
+    }
+
+    /// Shortens the [`InlineDynamicArray`] to `new_len` items, dropping the rest.
+    /// Does nothing if `new_len >= self.len()`.
+    ///
+    /// # Notes
+    ///
+    /// The capacity is not changed. In particular if the data was already
+    /// moved to the heap, it stays there.
+    pub fn truncate(&mut self, new_len: usize) {
+        let len: usize = self.length.into();
+        if new_len >= len {
+            return;
+        }
+
+        unsafe {
+            let ptr = self.data_ptr();
+            // Update the length first, in case a destructor panics.
+            self.length = Length::new_unchecked(new_len as i32);
+            let tail = core::ptr::slice_from_raw_parts_mut(ptr.add(new_len), len - new_len);
+            core::ptr::drop_in_place(tail);
+        }
+    }
+
     /// Creates a new empty [`InlineDynamicArray`].
     #[inline(always)]
     pub fn new() -> Self {
@@ -200,6 +243,44 @@ impl<const N: usize, T, TAllocator: Allocator> InlineDynamicArray<N, T, TAllocat
         }
     }
 
+    /// Creates a new [`InlineDynamicArray`] from a fixed-size array and allocator.
+    ///
+    /// # Notes
+    ///
+    /// The items are kept inline if `M <= N`. Otherwise exactly `M` items
+    /// are allocated on the heap.
+    ///
+    /// # Errors
+    ///
+    /// For details see [`ArrayConstructionError`].
+    pub fn from_array_with_allocator<const M: usize>(
+        array: [T; M],
+        allocator: TAllocator,
+    ) -> Result<Self, ArrayConstructionError> {
+        let mut result = Self::with_allocator(allocator);
+        if M > N {
+            result.try_grow(M)?;
+        }
+
+        let array = ManuallyDrop::new(array);
+        unsafe {
+            result.data_ptr().copy_from_nonoverlapping(array.as_ptr(), M);
+            result.length = Length::new_unchecked(M as i32);
+        }
+        Ok(result)
+    }
+
+    /// Creates a new [`InlineDynamicArray`] from a fixed-size array.
+    /// See [`from_array_with_allocator`][`Self::from_array_with_allocator`].
+    ///
+    /// # Errors
+    ///
+    /// For details see [`ArrayConstructionError`].
+    #[inline(always)]
+    pub fn from_array<const M: usize>(array: [T; M]) -> Result<Self, ArrayConstructionError> {
+        Self::from_array_with_allocator(array, TAllocator::default())
+    }
+
     /// Creates a new empty [`InlineDynamicArray`] with the given capacity.
     ///
     /// # Errors
@@ -246,6 +327,23 @@ impl<const N: usize, T, TAllocator: Allocator> InlineDynamicArray<N, T, TAllocat
         self.capacity
     }
 
+    /// Returns `true` if the [`InlineDynamicArray`] still keeps its items
+    /// in the inline buffer, i.e. it never allocated memory. Returns `false`
+    /// once the items were moved to the heap.
+    #[inline(always)]
+    #[must_use]
+    pub const fn is_inlined(&self) -> bool {
+        self.capacity.value() == N as i32
+    }
+
+    /// Returns the size of the inline buffer, i.e. `N`. The [`InlineDynamicArray`]
+    /// can hold that many items without allocating memory.
+    #[inline(always)]
+    #[must_use]
+    pub const fn inline_capacity(&self) -> usize {
+        N
+    }
+
     /// Returns a reference to the allocator of the [`InlineDynamicArray`].
     #[inline(always)]
     pub const fn allocator(&self) -> &TAllocator {
@@ -270,6 +368,19 @@ impl<const N: usize, T, TAllocator: Allocator> InlineDynamicArray<N, T, TAllocat
         }
     }
 
+    /// Returns an iterator over the items of the [`InlineDynamicArray`].
+    #[inline(always)]
+    pub fn iter(&self) -> core::slice::Iter<'_, T> {
+        self.as_slice().iter()
+    }
+
+    /// Returns an iterator over the items of the [`InlineDynamicArray`] that allows
+    /// modifying each value.
+    #[inline(always)]
+    pub fn iter_mut(&mut self) -> core::slice::IterMut<'_, T> {
+        self.as_slice_mut().iter_mut()
+    }
+
     /// Fills the [`InlineDynamicArray`] up to its capacity, by invoking
     /// `constructor` for each missing element.
     ///
@@ -297,18 +408,50 @@ impl<const N: usize, T, TAllocator: Allocator> InlineDynamicArray<N, T, TAllocat
 }
 
 impl<const N: usize, T: Clone, TAllocator: Allocator> InlineDynamicArray<N, T, TAllocator> {
+    /// Creates a new [`InlineDynamicArray`] from a slice and allocator.
+    /// The items are cloned.
+    ///
+    /// # Notes
+    ///
+    /// The items are kept inline if `slice.len() <= N`. Otherwise exactly
+    /// `slice.len()` items are allocated on the heap.
+    ///
+    /// # Errors
+    ///
+    /// For details see [`ArrayConstructionError`].
+    pub fn from_slice_with_allocator(slice: &[T], allocator: TAllocator) -> Result<Self, ArrayConstructionError> {
+        let mut result = Self::with_allocator(allocator);
+        if slice.len() > N {
+            result.try_grow(slice.len())?;
+        }
+
+        let ptr = result.data_ptr();
+        for (index, item) in slice.iter().enumerate() {
+            unsafe { ptr.add(index).write(item.clone()) };
+            // Keep the length up to date, in case `clone` panics.
+            result.length += 1;
+        }
+        Ok(result)
+    }
+
+    /// Creates a new [`InlineDynamicArray`] from a slice. The items are cloned.
+    /// See [`from_slice_with_allocator`][`Self::from_slice_with_allocator`].
+    ///
+    /// # Errors
+    ///
+    /// For details see [`ArrayConstructionError`].
+    #[inline(always)]
+    pub fn from_slice(slice: &[T]) -> Result<Self, ArrayConstructionError> {
+        Self::from_slice_with_allocator(slice, TAllocator::default())
+    }
+
     /// Tries to clone the [`InlineDynamicArray`].
     ///
     /// # Errors
     ///
     /// For details see [`ArrayConstructionError`].
     pub fn try_clone(&self) -> Result<Self, ArrayConstructionError> {
-        let mut new = Self::with_allocator(self.allocator.clone());
-        let slice = self.as_slice();
-        for item in slice {
-            new.push(item.clone())?;
-        }
-        Ok(new)
+        Self::from_slice_with_allocator(self.as_slice(), self.allocator.clone())
     }
 }
 
@@ -382,6 +525,24 @@ impl<const N: usize, T, TAllocator: Allocator> AsRef<[T]> for InlineDynamicArray
     }
 }
 
+impl<'a, const N: usize, T, TAllocator: Allocator> IntoIterator for &'a InlineDynamicArray<N, T, TAllocator> {
+    type Item = &'a T;
+    type IntoIter = core::slice::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, const N: usize, T, TAllocator: Allocator> IntoIterator for &'a mut InlineDynamicArray<N, T, TAllocator> {
+    type Item = &'a mut T;
+    type IntoIter = core::slice::IterMut<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
 unsafe impl<const N: usize, T: Send, TAllocator: Allocator> Send for InlineDynamicArray<N, T, TAllocator> {}
 unsafe impl<const N: usize, T: Sync, TAllocator: Allocator> Sync for InlineDynamicArray<N, T, TAllocator> {}
 
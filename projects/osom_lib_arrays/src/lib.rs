@@ -13,6 +13,9 @@
 #![cfg_attr(docsrs, feature(doc_cfg))]
 #![cfg_attr(docsrs, allow(unused_attributes))]
 
+#[cfg(feature = "std_alloc")]
+extern crate alloc;
+
 pub mod errors;
 
 mod array;
@@ -32,3 +35,13 @@ pub use fixed_array::*;
 
 mod double_fixed_array;
 pub use double_fixed_array::*;
+
+mod deque;
+pub use deque::*;
+
+mod bit_array;
+pub use bit_array::*;
+
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+mod serde_impls;
@@ -0,0 +1,202 @@
+//! Implements [`serde`] traits for the arrays of this crate.
+//!
+//! All arrays serialize as sequences of their items. Deserialization
+//! collects a sequence into the target array, using the default allocator.
+use core::{fmt, marker::PhantomData};
+
+use osom_lib_alloc::Allocator;
+use serde::{
+    Deserialize, Deserializer, Serialize, Serializer,
+    de::{Error, SeqAccess, Visitor},
+};
+
+use crate::{
+    Array, Deque, DynamicArray, FixedArray, ImmutableArray, ImmutableArrayBuilder, InlineDynamicArray, OutOfRangeError,
+    errors::ArrayConstructionError,
+};
+
+impl<T: Serialize, TAllocator: Allocator> Serialize for Array<T, TAllocator> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.iter())
+    }
+}
+
+impl<T: Serialize, TAllocator: Allocator> Serialize for DynamicArray<T, TAllocator> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.iter())
+    }
+}
+
+impl<T: Serialize, const N: usize> Serialize for FixedArray<T, N> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.iter())
+    }
+}
+
+impl<const N: usize, T: Serialize, TAllocator: Allocator> Serialize for InlineDynamicArray<N, T, TAllocator> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.iter())
+    }
+}
+
+impl<T: Serialize, TAllocator: Allocator> Serialize for Deque<T, TAllocator> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.iter())
+    }
+}
+
+impl<T: Serialize, TAllocator: Allocator> Serialize for ImmutableArray<T, TAllocator> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.as_slice().iter())
+    }
+}
+
+/// A collection that a deserialized sequence can be pushed into.
+trait SeqTarget: Sized {
+    type Item;
+    type Error: fmt::Display;
+
+    fn try_new() -> Result<Self, Self::Error>;
+
+    fn try_push(&mut self, item: Self::Item) -> Result<(), Self::Error>;
+}
+
+impl<T, TAllocator: Allocator> SeqTarget for DynamicArray<T, TAllocator> {
+    type Item = T;
+    type Error = ArrayConstructionError;
+
+    fn try_new() -> Result<Self, Self::Error> {
+        Ok(Self::new())
+    }
+
+    fn try_push(&mut self, item: T) -> Result<(), Self::Error> {
+        self.push(item)
+    }
+}
+
+impl<T, const N: usize> SeqTarget for FixedArray<T, N> {
+    type Item = T;
+    type Error = OutOfRangeError;
+
+    fn try_new() -> Result<Self, Self::Error> {
+        Ok(Self::new())
+    }
+
+    fn try_push(&mut self, item: T) -> Result<(), Self::Error> {
+        self.push(item)
+    }
+}
+
+impl<const N: usize, T, TAllocator: Allocator> SeqTarget for InlineDynamicArray<N, T, TAllocator> {
+    type Item = T;
+    type Error = ArrayConstructionError;
+
+    fn try_new() -> Result<Self, Self::Error> {
+        Ok(Self::new())
+    }
+
+    fn try_push(&mut self, item: T) -> Result<(), Self::Error> {
+        self.push(item)
+    }
+}
+
+impl<T, TAllocator: Allocator> SeqTarget for Deque<T, TAllocator> {
+    type Item = T;
+    type Error = ArrayConstructionError;
+
+    fn try_new() -> Result<Self, Self::Error> {
+        Ok(Self::new())
+    }
+
+    fn try_push(&mut self, item: T) -> Result<(), Self::Error> {
+        self.push_back(item)
+    }
+}
+
+impl<T, TAllocator: Allocator> SeqTarget for ImmutableArrayBuilder<T, TAllocator> {
+    type Item = T;
+    type Error = ArrayConstructionError;
+
+    fn try_new() -> Result<Self, Self::Error> {
+        Self::new()
+    }
+
+    fn try_push(&mut self, item: T) -> Result<(), Self::Error> {
+        self.push(item)
+    }
+}
+
+struct SeqTargetVisitor<TTarget> {
+    phantom: PhantomData<TTarget>,
+}
+
+impl<'de, TTarget> Visitor<'de> for SeqTargetVisitor<TTarget>
+where
+    TTarget: SeqTarget,
+    TTarget::Item: Deserialize<'de>,
+{
+    type Value = TTarget;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a sequence")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut result = TTarget::try_new().map_err(A::Error::custom)?;
+        while let Some(item) = seq.next_element()? {
+            result.try_push(item).map_err(A::Error::custom)?;
+        }
+        Ok(result)
+    }
+}
+
+#[inline(always)]
+fn deserialize_seq<'de, D, TTarget>(deserializer: D) -> Result<TTarget, D::Error>
+where
+    D: Deserializer<'de>,
+    TTarget: SeqTarget,
+    TTarget::Item: Deserialize<'de>,
+{
+    deserializer.deserialize_seq(SeqTargetVisitor { phantom: PhantomData })
+}
+
+impl<'de, T: Deserialize<'de>, TAllocator: Allocator> Deserialize<'de> for DynamicArray<T, TAllocator> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserialize_seq(deserializer)
+    }
+}
+
+impl<'de, T: Deserialize<'de>, TAllocator: Allocator> Deserialize<'de> for Array<T, TAllocator> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let array: DynamicArray<T, TAllocator> = deserialize_seq(deserializer)?;
+        array.into_array().map_err(D::Error::custom)
+    }
+}
+
+/// Fails if the sequence has more than `N` items.
+impl<'de, T: Deserialize<'de>, const N: usize> Deserialize<'de> for FixedArray<T, N> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserialize_seq(deserializer)
+    }
+}
+
+impl<'de, const N: usize, T: Deserialize<'de>, TAllocator: Allocator> Deserialize<'de>
+    for InlineDynamicArray<N, T, TAllocator>
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserialize_seq(deserializer)
+    }
+}
+
+impl<'de, T: Deserialize<'de>, TAllocator: Allocator> Deserialize<'de> for Deque<T, TAllocator> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserialize_seq(deserializer)
+    }
+}
+
+impl<'de, T: Deserialize<'de>, TAllocator: Allocator> Deserialize<'de> for ImmutableArray<T, TAllocator> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let builder: ImmutableArrayBuilder<T, TAllocator> = deserialize_seq(deserializer)?;
+        Ok(builder.build())
+    }
+}
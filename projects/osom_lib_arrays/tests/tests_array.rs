@@ -24,6 +24,19 @@ fn test_array_clone() {
     assert_eq!(array_2.len(), Length::try_from_i32(3).unwrap());
 }
 
+#[test]
+fn test_array_try_clone() {
+    let mut array = new_array([1, 2, 3]);
+    let array_2 = array.try_clone().unwrap();
+    array.as_slice_mut()[0] = 10;
+    assert_eq!(array.as_slice(), &[10, 2, 3]);
+    assert_eq!(array_2.as_slice(), &[1, 2, 3]);
+
+    let empty = StdArray::<i32>::empty();
+    let empty_2 = empty.try_clone().unwrap();
+    assert!(empty_2.is_empty());
+}
+
 #[test]
 fn test_custom_struct() {
     #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -106,3 +119,167 @@ fn test_custom_drop() {
 
     drop(drops_count);
 }
+
+#[test]
+fn test_chunks_and_windows() {
+    let array = new_array([1, 2, 3, 4, 5]);
+    let chunks: Vec<&[i32]> = array.chunks(2).collect();
+    assert_eq!(chunks, [&[1, 2][..], &[3, 4], &[5]]);
+    let windows: Vec<&[i32]> = array.windows(4).collect();
+    assert_eq!(windows, [&[1, 2, 3, 4][..], &[2, 3, 4, 5]]);
+    assert_eq!(array.windows(6).count(), 0);
+}
+
+#[test]
+fn test_chunks_exact() {
+    let array = new_array([1, 2, 3, 4, 5]);
+    let chunks = array.chunks_exact(2);
+    assert_eq!(chunks.remainder(), &[5]);
+    assert_eq!(chunks.collect::<Vec<_>>(), [&[1, 2][..], &[3, 4]]);
+
+    let array = new_array([1, 2, 3, 4]);
+    assert!(array.chunks_exact(2).remainder().is_empty());
+    assert_eq!(array.chunks_exact(4).count(), 1);
+}
+
+#[test]
+fn test_get_and_get_mut() {
+    let mut array = new_array([1, 2, 3, 4]);
+    assert_eq!(array.get(0), Some(&1));
+    assert_eq!(array.get(3), Some(&4));
+    assert_eq!(array.get(4), None);
+    assert_eq!(array.get(1..3), Some(&[2, 3][..]));
+    assert_eq!(array.get(2..), Some(&[3, 4][..]));
+    assert_eq!(array.get(3..5), None);
+
+    *array.get_mut(0).unwrap() = 10;
+    array.get_mut(2..).unwrap().fill(0);
+    assert!(array.get_mut(usize::MAX).is_none());
+    assert_eq!(array.as_slice(), &[10, 2, 0, 0]);
+}
+
+#[test]
+fn test_repeat_and_concat() {
+    let array = new_array([1, 2]);
+    assert_eq!(array.repeat(3).unwrap().as_slice(), &[1, 2, 1, 2, 1, 2]);
+    assert!(array.repeat(0).unwrap().is_empty());
+    assert_eq!(array.concat(&[3, 4, 5]).unwrap().as_slice(), &[1, 2, 3, 4, 5]);
+    assert_eq!(array.concat(&[]).unwrap().as_slice(), &[1, 2]);
+
+    let empty = StdArray::<i32>::empty();
+    assert!(empty.repeat(usize::MAX).unwrap().is_empty());
+    assert_eq!(empty.concat(&[7]).unwrap().as_slice(), &[7]);
+
+    let strings = StdArray::from_array(["a".to_string(), "b".to_string()]).unwrap();
+    let repeated = strings.repeat(2).unwrap();
+    assert_eq!(repeated.as_slice(), &["a", "b", "a", "b"]);
+}
+
+#[test]
+fn test_repeat_too_long() {
+    use osom_lib_arrays::errors::ArrayConstructionError;
+
+    let array = new_array([1, 2]);
+    let max_length = StdArray::<i32>::MAX_LENGTH;
+    assert_eq!(
+        array.repeat(max_length).err(),
+        Some(ArrayConstructionError::ArrayTooLong)
+    );
+    assert_eq!(
+        array.repeat(usize::MAX).err(),
+        Some(ArrayConstructionError::ArrayTooLong)
+    );
+}
+
+#[test]
+fn test_errors_display() {
+    use osom_lib_arrays::{OutOfRangeError, errors::ArrayConstructionError};
+
+    assert_eq!(
+        ArrayConstructionError::AllocationError.to_string(),
+        "failed to allocate memory for the array"
+    );
+    assert_eq!(
+        ArrayConstructionError::ArrayTooLong.to_string(),
+        "array length exceeds the maximum length"
+    );
+    assert_eq!(OutOfRangeError.to_string(), "fixed array capacity exceeded");
+}
+
+#[test]
+fn test_is_allocation_error() {
+    use osom_lib_arrays::errors::ArrayConstructionError;
+
+    assert!(ArrayConstructionError::AllocationError.is_allocation_error());
+    assert!(!ArrayConstructionError::ArrayTooLong.is_allocation_error());
+}
+
+#[test]
+fn test_errors_into_boxed_error() {
+    use osom_lib_arrays::errors::ArrayConstructionError;
+
+    fn fails() -> Result<(), Box<dyn core::error::Error>> {
+        Err(ArrayConstructionError::ArrayTooLong)?;
+        Ok(())
+    }
+
+    let error = fails().unwrap_err();
+    assert_eq!(error.to_string(), "array length exceeds the maximum length");
+    assert!(error.source().is_none());
+}
+
+#[test]
+fn test_iter_and_into_iter_for_refs() {
+    let mut array = new_array([1, 2, 3]);
+    for item in &mut array {
+        *item *= 2;
+    }
+    let mut collected = Vec::new();
+    for item in &array {
+        collected.push(*item);
+    }
+    assert_eq!(collected, [2, 4, 6]);
+    assert_eq!(array.iter().sum::<i32>(), 12);
+}
+
+#[test]
+fn test_sort_unstable() {
+    let mut array = new_array([5, 3, 9, 1, 7]);
+    array.sort_unstable();
+    assert_eq!(array.as_slice(), &[1, 3, 5, 7, 9]);
+
+    array.sort_unstable_by(|left, right| right.cmp(left));
+    assert_eq!(array.as_slice(), &[9, 7, 5, 3, 1]);
+
+    array.sort_unstable_by_key(|value| value % 3);
+    assert!(array.is_sorted_by_key(|value| value % 3));
+}
+
+#[test]
+fn test_array_as_chunks() {
+    let array = new_array([1, 2, 3, 4, 5, 6, 7]);
+    let (chunks, remainder) = array.as_chunks::<3>();
+    assert_eq!(chunks, &[[1, 2, 3], [4, 5, 6]]);
+    assert_eq!(remainder, &[7]);
+
+    let (chunks, remainder) = array.as_chunks::<7>();
+    assert_eq!(chunks, &[[1, 2, 3, 4, 5, 6, 7]]);
+    assert!(remainder.is_empty());
+
+    let (chunks, remainder) = array.as_chunks::<8>();
+    assert!(chunks.is_empty());
+    assert_eq!(remainder, &[1, 2, 3, 4, 5, 6, 7]);
+
+    let empty = new_array::<i32, 0>([]);
+    let (chunks, remainder) = empty.as_chunks::<2>();
+    assert!(chunks.is_empty());
+    assert!(remainder.is_empty());
+}
+
+#[test]
+fn test_array_partition_point() {
+    let array = new_array([2, 4, 6, 8]);
+    assert_eq!(array.partition_point(|item| *item < 5), 2);
+    assert_eq!(array.partition_point(|_| true), 4);
+    assert_eq!(array.partition_point(|_| false), 0);
+}
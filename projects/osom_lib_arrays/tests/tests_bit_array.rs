@@ -0,0 +1,102 @@
+#![cfg(feature = "std_alloc")]
+
+use osom_lib_arrays::StdBitArray;
+use osom_lib_primitives::Length;
+use rstest::rstest;
+
+fn bit_array_from(bits: &[bool]) -> StdBitArray {
+    let mut array = StdBitArray::new();
+    for bit in bits {
+        array.push(*bit).unwrap();
+    }
+    array
+}
+
+#[rstest]
+#[case(0)]
+#[case(1)]
+#[case(63)]
+#[case(64)]
+#[case(65)]
+#[case(300)]
+fn test_with_len(#[case] len: i32) {
+    let array = StdBitArray::with_len(Length::try_from_i32(len).unwrap()).unwrap();
+    assert_eq!(array.len().value(), len);
+    assert_eq!(array.count_ones(), 0);
+    assert_eq!(array.iter_ones().count(), 0);
+    assert_eq!(array.as_words().len(), (len as usize).div_ceil(64));
+}
+
+#[test]
+fn test_set_get_clear() {
+    let mut array = StdBitArray::with_len(Length::try_from_i32(130).unwrap()).unwrap();
+    for index in [0, 5, 63, 64, 127, 129] {
+        assert!(!array.get(index));
+        array.set(index);
+        assert!(array.get(index));
+    }
+    assert_eq!(array.count_ones(), 6);
+    assert_eq!(array.iter_ones().collect::<Vec<_>>(), vec![0, 5, 63, 64, 127, 129]);
+
+    array.clear(63);
+    array.clear(63);
+    array.clear(1);
+    assert!(!array.get(63));
+    assert_eq!(array.count_ones(), 5);
+    assert_eq!(array.iter_ones().collect::<Vec<_>>(), vec![0, 5, 64, 127, 129]);
+}
+
+#[test]
+fn test_push() {
+    let bits: Vec<bool> = (0..200).map(|i| i % 3 == 0).collect();
+    let array = bit_array_from(&bits);
+    assert_eq!(array.len().value(), 200);
+    for (index, bit) in bits.iter().enumerate() {
+        assert_eq!(array.get(index), *bit);
+    }
+    assert_eq!(array.count_ones(), bits.iter().filter(|bit| **bit).count());
+}
+
+#[test]
+#[should_panic(expected = "BitArray index out of bounds.")]
+fn test_get_out_of_bounds() {
+    let array = StdBitArray::with_len(Length::try_from_i32(10).unwrap()).unwrap();
+    let _ = array.get(10);
+}
+
+#[rstest]
+#[case(&[true, true, false, false], &[true, false, true, false])]
+#[case(&[true; 70], &[false; 70])]
+fn test_bitwise(#[case] left: &[bool], #[case] right: &[bool]) {
+    let right_array = bit_array_from(right);
+    let expected =
+        |f: fn(bool, bool) -> bool| bit_array_from(&left.iter().zip(right).map(|(l, r)| f(*l, *r)).collect::<Vec<_>>());
+
+    let mut and = bit_array_from(left);
+    and.and(&right_array);
+    assert_eq!(and, expected(|l, r| l & r));
+
+    let mut or = bit_array_from(left);
+    or.or(&right_array);
+    assert_eq!(or, expected(|l, r| l | r));
+
+    let mut xor = bit_array_from(left);
+    xor.xor(&right_array);
+    assert_eq!(xor, expected(|l, r| l ^ r));
+}
+
+#[test]
+#[should_panic(expected = "BitArray lengths differ.")]
+fn test_bitwise_length_mismatch() {
+    let mut left = bit_array_from(&[true, false]);
+    let right = bit_array_from(&[true]);
+    left.or(&right);
+}
+
+#[test]
+fn test_clone_and_debug() {
+    let array = bit_array_from(&[true, false, true]);
+    let clone = array.clone();
+    assert_eq!(array, clone);
+    assert_eq!(format!("{clone:?}"), "BitArray(101)");
+}
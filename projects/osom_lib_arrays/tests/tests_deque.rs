@@ -0,0 +1,130 @@
+#![cfg(feature = "std_alloc")]
+
+use std::{
+    collections::VecDeque,
+    sync::{
+        Arc,
+        atomic::{AtomicUsize, Ordering},
+    },
+};
+
+use osom_lib_arrays::StdDeque;
+use osom_lib_primitives::Length;
+use rstest::rstest;
+
+fn collect<T: Clone>(deque: &StdDeque<T>) -> Vec<T> {
+    deque.iter().cloned().collect()
+}
+
+#[test]
+fn test_push_and_pop_both_ends() {
+    let mut deque = StdDeque::<i32>::new();
+    assert_eq!(deque.pop_front(), None);
+    assert_eq!(deque.pop_back(), None);
+    assert_eq!(deque.front(), None);
+    assert_eq!(deque.back(), None);
+
+    deque.push_back(2).unwrap();
+    deque.push_front(1).unwrap();
+    deque.push_back(3).unwrap();
+    deque.push_front(0).unwrap();
+    assert_eq!(collect(&deque), [0, 1, 2, 3]);
+    assert_eq!(deque.front(), Some(&0));
+    assert_eq!(deque.back(), Some(&3));
+    assert_eq!(deque.get(2), Some(&2));
+    assert_eq!(deque.get(4), None);
+
+    assert_eq!(deque.pop_front(), Some(0));
+    assert_eq!(deque.pop_back(), Some(3));
+    assert_eq!(deque.pop_back(), Some(2));
+    assert_eq!(deque.pop_front(), Some(1));
+    assert!(deque.is_empty());
+}
+
+#[rstest]
+#[case(1)]
+#[case(7)]
+#[case(100)]
+#[case(1000)]
+fn test_matches_vec_deque(#[case] count: u32) {
+    let mut deque = StdDeque::<u32>::new();
+    let mut expected = VecDeque::new();
+    for value in 0..count {
+        match value % 5 {
+            0 | 3 => {
+                deque.push_front(value).unwrap();
+                expected.push_front(value);
+            }
+            1 | 4 => {
+                deque.push_back(value).unwrap();
+                expected.push_back(value);
+            }
+            _ => {
+                assert_eq!(deque.pop_front(), expected.pop_front());
+            }
+        }
+        assert_eq!(deque.len().value() as usize, expected.len());
+        assert_eq!(collect(&deque), expected.iter().copied().collect::<Vec<_>>());
+    }
+
+    while let Some(value) = expected.pop_back() {
+        assert_eq!(deque.pop_back(), Some(value));
+    }
+    assert!(deque.is_empty());
+}
+
+#[test]
+fn test_as_slices_wrap_around() {
+    let mut deque = StdDeque::<i32>::with_capacity(Length::try_from_i32(4).unwrap()).unwrap();
+    deque.push_back(1).unwrap();
+    deque.push_back(2).unwrap();
+    deque.push_front(0).unwrap();
+    assert_eq!(deque.capacity().value(), 4);
+    assert_eq!(deque.as_slices(), (&[0][..], &[1, 2][..]));
+
+    for item in deque.iter_mut() {
+        *item *= 10;
+    }
+    assert_eq!(collect(&deque), [0, 10, 20]);
+
+    // Grows while wrapped around.
+    deque.push_front(-10).unwrap();
+    deque.push_front(-20).unwrap();
+    assert!(deque.capacity().value() > 4);
+    assert_eq!(collect(&deque), [-20, -10, 0, 10, 20]);
+    let (first, second) = deque.as_slices();
+    assert_eq!([first, second].concat(), [-20, -10, 0, 10, 20]);
+}
+
+#[test]
+fn test_drop() {
+    struct DropCounter(Arc<AtomicUsize>);
+
+    impl Drop for DropCounter {
+        fn drop(&mut self) {
+            self.0.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    let counter = Arc::new(AtomicUsize::new(0));
+    let mut deque = StdDeque::new();
+    for index in 0..50 {
+        if index % 2 == 0 {
+            deque.push_back(DropCounter(counter.clone())).unwrap();
+        } else {
+            deque.push_front(DropCounter(counter.clone())).unwrap();
+        }
+    }
+
+    drop(deque.pop_front());
+    drop(deque.pop_back());
+    assert_eq!(counter.load(Ordering::SeqCst), 2);
+
+    deque.clear();
+    assert_eq!(counter.load(Ordering::SeqCst), 50);
+    assert!(deque.is_empty());
+
+    deque.push_back(DropCounter(counter.clone())).unwrap();
+    drop(deque);
+    assert_eq!(counter.load(Ordering::SeqCst), 51);
+}
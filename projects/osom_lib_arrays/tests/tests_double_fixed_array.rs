@@ -187,6 +187,19 @@ fn test_push_on_boundary() {
     assert_eq!(arr.as_slice(), &[1, 2, 3, 4, -1]);
 }
 
+#[rstest]
+#[case(1 << 32)]
+#[case((1 << 32) + 1)]
+#[case(u32::MAX as usize)]
+fn test_extend_from_slice_huge_input(#[case] count: usize) {
+    // Zero-sized items, so the huge slice does not allocate.
+    let input = vec![(); count];
+    let mut arr = DoubleFixedArray::<(), 2, 1>::new();
+    arr.push(()).unwrap();
+    assert!(arr.extend_from_slice(&input).is_err());
+    assert_eq!(arr.len().value(), 1);
+}
+
 #[rstest]
 #[case(&[])]
 #[case(&[5])]
@@ -204,3 +217,72 @@ fn test_clone(#[case] input: &[i32]) {
     assert_eq!(arr.is_full(), arr2.is_full());
     assert_eq!(arr.is_empty(), arr2.is_empty());
 }
+
+#[test]
+fn test_clone_owned_values_across_halves() {
+    let mut arr = DoubleFixedArray::<String, 2, 3>::new();
+    for value in 0..5 {
+        arr.push(value.to_string()).unwrap();
+    }
+
+    let arr2 = arr.clone();
+    drop(arr);
+    assert_eq!(arr2.as_slice(), &["0", "1", "2", "3", "4"]);
+}
+
+#[test]
+fn test_iter_across_halves() {
+    let mut arr = DoubleFixedArray::<i32, 3, 4>::new();
+    for value in 0..6 {
+        arr.push(value).unwrap();
+    }
+
+    assert_eq!(arr.iter().copied().collect::<Vec<_>>(), vec![0, 1, 2, 3, 4, 5]);
+
+    for value in &mut arr {
+        *value *= 10;
+    }
+    assert_eq!(
+        (&arr).into_iter().copied().collect::<Vec<_>>(),
+        vec![0, 10, 20, 30, 40, 50]
+    );
+
+    let into_iter = arr.into_iter();
+    assert_eq!(into_iter.len(), 6);
+    assert_eq!(into_iter.rev().collect::<Vec<_>>(), vec![50, 40, 30, 20, 10, 0]);
+}
+
+#[test]
+fn test_into_iter_partial_consumption_drops() {
+    #[derive(Debug)]
+    struct Foo {
+        id: usize,
+        counter: Arc<AtomicUsize>,
+    }
+
+    impl Drop for Foo {
+        fn drop(&mut self) {
+            self.counter.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    let counter = Arc::new(AtomicUsize::new(0));
+    let mut arr = DoubleFixedArray::<_, 3, 3>::new();
+    for id in 0..5 {
+        arr.push(Foo {
+            id,
+            counter: counter.clone(),
+        })
+        .unwrap();
+    }
+
+    let mut iter = arr.into_iter();
+    assert_eq!(iter.next().unwrap().id, 0);
+    assert_eq!(iter.next_back().unwrap().id, 4);
+    assert_eq!(iter.next().unwrap().id, 1);
+    assert_eq!(counter.load(Ordering::SeqCst), 3);
+    assert_eq!(iter.len(), 2);
+
+    drop(iter);
+    assert_eq!(counter.load(Ordering::SeqCst), 5);
+}
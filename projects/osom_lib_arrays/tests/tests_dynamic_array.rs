@@ -1,14 +1,17 @@
 #![cfg(feature = "std_alloc")]
 
 use std::{
-    ops::Deref,
+    alloc::Layout,
+    ops::{Deref, RangeBounds},
+    ptr::NonNull,
     sync::{
         Arc,
         atomic::{AtomicUsize, Ordering},
     },
 };
 
-use osom_lib_arrays::StdDynamicArray;
+use osom_lib_alloc::{Allocator, DetailedAllocationError, StdAllocator};
+use osom_lib_arrays::{DynamicArray, StdDynamicArray, StdImmutableArray};
 
 use osom_lib_primitives::Length;
 use rstest::rstest;
@@ -146,3 +149,547 @@ fn test_shrink_to_fit() {
     assert!(dynamic_array.capacity() >= Length::try_from_i32(2).unwrap());
     assert_eq!(dynamic_array.len(), Length::try_from_i32(2).unwrap());
 }
+
+#[test]
+fn test_from_iter_in() {
+    let array = StdDynamicArray::from_iter_in(1..=5, StdAllocator).unwrap();
+    assert_eq!(array.as_slice(), &[1, 2, 3, 4, 5]);
+
+    let empty = StdDynamicArray::<i32>::from_iter_in(core::iter::empty(), StdAllocator).unwrap();
+    assert!(empty.is_empty());
+}
+
+#[test]
+fn test_try_collect() {
+    let items: [Result<i32, &str>; 3] = [Ok(1), Ok(2), Ok(3)];
+    let array = StdDynamicArray::try_collect(items).unwrap().unwrap();
+    assert_eq!(array.as_slice(), &[1, 2, 3]);
+
+    let items: [Result<i32, &str>; 3] = [Ok(1), Err("bad"), Ok(3)];
+    let error = StdDynamicArray::try_collect(items).unwrap_err();
+    assert_eq!(error, "bad");
+
+    let mut polled = 0;
+    let items = (0..10).map(|value| {
+        polled += 1;
+        if value == 3 { Err(value) } else { Ok(value) }
+    });
+    assert_eq!(StdDynamicArray::try_collect(items).unwrap_err(), 3);
+    assert_eq!(polled, 4);
+}
+
+#[test]
+fn test_reserve() {
+    let mut array = StdDynamicArray::<i32>::new();
+    array.reserve_exact(10).unwrap();
+    assert_eq!(array.capacity().value(), 10);
+    array.reserve_exact(5).unwrap();
+    assert_eq!(array.capacity().value(), 10);
+
+    array.extend_from_slice(&[1, 2, 3]).unwrap();
+    array.reserve(20).unwrap();
+    assert!(array.capacity().value() >= 23);
+    assert_eq!(array.as_slice(), &[1, 2, 3]);
+}
+
+#[test]
+fn test_too_long_is_reported_before_allocating() {
+    use osom_lib_arrays::errors::ArrayConstructionError;
+
+    let max_length = StdDynamicArray::<i32>::MAX_LENGTH;
+    let mut array = StdDynamicArray::<i32>::new();
+    assert_eq!(array.reserve(max_length + 1), Err(ArrayConstructionError::ArrayTooLong));
+    assert_eq!(
+        array.reserve_exact(usize::MAX),
+        Err(ArrayConstructionError::ArrayTooLong)
+    );
+    assert_eq!(array.capacity(), Length::ZERO);
+
+    array.push(1).unwrap();
+    let capacity = array.capacity();
+    assert_eq!(array.reserve(max_length), Err(ArrayConstructionError::ArrayTooLong));
+    assert_eq!(array.reserve(usize::MAX), Err(ArrayConstructionError::ArrayTooLong));
+    assert_eq!(array.capacity(), capacity);
+    assert_eq!(array.as_slice(), &[1]);
+}
+
+#[test]
+fn test_as_ptr() {
+    let mut array = StdDynamicArray::<i32>::new();
+    assert!(array.as_ptr().is_aligned());
+
+    array.extend_from_slice(&[1, 2, 3]).unwrap();
+    assert_eq!(array.as_ptr(), array.as_slice().as_ptr());
+    unsafe {
+        array.as_mut_ptr().add(1).write(20);
+        assert_eq!(*array.as_ptr().add(2), 3);
+    }
+    assert_eq!(array.as_slice(), &[1, 20, 3]);
+}
+
+#[test]
+fn test_swap_and_fill() {
+    let mut array = StdDynamicArray::<i32>::new();
+    array.extend_from_slice(&[1, 2, 3, 4]).unwrap();
+    array.swap(0, 3);
+    array.swap(1, 1);
+    assert_eq!(array.as_slice(), &[4, 2, 3, 1]);
+
+    let capacity = array.capacity();
+    array.fill(7);
+    assert_eq!(array.as_slice(), &[7, 7, 7, 7]);
+    assert_eq!(array.capacity(), capacity);
+}
+
+#[test]
+#[should_panic]
+fn test_swap_out_of_bounds() {
+    let mut array = StdDynamicArray::<i32>::new();
+    array.extend_from_slice(&[1, 2]).unwrap();
+    array.swap(0, 2);
+}
+
+#[test]
+fn test_chunks_and_windows() {
+    let mut array = StdDynamicArray::<i32>::new();
+    assert_eq!(array.chunks(3).count(), 0);
+    array.extend_from_slice(&[1, 2, 3, 4, 5]).unwrap();
+    let chunks: Vec<&[i32]> = array.chunks(3).collect();
+    assert_eq!(chunks, [&[1, 2, 3][..], &[4, 5]]);
+    let windows: Vec<&[i32]> = array.windows(2).collect();
+    assert_eq!(windows, [&[1, 2][..], &[2, 3], &[3, 4], &[4, 5]]);
+}
+
+#[test]
+#[should_panic]
+fn test_chunks_zero_size() {
+    let array = StdDynamicArray::<i32>::new();
+    let _ = array.chunks(0);
+}
+
+#[test]
+fn test_shrink_to_fit_empty_deallocates() {
+    let mut dynamic_array = StdDynamicArray::<String>::with_capacity(Length::try_from_i32(10).unwrap()).unwrap();
+    dynamic_array.push("a".to_string()).unwrap();
+    dynamic_array.push("b".to_string()).unwrap();
+    while dynamic_array.pop().is_some() {}
+
+    dynamic_array.shrink_to_fit().unwrap();
+    assert_eq!(dynamic_array.capacity(), Length::ZERO);
+    assert_eq!(dynamic_array.len(), Length::ZERO);
+    assert!(dynamic_array.as_ptr().is_aligned());
+
+    dynamic_array.shrink_to_fit().unwrap();
+    assert_eq!(dynamic_array.capacity(), Length::ZERO);
+
+    dynamic_array.push("c".to_string()).unwrap();
+    assert_eq!(dynamic_array.as_slice(), &["c".to_string()]);
+}
+
+#[test]
+fn test_cross_type_equality() {
+    use osom_lib_arrays::FixedArray;
+
+    let mut array = StdDynamicArray::<i32>::new();
+    array.extend_from_slice(&[1, 2, 3]).unwrap();
+    assert_eq!(array, [1, 2, 3]);
+    assert_eq!(array, &[1, 2, 3]);
+    assert_eq!(array, [1, 2, 3][..]);
+    assert_eq!(array, &[1, 2, 3][..]);
+    assert_ne!(array, [1, 2]);
+    assert_ne!(array, &[1, 2, 4][..]);
+
+    let fixed = FixedArray::<i32, 8>::from_array([1, 2, 3]);
+    assert_eq!(array, fixed);
+    assert_eq!(fixed, array);
+    array.push(4).unwrap();
+    assert_ne!(array, fixed);
+    assert_ne!(fixed, array);
+}
+
+#[test]
+fn test_iter_and_iter_mut() {
+    let mut array = StdDynamicArray::<i32>::new();
+    assert_eq!(array.iter().count(), 0);
+    array.extend_from_slice(&[1, 2, 3]).unwrap();
+    for item in array.iter_mut() {
+        *item *= 10;
+    }
+    assert_eq!(array.iter().copied().collect::<Vec<_>>(), [10, 20, 30]);
+}
+
+#[rstest]
+#[case(0..2, &[1, 2, 3, 4, 1, 2])]
+#[case(1..=3, &[1, 2, 3, 4, 2, 3, 4])]
+#[case(.., &[1, 2, 3, 4, 1, 2, 3, 4])]
+#[case(2.., &[1, 2, 3, 4, 3, 4])]
+#[case(4.., &[1, 2, 3, 4])]
+fn test_extend_from_within<TRange: RangeBounds<usize>>(#[case] range: TRange, #[case] expected: &[i32]) {
+    let mut array = StdDynamicArray::<i32>::new();
+    array.extend_from_slice(&[1, 2, 3, 4]).unwrap();
+    array.shrink_to_fit().unwrap();
+    array.extend_from_within(range).unwrap();
+    assert_eq!(array.as_slice(), expected);
+}
+
+#[test]
+fn test_extend_from_within_repeated() {
+    let mut array = StdDynamicArray::<String>::new();
+    array.push("a".to_string()).unwrap();
+    for _ in 0..4 {
+        array.extend_from_within(..).unwrap();
+    }
+    assert_eq!(array.len().value(), 16);
+    assert!(array.iter().all(|item| item == "a"));
+}
+
+#[test]
+#[should_panic]
+fn test_extend_from_within_out_of_bounds() {
+    let mut array = StdDynamicArray::<i32>::new();
+    array.extend_from_slice(&[1, 2]).unwrap();
+    let _ = array.extend_from_within(1..3);
+}
+
+#[rstest]
+#[case(0, Err(0))]
+#[case(1, Ok(0))]
+#[case(2, Err(1))]
+#[case(3, Ok(1))]
+#[case(9, Err(6))]
+fn test_binary_search_by_key(#[case] id: u32, #[case] expected: Result<usize, usize>) {
+    let items = [(1u32, "a"), (3, "b"), (5, "c"), (5, "d"), (5, "e"), (7, "f")];
+    let mut array = StdDynamicArray::<(u32, &str)>::new();
+    array.extend_from_slice(&items).unwrap();
+    assert_eq!(array.binary_search_by_key(&id, |item| item.0), expected);
+    assert_eq!(
+        array.binary_search_by_key(&id, |item| item.0),
+        items.binary_search_by_key(&id, |item| item.0)
+    );
+}
+
+#[test]
+fn test_binary_search_by_key_duplicates() {
+    let items = [(1u32, "a"), (5, "b"), (5, "c"), (5, "d"), (7, "e")];
+    let mut array = StdDynamicArray::<(u32, &str)>::new();
+    array.extend_from_slice(&items).unwrap();
+    let index = array.binary_search_by_key(&5, |item| item.0).unwrap();
+    assert!((1..=3).contains(&index));
+    assert_eq!(Ok(index), items.binary_search_by_key(&5, |item| item.0));
+}
+
+#[test]
+fn test_push_front_and_pop_front() {
+    let mut array = StdDynamicArray::<String>::new();
+    assert_eq!(array.pop_front(), None);
+
+    array.push_front("b".to_string()).unwrap();
+    array.push("c".to_string()).unwrap();
+    array.push_front("a".to_string()).unwrap();
+    assert_eq!(array.as_slice(), ["a", "b", "c"]);
+
+    assert_eq!(array.pop_front().as_deref(), Some("a"));
+    array.push("d".to_string()).unwrap();
+    assert_eq!(array.pop().as_deref(), Some("d"));
+    array.push_front("z".to_string()).unwrap();
+    assert_eq!(array.as_slice(), ["z", "b", "c"]);
+
+    assert_eq!(array.pop_front().as_deref(), Some("z"));
+    assert_eq!(array.pop_front().as_deref(), Some("b"));
+    assert_eq!(array.pop_front().as_deref(), Some("c"));
+    assert_eq!(array.pop_front(), None);
+    assert!(array.is_empty());
+}
+
+#[test]
+fn test_push_front_grows() {
+    let mut array = StdDynamicArray::<i32>::new();
+    for value in 0..100 {
+        array.push_front(value).unwrap();
+    }
+    let expected: Vec<i32> = (0..100).rev().collect();
+    assert_eq!(array.as_slice(), expected.as_slice());
+}
+
+#[rstest]
+#[case(&[], 3, &[], &[])]
+#[case(&[1, 2, 3, 4, 5, 6], 3, &[&[1, 2, 3][..], &[4, 5, 6]], &[])]
+#[case(&[1, 2, 3, 4, 5, 6, 7, 8], 3, &[&[1, 2, 3][..], &[4, 5, 6]], &[7, 8])]
+#[case(&[1, 2], 3, &[], &[1, 2])]
+fn test_chunks_exact(
+    #[case] data: &[i32],
+    #[case] size: usize,
+    #[case] expected_chunks: &[&[i32]],
+    #[case] expected_remainder: &[i32],
+) {
+    let mut array = StdDynamicArray::<i32>::new();
+    array.extend_from_slice(data).unwrap();
+    let chunks = array.chunks_exact(size);
+    assert_eq!(chunks.remainder(), expected_remainder);
+    assert_eq!(chunks.len(), expected_chunks.len());
+    assert_eq!(chunks.collect::<Vec<_>>(), expected_chunks);
+}
+
+#[test]
+fn test_retain() {
+    let mut array = StdDynamicArray::<i32>::new();
+    array.extend_from_slice(&[1, 2, 3, 4, 5, 6, 7]).unwrap();
+    array.retain(|item| item % 3 != 0);
+    assert_eq!(array.as_slice(), &[1, 2, 4, 5, 7]);
+    array.retain(|_| false);
+    assert!(array.is_empty());
+}
+
+#[test]
+fn test_retain_mut() {
+    let mut array = StdDynamicArray::<String>::new();
+    for text in [" a ", "", "b", "  ", " c"] {
+        array.push(text.to_string()).unwrap();
+    }
+    array.retain_mut(|item| {
+        *item = item.trim().to_uppercase();
+        !item.is_empty()
+    });
+    assert_eq!(array.as_slice(), ["A", "B", "C"]);
+}
+
+#[test]
+fn test_retain_mut_drops() {
+    let counter = Arc::new(AtomicUsize::new(0));
+    let mut array = StdDynamicArray::<(usize, DropCounter)>::new();
+    for index in 0..10 {
+        array.push((index, DropCounter(counter.clone()))).unwrap();
+    }
+    array.retain_mut(|item| item.0 % 2 == 0);
+    assert_eq!(counter.load(Ordering::SeqCst), 5);
+    assert_eq!(array.iter().map(|item| item.0).collect::<Vec<_>>(), [0, 2, 4, 6, 8]);
+    drop(array);
+    assert_eq!(counter.load(Ordering::SeqCst), 10);
+}
+
+#[test]
+fn test_retain_mut_panic_keeps_remaining() {
+    let counter = Arc::new(AtomicUsize::new(0));
+    let mut array = StdDynamicArray::<(usize, DropCounter)>::new();
+    for index in 0..6 {
+        array.push((index, DropCounter(counter.clone()))).unwrap();
+    }
+
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        array.retain_mut(|item| {
+            assert!(item.0 != 3, "Boom");
+            item.0 != 1
+        });
+    }));
+    assert!(result.is_err());
+    assert_eq!(counter.load(Ordering::SeqCst), 1);
+    assert_eq!(array.iter().map(|item| item.0).collect::<Vec<_>>(), [0, 2, 3, 4, 5]);
+    drop(array);
+    assert_eq!(counter.load(Ordering::SeqCst), 6);
+}
+
+struct DropCounter(Arc<AtomicUsize>);
+
+impl Drop for DropCounter {
+    fn drop(&mut self) {
+        self.0.fetch_add(1, Ordering::SeqCst);
+    }
+}
+
+#[test]
+fn test_from_vec_is_zero_copy() {
+    let mut vec = Vec::with_capacity(10);
+    vec.extend(["a".to_string(), "b".to_string(), "c".to_string()]);
+    let vec_ptr = vec.as_ptr();
+
+    let array = StdDynamicArray::from(vec);
+    assert_eq!(array.as_slice(), ["a", "b", "c"]);
+    assert_eq!(array.capacity().value(), 10);
+    assert_eq!(array.as_ptr(), vec_ptr);
+
+    let vec: Vec<String> = array.into();
+    assert_eq!(vec, ["a", "b", "c"]);
+    assert_eq!(vec.capacity(), 10);
+    assert_eq!(vec.as_ptr(), vec_ptr);
+}
+
+#[test]
+fn test_vec_conversions_empty() {
+    let mut array = StdDynamicArray::from(Vec::<i32>::new());
+    assert!(array.is_empty());
+    array.push(1).unwrap();
+    assert_eq!(array.as_slice(), &[1]);
+
+    let vec: Vec<i32> = StdDynamicArray::<i32>::new().into();
+    assert!(vec.is_empty());
+}
+
+#[test]
+fn test_vec_conversions_roundtrip_after_growth() {
+    let mut array = StdDynamicArray::<i32>::new();
+    for value in 0..100 {
+        array.push(value).unwrap();
+    }
+    let mut vec: Vec<i32> = array.into();
+    vec.push(100);
+    assert_eq!(vec, (0..=100).collect::<Vec<_>>());
+
+    let mut array = StdDynamicArray::from(vec);
+    array.push(101).unwrap();
+    assert_eq!(array.as_slice(), (0..=101).collect::<Vec<_>>().as_slice());
+}
+
+#[test]
+fn test_leak() {
+    let mut array = StdDynamicArray::<String>::with_capacity(Length::try_from_i32(8).unwrap()).unwrap();
+    array.push("a".to_string()).unwrap();
+    array.push("b".to_string()).unwrap();
+
+    let leaked: &'static mut [String] = array.leak();
+    leaked[1].push('c');
+    assert_eq!(leaked, ["a", "bc"]);
+
+    let empty: &'static mut [i32] = StdDynamicArray::new().leak();
+    assert!(empty.is_empty());
+}
+
+#[test]
+fn test_sort_unstable() {
+    let mut array = StdDynamicArray::<i32>::new();
+    for value in [5, 3, 9, 1, 7] {
+        array.push(value).unwrap();
+    }
+    array.sort_unstable();
+    assert_eq!(array.as_slice(), &[1, 3, 5, 7, 9]);
+
+    array.sort_unstable_by(|left, right| right.cmp(left));
+    assert_eq!(array.as_slice(), &[9, 7, 5, 3, 1]);
+
+    array.sort_unstable_by_key(|value| -value.abs_diff(5).cast_signed());
+    assert_eq!(array[4], 5);
+}
+
+#[test]
+fn test_try_into_immutable() {
+    let mut array = StdDynamicArray::<String>::new();
+    for value in ["a", "b", "c"] {
+        array.push(value.to_string()).unwrap();
+    }
+
+    let immutable = array.try_into_immutable().unwrap();
+    assert_eq!(immutable.as_slice(), ["a", "b", "c"]);
+    assert_eq!(StdImmutableArray::strong_count(&immutable), 1);
+
+    let empty = StdDynamicArray::<i32>::new().try_into_immutable().unwrap();
+    assert!(empty.is_empty());
+}
+
+#[rstest]
+#[case(&[], &[], &[])]
+#[case(&[1], &[], &[1])]
+#[case(&[1, 2], &[[1, 2]], &[])]
+#[case(&[1, 2, 3, 4, 5], &[[1, 2], [3, 4]], &[5])]
+fn test_as_chunks(#[case] items: &[i32], #[case] expected_chunks: &[[i32; 2]], #[case] expected_remainder: &[i32]) {
+    let mut array = StdDynamicArray::<i32>::new();
+    array.extend_from_slice(items).unwrap();
+    let (chunks, remainder) = array.as_chunks::<2>();
+    assert_eq!(chunks, expected_chunks);
+    assert_eq!(remainder, expected_remainder);
+}
+
+#[rstest]
+#[case(&[], 3)]
+#[case(&[1, 2, 3], 0)]
+#[case(&[1, 2, 3], 2)]
+#[case(&[1, 2, 3], 10)]
+fn test_partition_point(#[case] items: &[i32], #[case] threshold: i32) {
+    let mut array = StdDynamicArray::<i32>::new();
+    array.extend_from_slice(items).unwrap();
+    let pred = |item: &i32| *item < threshold;
+    assert_eq!(array.partition_point(pred), items.partition_point(pred));
+}
+
+/// Rounds every allocation up to a multiple of 32 bytes, and reports that
+/// through `usable_size`.
+#[derive(Clone, Default, Debug)]
+struct RoundingAllocator;
+
+impl RoundingAllocator {
+    fn round(layout: Layout) -> Layout {
+        Layout::from_size_align(layout.size().next_multiple_of(32), layout.align()).unwrap()
+    }
+}
+
+unsafe impl Allocator for RoundingAllocator {
+    type ErrorDetails = ();
+
+    fn allocate(&self, layout: Layout) -> Result<NonNull<u8>, DetailedAllocationError<Self::ErrorDetails>> {
+        StdAllocator.allocate(Self::round(layout))
+    }
+
+    unsafe fn resize(
+        &self,
+        ptr: NonNull<u8>,
+        old_layout: Layout,
+        new_layout: Layout,
+    ) -> Result<NonNull<u8>, DetailedAllocationError<Self::ErrorDetails>> {
+        unsafe { StdAllocator.resize(ptr, Self::round(old_layout), Self::round(new_layout)) }
+    }
+
+    unsafe fn deallocate(&self, ptr: NonNull<u8>, layout: Layout) {
+        unsafe { StdAllocator.deallocate(ptr, Self::round(layout)) };
+    }
+
+    fn usable_size(&self, layout: Layout) -> usize {
+        Self::round(layout).size()
+    }
+
+    unsafe fn dangling<T: Sized>(&self) -> NonNull<T> {
+        unsafe { StdAllocator.dangling() }
+    }
+}
+
+#[test]
+fn test_capacity_uses_usable_size() {
+    let mut array = DynamicArray::<u32, RoundingAllocator>::with_capacity(Length::try_from_i32(3).unwrap()).unwrap();
+    assert_eq!(array.capacity().value(), 8);
+
+    for value in 0..8 {
+        array.push(value).unwrap();
+    }
+    assert_eq!(array.capacity().value(), 8);
+
+    array.push(8).unwrap();
+    assert!(array.capacity().value() >= 9);
+    assert_eq!(array.capacity().value() % 8, 0);
+    assert_eq!(array.as_slice(), &[0, 1, 2, 3, 4, 5, 6, 7, 8]);
+
+    array.shrink_to_fit().unwrap();
+    assert_eq!(array.capacity().value(), 9);
+    assert_eq!(array.as_slice(), &[0, 1, 2, 3, 4, 5, 6, 7, 8]);
+}
+
+#[test]
+fn test_capacity_with_default_usable_size() {
+    let array = StdDynamicArray::<u32>::with_capacity(Length::try_from_i32(3).unwrap()).unwrap();
+    assert_eq!(array.capacity().value(), 3);
+}
+
+#[rstest]
+#[case(&[], 0, Ok(0), &[])]
+#[case(&[1, 2, 3], 5, Ok(3), &[1, 2, 3, 0, 0])]
+#[case(&[1, 2, 3], 3, Ok(3), &[1, 2, 3])]
+#[case(&[1, 2, 3], 2, Err(3), &[1, 2])]
+#[case(&[1, 2, 3], 0, Err(3), &[])]
+fn test_copy_into(
+    #[case] items: &[i32],
+    #[case] dst_len: usize,
+    #[case] expected: Result<usize, usize>,
+    #[case] expected_dst: &[i32],
+) {
+    let mut array = StdDynamicArray::<i32>::new();
+    array.extend_from_slice(items).unwrap();
+    let mut dst = vec![0; dst_len];
+    assert_eq!(array.copy_into(&mut dst), expected);
+    assert_eq!(dst, expected_dst);
+}
@@ -147,6 +147,19 @@ fn test_out_of_range() {
     assert!(arr.pop().is_none());
 }
 
+#[rstest]
+#[case(1 << 32)]
+#[case((1 << 32) + 1)]
+#[case(u32::MAX as usize)]
+fn test_extend_from_slice_huge_input(#[case] count: usize) {
+    // Zero-sized items, so the huge slice does not allocate.
+    let input = vec![(); count];
+    let mut arr = FixedArray::<(), 3>::new();
+    arr.push(()).unwrap();
+    assert!(arr.extend_from_slice(&input).is_err());
+    assert_eq!(arr.len().value(), 1);
+}
+
 #[rstest]
 #[case(&[])]
 #[case(&[5])]
@@ -164,3 +177,66 @@ fn test_clone(#[case] input: &[i32]) {
     assert_eq!(arr.is_full(), arr2.is_full());
     assert_eq!(arr.is_empty(), arr2.is_empty());
 }
+
+#[test]
+fn test_spare_capacity_and_set_len() {
+    let mut array = FixedArray::<i32, 5>::from_array([1, 2]);
+    assert_eq!(array.as_ptr(), array.as_slice().as_ptr());
+
+    let spare = array.spare_capacity_mut();
+    assert_eq!(spare.len(), 3);
+    spare[0].write(3);
+    spare[1].write(4);
+    unsafe { array.set_len(Length::try_from_i32(4).unwrap()) };
+    assert_eq!(array.as_slice(), &[1, 2, 3, 4]);
+    assert_eq!(array.spare_capacity_mut().len(), 1);
+
+    unsafe { array.as_mut_ptr().write(10) };
+    assert_eq!(array.as_slice(), &[10, 2, 3, 4]);
+}
+
+#[test]
+fn test_iter_and_iter_mut() {
+    let mut array = FixedArray::<i32, 5>::from_array([1, 2, 3]);
+    for item in array.iter_mut() {
+        *item += 1;
+    }
+    assert_eq!(array.iter().copied().collect::<Vec<_>>(), [2, 3, 4]);
+    assert_eq!(array.iter().len(), 3);
+}
+
+#[test]
+fn test_binary_search_by_key() {
+    let array = FixedArray::<(u32, char), 8>::from_array([(2, 'a'), (4, 'b'), (4, 'c'), (8, 'd')]);
+    assert_eq!(array.binary_search_by_key(&8, |item| item.0), Ok(3));
+    assert_eq!(array.binary_search_by_key(&5, |item| item.0), Err(3));
+    assert_eq!(array.binary_search_by_key(&1, |item| item.0), Err(0));
+    assert_eq!(
+        array.binary_search_by_key(&4, |item| item.0),
+        array.as_slice().binary_search_by_key(&4, |item| item.0)
+    );
+}
+
+#[test]
+fn test_sort_unstable() {
+    let mut array = FixedArray::<i32, 8>::from_array([5, 3, 9, 1, 7]);
+    array.sort_unstable();
+    assert_eq!(array.as_slice(), &[1, 3, 5, 7, 9]);
+
+    array.sort_unstable_by(|left, right| right.cmp(left));
+    assert_eq!(array.as_slice(), &[9, 7, 5, 3, 1]);
+
+    array.sort_unstable_by_key(|value| value % 3);
+    assert!(array.is_sorted_by_key(|value| value % 3));
+}
+
+#[rstest]
+#[case(0)]
+#[case(4)]
+#[case(5)]
+#[case(100)]
+fn test_partition_point(#[case] threshold: i32) {
+    let array = FixedArray::<i32, 8>::from_array([1, 3, 5, 7, 9]);
+    let pred = |item: &i32| *item < threshold;
+    assert_eq!(array.partition_point(pred), array.as_slice().partition_point(pred));
+}
@@ -1,6 +1,6 @@
 #![cfg(feature = "std_alloc")]
 
-use osom_lib_arrays::{StdImmutableArray, StdImmutableArrayBuilder};
+use osom_lib_arrays::{StdDynamicArray, StdImmutableArray, StdImmutableArrayBuilder, errors::ArrayConstructionError};
 use osom_lib_primitives::Length;
 use rstest::rstest;
 
@@ -195,3 +195,344 @@ fn test_thread_safety() {
 
     assert_eq!(final_sum, 44955009);
 }
+
+#[rstest]
+#[case(&[])]
+#[case(&[1])]
+#[case(&[1, -5, 3, 4, -87, 6, 7, 8, 9, 3412])]
+fn test_to_dynamic_and_freeze_from(#[case] slice: &[i32]) {
+    let array = StdImmutableArray::from_slice(slice).unwrap();
+    let mut dynamic = array.to_dynamic().unwrap();
+    assert_eq!(dynamic.as_slice(), slice);
+    dynamic.push(17).unwrap();
+    assert_eq!(array.as_slice(), slice);
+
+    let frozen = StdImmutableArray::freeze_from(dynamic).unwrap();
+    assert_eq!(frozen.len().value() as usize, slice.len() + 1);
+    assert_eq!(&frozen.as_slice()[..slice.len()], slice);
+    assert_eq!(frozen.as_slice()[slice.len()], 17);
+    assert_eq!(StdImmutableArray::strong_count(&frozen), 1);
+    assert_eq!(StdImmutableArray::weak_count(&frozen), 1);
+}
+
+#[test]
+fn test_freeze_from_moves_items() {
+    struct CustomDrop {
+        drops_count: *mut i32,
+    }
+
+    impl Drop for CustomDrop {
+        fn drop(&mut self) {
+            unsafe {
+                *self.drops_count += 1;
+            }
+        }
+    }
+
+    let mut drops_count = Box::new(0);
+    let drops_count_ptr: *mut i32 = &mut *drops_count;
+
+    let mut dynamic = StdDynamicArray::new();
+    for _ in 0..5 {
+        dynamic
+            .push(CustomDrop {
+                drops_count: drops_count_ptr,
+            })
+            .unwrap();
+    }
+
+    let frozen = StdImmutableArray::freeze_from(dynamic).unwrap();
+    assert_eq!(frozen.len().value(), 5);
+    assert_eq!(unsafe { *drops_count_ptr }, 0);
+    drop(frozen);
+    assert_eq!(unsafe { *drops_count_ptr }, 5);
+    drop(drops_count);
+}
+
+#[test]
+fn test_sub_array() {
+    let array = new_array([1, 2, 3, 4, 5]);
+    let sub = StdImmutableArray::sub_array(
+        &array,
+        Length::try_from_i32(1).unwrap(),
+        Length::try_from_i32(3).unwrap(),
+    );
+    assert_eq!(sub.as_slice(), &[2, 3, 4]);
+    assert_eq!(StdImmutableArray::strong_count(&array), 2);
+    assert!(!StdImmutableArray::ref_equal(&array, &sub));
+
+    let sub_sub = StdImmutableArray::sub_array(&sub, Length::try_from_i32(2).unwrap(), Length::ONE);
+    assert_eq!(sub_sub.as_slice(), &[4]);
+    assert_eq!(StdImmutableArray::strong_count(&array), 3);
+
+    drop(array);
+    drop(sub);
+    assert_eq!(sub_sub.as_slice(), &[4]);
+    let weak = StdImmutableArray::downgrade(&sub_sub);
+    drop(sub_sub);
+    assert!(weak.upgrade().is_none());
+}
+
+#[test]
+#[should_panic(expected = "Sub array exceeds the length of the ImmutableArray.")]
+fn test_sub_array_out_of_range() {
+    let array = new_array([1, 2, 3]);
+    let _ = StdImmutableArray::sub_array(
+        &array,
+        Length::try_from_i32(2).unwrap(),
+        Length::try_from_i32(2).unwrap(),
+    );
+}
+
+#[test]
+fn test_try_from_fixed_and_dynamic_array_moves_items() {
+    use osom_lib_arrays::FixedArray;
+
+    struct CustomDrop {
+        drops_count: *mut i32,
+    }
+
+    impl Drop for CustomDrop {
+        fn drop(&mut self) {
+            unsafe {
+                *self.drops_count += 1;
+            }
+        }
+    }
+
+    let mut drops_count = Box::new(0);
+    let drops_count_ptr: *mut i32 = &mut *drops_count;
+
+    let mut fixed = FixedArray::<CustomDrop, 8>::new();
+    for _ in 0..3 {
+        fixed
+            .push(CustomDrop {
+                drops_count: drops_count_ptr,
+            })
+            .unwrap();
+    }
+    let from_fixed = StdImmutableArray::try_from(fixed).unwrap();
+    assert_eq!(from_fixed.len().value(), 3);
+    assert_eq!(unsafe { *drops_count_ptr }, 0);
+
+    let mut dynamic = StdDynamicArray::new();
+    for _ in 0..4 {
+        dynamic
+            .push(CustomDrop {
+                drops_count: drops_count_ptr,
+            })
+            .unwrap();
+    }
+    let from_dynamic = StdImmutableArray::try_from(dynamic).unwrap();
+    assert_eq!(from_dynamic.len().value(), 4);
+    assert_eq!(unsafe { *drops_count_ptr }, 0);
+
+    drop(from_fixed);
+    assert_eq!(unsafe { *drops_count_ptr }, 3);
+    drop(from_dynamic);
+    assert_eq!(unsafe { *drops_count_ptr }, 7);
+    drop(drops_count);
+}
+
+#[test]
+fn test_try_from_fixed_array_values() {
+    use osom_lib_arrays::FixedArray;
+
+    let fixed = FixedArray::<i32, 5>::from_array([1, 2, 3]);
+    let array: StdImmutableArray<i32> = fixed.try_into().unwrap();
+    assert_eq!(array.as_slice(), &[1, 2, 3]);
+    assert_eq!(StdImmutableArray::strong_count(&array), 1);
+}
+
+#[test]
+fn test_chunks_and_windows() {
+    let array = new_array([1, 2, 3, 4, 5]);
+    let sub = StdImmutableArray::sub_array(&array, Length::ONE, Length::try_from_i32(3).unwrap());
+    let chunks: Vec<&[i32]> = sub.chunks(2).collect();
+    assert_eq!(chunks, [&[2, 3][..], &[4]]);
+    let windows: Vec<&[i32]> = sub.windows(3).collect();
+    assert_eq!(windows, [&[2, 3, 4][..]]);
+}
+
+#[test]
+fn test_builder_try_extend() {
+    let mut builder = StdImmutableArray::<i32>::builder().unwrap();
+    builder.push(-1).unwrap();
+    builder.try_extend(0..40).unwrap();
+    builder.try_extend((0..5).filter(|x| x % 2 == 0)).unwrap();
+    assert_eq!(builder.len().value(), 44);
+    let array = builder.build();
+    let expected: Vec<i32> = [-1].into_iter().chain(0..40).chain([0, 2, 4]).collect();
+    assert_eq!(array.as_slice(), expected.as_slice());
+}
+
+#[test]
+fn test_cross_type_equality() {
+    let array = new_array([1, 2, 3]);
+    assert_eq!(array, [1, 2, 3]);
+    assert_eq!(array, &[1, 2, 3]);
+    assert_eq!(array, [1, 2, 3][..]);
+    assert_eq!(array, &[1, 2, 3][..]);
+    assert_ne!(array, [3, 2, 1]);
+
+    let sub = StdImmutableArray::sub_array(&array, Length::ONE, Length::try_from_i32(2).unwrap());
+    assert_eq!(sub, [2, 3]);
+}
+
+#[test]
+fn test_weak_ptr_eq_and_ref_equal() {
+    use osom_lib_arrays::StdImmutableWeakArray;
+
+    let array = new_array([1, 2, 3]);
+    let other = new_array([1, 2, 3]);
+    let weak = StdImmutableArray::downgrade(&array);
+    let weak_clone = weak.clone();
+    let other_weak = StdImmutableArray::downgrade(&other);
+    assert_eq!(StdImmutableArray::weak_count(&array), 3);
+    assert_eq!(weak.strong_count(), 1);
+
+    assert!(StdImmutableWeakArray::ptr_eq(&weak, &weak_clone));
+    assert!(!StdImmutableWeakArray::ptr_eq(&weak, &other_weak));
+    assert!(StdImmutableWeakArray::ref_equal(&weak, &array));
+    assert!(!StdImmutableWeakArray::ref_equal(&weak, &other));
+
+    let sub = StdImmutableArray::sub_array(&array, Length::ONE, Length::ONE);
+    let sub_weak = StdImmutableArray::downgrade(&sub);
+    assert!(!StdImmutableWeakArray::ptr_eq(&weak, &sub_weak));
+    assert!(StdImmutableWeakArray::ref_equal(&sub_weak, &sub));
+    assert!(!StdImmutableWeakArray::ref_equal(&sub_weak, &array));
+
+    drop(sub);
+    drop(array);
+    assert_eq!(weak.strong_count(), 0);
+    assert!(StdImmutableWeakArray::ptr_eq(&weak, &weak_clone));
+    assert!(weak.upgrade().is_none());
+    drop(weak);
+    assert_eq!(weak_clone.weak_count(), 2);
+}
+
+#[test]
+fn test_weak_with_upgraded() {
+    let array = new_array([1, 2, 3]);
+    let weak = StdImmutableArray::downgrade(&array);
+    let sum = weak.with_upgraded(|slice| {
+        assert_eq!(weak.strong_count(), 2);
+        slice.iter().sum::<i32>()
+    });
+    assert_eq!(sum, Some(6));
+    assert_eq!(StdImmutableArray::strong_count(&array), 1);
+
+    drop(array);
+    assert_eq!(weak.with_upgraded(|slice| slice.len()), None);
+}
+
+#[test]
+fn test_builder_build_counters() {
+    let mut builder: StdImmutableArrayBuilder<i32> = StdImmutableArrayBuilder::new().unwrap();
+    builder.extend_from_slice(&[1, 2, 3]).unwrap();
+    let array = builder.build();
+    assert_eq!(StdImmutableArray::strong_count(&array), 1);
+    assert_eq!(StdImmutableArray::weak_count(&array), 1);
+
+    let clone = array.clone();
+    assert_eq!(StdImmutableArray::strong_count(&array), 2);
+    drop(clone);
+    assert_eq!(StdImmutableArray::strong_count(&array), 1);
+}
+
+#[test]
+fn test_eq_shared_buffer() {
+    let array = StdImmutableArray::from_array([1.0, f64::NAN, 3.0]).unwrap();
+    let clone = array.clone();
+    assert!(array == clone);
+
+    let copy = StdImmutableArray::from_array([1.0, f64::NAN, 3.0]).unwrap();
+    assert!(array != copy);
+
+    let sub = StdImmutableArray::sub_array(&array, Length::ZERO, Length::try_from_i32(1).unwrap());
+    let other_sub = StdImmutableArray::sub_array(&clone, Length::ZERO, Length::try_from_i32(1).unwrap());
+    assert!(sub == other_sub);
+    assert!(sub != array);
+}
+
+#[rstest]
+#[case(&[], Some(&[1, 2, 3, 4][..]))]
+#[case(&[1, 2], Some(&[3, 4][..]))]
+#[case(&[1, 2, 3, 4], Some(&[][..]))]
+#[case(&[2], None)]
+#[case(&[1, 2, 3, 4, 5], None)]
+fn test_strip_prefix(#[case] prefix: &[i32], #[case] expected: Option<&[i32]>) {
+    let array = new_array([1, 2, 3, 4]);
+    let stripped = StdImmutableArray::strip_prefix(&array, prefix);
+    assert_eq!(stripped.as_ref().map(StdImmutableArray::as_slice), expected);
+    let expected_count = if expected.is_some() { 2 } else { 1 };
+    assert_eq!(StdImmutableArray::strong_count(&array), expected_count);
+}
+
+#[rstest]
+#[case(&[], Some(&[1, 2, 3, 4][..]))]
+#[case(&[3, 4], Some(&[1, 2][..]))]
+#[case(&[1, 2, 3, 4], Some(&[][..]))]
+#[case(&[3], None)]
+#[case(&[0, 1, 2, 3, 4], None)]
+fn test_strip_suffix(#[case] suffix: &[i32], #[case] expected: Option<&[i32]>) {
+    let array = new_array([1, 2, 3, 4]);
+    let stripped = StdImmutableArray::strip_suffix(&array, suffix);
+    assert_eq!(stripped.as_ref().map(StdImmutableArray::as_slice), expected);
+}
+
+#[rstest]
+#[case(0)]
+#[case(1)]
+#[case(17)]
+fn test_try_from_fn(#[case] len: usize) {
+    let array = StdImmutableArray::try_from_fn(len, |index| index * index).unwrap();
+    assert_eq!(array.len().value() as usize, len);
+    assert_eq!(
+        array.as_slice(),
+        (0..len).map(|index| index * index).collect::<Vec<_>>()
+    );
+    assert_eq!(StdImmutableArray::strong_count(&array), 1);
+    assert_eq!(StdImmutableArray::weak_count(&array), 1);
+}
+
+#[test]
+fn test_try_from_fn_too_long() {
+    let result = StdImmutableArray::try_from_fn(StdImmutableArray::<u8>::MAX_LENGTH + 1, |_| 0u8);
+    assert!(matches!(result, Err(ArrayConstructionError::ArrayTooLong)));
+}
+
+#[test]
+fn test_try_from_fn_panic_drops_prefix() {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    static DROPPED: AtomicUsize = AtomicUsize::new(0);
+
+    struct Counted(#[allow(dead_code)] usize);
+    impl Drop for Counted {
+        fn drop(&mut self) {
+            DROPPED.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    let result = std::panic::catch_unwind(|| {
+        StdImmutableArray::try_from_fn(10, |index| {
+            assert!(index < 4, "Boom.");
+            Counted(index)
+        })
+    });
+    assert!(result.is_err());
+    assert_eq!(DROPPED.load(Ordering::SeqCst), 4);
+}
+
+#[test]
+fn test_borrow_as_slice_key() {
+    let mut set = std::collections::HashSet::new();
+    set.insert(new_array([1, 2, 3]));
+    set.insert(new_array([4, 5]));
+
+    assert!(set.contains(&[1, 2, 3][..]));
+    assert!(set.contains(&[4, 5][..]));
+    assert!(!set.contains(&[1, 2][..]));
+    assert_eq!(set.get(&[4, 5][..]).unwrap().as_slice(), &[4, 5]);
+}
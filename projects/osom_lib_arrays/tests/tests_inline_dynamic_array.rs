@@ -144,3 +144,116 @@ fn test_fill_2() {
     assert_eq!(arr.as_slice(), &[-1, -2, 7, 7, 7, 7, 7, 7, 7, 7]);
     assert_eq!(*result, Length::try_from_i32(8).unwrap());
 }
+
+#[test]
+fn test_is_inlined() {
+    let mut array = StdInlineDynamicArray::<4, i32>::new();
+    assert_eq!(array.inline_capacity(), 4);
+    assert!(array.is_inlined());
+    for i in 0..4 {
+        array.push(i).unwrap();
+        assert!(array.is_inlined());
+    }
+    array.push(4).unwrap();
+    assert!(!array.is_inlined());
+    assert_eq!(array.inline_capacity(), 4);
+    assert!(array.capacity().value() > 4);
+    assert_eq!(array.as_slice(), &[0, 1, 2, 3, 4]);
+}
+
+#[test]
+fn test_iter_and_iter_mut() {
+    let mut array = StdInlineDynamicArray::<2, i32>::new();
+    for value in [1, 2, 3, 4] {
+        array.push(value).unwrap();
+        for item in array.iter_mut() {
+            *item += 1;
+        }
+    }
+    assert_eq!(array.iter().copied().collect::<Vec<_>>(), [5, 5, 5, 5]);
+}
+
+#[rstest]
+#[case::inlined(3)]
+#[case::heap(6)]
+fn test_swap_remove(#[case] count: i32) {
+    let mut array = StdInlineDynamicArray::<4, i32>::new();
+    for value in 0..count {
+        array.push(value).unwrap();
+    }
+    let was_inlined = array.is_inlined();
+
+    assert_eq!(array.swap_remove(count as usize), None);
+    assert_eq!(array.swap_remove(0), Some(0));
+    assert_eq!(array.as_slice()[0], count - 1);
+    assert_eq!(array.swap_remove(array.len().value() as usize - 1), Some(count - 2));
+    assert_eq!(array.len().value(), count - 2);
+    assert_eq!(array.is_inlined(), was_inlined);
+}
+
+#[rstest]
+#[case::inlined(3)]
+#[case::heap(10)]
+fn test_truncate(#[case] count: usize) {
+    let counter = Arc::new(AtomicUsize::new(0));
+    let mut array = StdInlineDynamicArray::<4, DropCounter>::new();
+    for _ in 0..count {
+        array.push(DropCounter(counter.clone())).unwrap();
+    }
+    let capacity = array.capacity();
+    let was_inlined = array.is_inlined();
+
+    array.truncate(count + 1);
+    assert_eq!(counter.load(Ordering::SeqCst), 0);
+
+    array.truncate(1);
+    assert_eq!(counter.load(Ordering::SeqCst), count - 1);
+    assert_eq!(array.len().value(), 1);
+    assert_eq!(array.capacity(), capacity);
+    assert_eq!(array.is_inlined(), was_inlined);
+
+    array.truncate(0);
+    assert!(array.is_empty());
+    assert_eq!(counter.load(Ordering::SeqCst), count);
+
+    drop(array);
+    assert_eq!(counter.load(Ordering::SeqCst), count);
+}
+
+struct DropCounter(Arc<AtomicUsize>);
+
+impl Drop for DropCounter {
+    fn drop(&mut self) {
+        self.0.fetch_add(1, Ordering::SeqCst);
+    }
+}
+
+#[test]
+fn test_from_array() {
+    let inlined = StdInlineDynamicArray::<4, String>::from_array(["a".to_string(), "b".to_string()]).unwrap();
+    assert!(inlined.is_inlined());
+    assert_eq!(inlined.as_slice(), ["a", "b"]);
+
+    let on_heap = StdInlineDynamicArray::<2, i32>::from_array([1, 2, 3, 4, 5]).unwrap();
+    assert!(!on_heap.is_inlined());
+    assert_eq!(on_heap.capacity().value(), 5);
+    assert_eq!(on_heap.as_slice(), &[1, 2, 3, 4, 5]);
+
+    let empty = StdInlineDynamicArray::<2, i32>::from_array([]).unwrap();
+    assert!(empty.is_empty());
+}
+
+#[rstest]
+#[case(&[])]
+#[case(&[1, 2])]
+#[case(&[1, 2, 3])]
+#[case(&[1, 2, 3, 4, 5, 6, 7])]
+fn test_from_slice(#[case] data: &[i32]) {
+    let mut array = StdInlineDynamicArray::<3, i32>::from_slice(data).unwrap();
+    assert_eq!(array.as_slice(), data);
+    assert_eq!(array.is_inlined(), data.len() <= 3);
+
+    array.push(100).unwrap();
+    assert_eq!(array.len().value() as usize, data.len() + 1);
+    assert_eq!(array[data.len()], 100);
+}
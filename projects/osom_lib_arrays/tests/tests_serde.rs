@@ -0,0 +1,86 @@
+#![cfg(all(feature = "std_alloc", feature = "serde"))]
+
+use osom_lib_arrays::{
+    FixedArray, StdArray, StdDeque, StdDynamicArray, StdImmutableArray, StdImmutableArrayBuilder, StdInlineDynamicArray,
+};
+use serde::{
+    Deserialize,
+    de::value::{self, SeqDeserializer},
+};
+use serde_test::{Token, assert_de_tokens, assert_de_tokens_error, assert_ser_tokens, assert_tokens};
+
+const TOKENS: [Token; 5] = [
+    Token::Seq { len: Some(3) },
+    Token::I32(1),
+    Token::I32(2),
+    Token::I32(3),
+    Token::SeqEnd,
+];
+
+fn new_dynamic_array() -> StdDynamicArray<i32> {
+    let mut array = StdDynamicArray::new();
+    array.extend_from_slice(&[1, 2, 3]).unwrap();
+    array
+}
+
+#[test]
+fn test_dynamic_array_serde() {
+    assert_tokens(&new_dynamic_array(), &TOKENS);
+    assert_tokens(
+        &StdDynamicArray::<i32>::new(),
+        &[Token::Seq { len: Some(0) }, Token::SeqEnd],
+    );
+}
+
+#[test]
+fn test_array_serde() {
+    let array: StdArray<i32> = new_dynamic_array().into_array().unwrap();
+    assert_ser_tokens(&array, &TOKENS);
+
+    let deserializer = SeqDeserializer::<_, value::Error>::new([1, 2, 3].into_iter());
+    let array = StdArray::<i32>::deserialize(deserializer).unwrap();
+    assert_eq!(array.as_slice(), &[1, 2, 3]);
+}
+
+#[test]
+fn test_fixed_array_serde() {
+    let array = FixedArray::<i32, 3>::from_array([1, 2, 3]);
+    assert_tokens(&array, &TOKENS);
+}
+
+#[test]
+fn test_fixed_array_deserialize_overflow() {
+    assert_de_tokens_error::<FixedArray<i32, 2>>(&TOKENS, "fixed array capacity exceeded");
+}
+
+#[test]
+fn test_inline_dynamic_array_serde() {
+    let mut array = StdInlineDynamicArray::<2, i32>::new();
+    for value in [1, 2, 3] {
+        array.push(value).unwrap();
+    }
+    assert_tokens(&array, &TOKENS);
+}
+
+#[test]
+fn test_deque_serde() {
+    let mut deque = StdDeque::new();
+    deque.push_back(2).unwrap();
+    deque.push_back(3).unwrap();
+    deque.push_front(1).unwrap();
+    assert_ser_tokens(&deque, &TOKENS);
+
+    let mut expected = StdDeque::new();
+    for value in [1, 2, 3] {
+        expected.push_back(value).unwrap();
+    }
+    assert_de_tokens(&expected, &TOKENS);
+}
+
+#[test]
+fn test_immutable_array_serde() {
+    let mut builder = StdImmutableArrayBuilder::new().unwrap();
+    builder.extend_from_slice(&[1, 2, 3]).unwrap();
+    let array: StdImmutableArray<i32> = builder.build();
+    assert_tokens(&array, &TOKENS);
+}
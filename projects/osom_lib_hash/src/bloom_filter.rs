@@ -0,0 +1,211 @@
+//! A module containing the implementation of the Bloom filter.
+#![allow(
+    clippy::cast_possible_truncation,
+    clippy::cast_precision_loss,
+    clippy::cast_sign_loss
+)]
+
+use core::hash::{BuildHasher, Hash, Hasher};
+
+use osom_lib_alloc::Allocator;
+use osom_lib_arrays::{DynamicArray, errors::ArrayConstructionError};
+
+const BITS_PER_WORD: usize = u64::BITS as usize;
+
+/// A probabilistic set, which answers whether an item is possibly
+/// in the set or definitely not in the set.
+///
+/// The bits are stored in a [`DynamicArray<u64>`][DynamicArray]. The `k` bit
+/// indices of an item are derived from two hashes produced by a single
+/// [`BuildHasher`], by using double hashing: `h1 + i * h2`.
+#[must_use]
+pub struct BloomFilter<TBuildHasher, TAllocator>
+where
+    TBuildHasher: BuildHasher,
+    TAllocator: Allocator,
+{
+    bits: DynamicArray<u64, TAllocator>,
+    bit_count: usize,
+    hash_count: u32,
+    hash_builder: TBuildHasher,
+}
+
+impl<TBuildHasher, TAllocator> BloomFilter<TBuildHasher, TAllocator>
+where
+    TBuildHasher: BuildHasher,
+    TAllocator: Allocator,
+{
+    /// Creates a new [`BloomFilter`] sized for `capacity` items with the
+    /// expected false positive rate `fpr`, with default hash builder and allocator.
+    ///
+    /// # Errors
+    ///
+    /// For details see [`ArrayConstructionError`].
+    ///
+    /// # Panics
+    ///
+    /// When `fpr` is not in the `(0, 1)` range.
+    #[inline(always)]
+    pub fn with_capacity_and_fpr(capacity: usize, fpr: f64) -> Result<Self, ArrayConstructionError>
+    where
+        TBuildHasher: Default,
+    {
+        Self::with_hasher_and_allocator(capacity, fpr, TBuildHasher::default(), TAllocator::default())
+    }
+
+    /// Creates a new [`BloomFilter`] sized for `capacity` items with the
+    /// expected false positive rate `fpr`.
+    ///
+    /// # Notes
+    ///
+    /// The number of bits is `m = -n * ln(fpr) / ln(2)^2` and the number of
+    /// hashes is `k = -log2(fpr)`, both rounded up. At least one word of bits
+    /// and at least one hash are always used.
+    ///
+    /// # Errors
+    ///
+    /// For details see [`ArrayConstructionError`].
+    ///
+    /// # Panics
+    ///
+    /// When `fpr` is not in the `(0, 1)` range.
+    pub fn with_hasher_and_allocator(
+        capacity: usize,
+        fpr: f64,
+        hash_builder: TBuildHasher,
+        allocator: TAllocator,
+    ) -> Result<Self, ArrayConstructionError> {
+        assert!(fpr > 0.0 && fpr < 1.0, "fpr has to be in the (0, 1) range.");
+
+        let minus_log2_fpr = -log2(fpr);
+        let bits_per_item = minus_log2_fpr / core::f64::consts::LN_2;
+        let bit_count = ceil_to_usize(capacity as f64 * bits_per_item);
+        let word_count = bit_count.div_ceil(BITS_PER_WORD).max(1);
+        if word_count > DynamicArray::<u64, TAllocator>::MAX_LENGTH {
+            return Err(ArrayConstructionError::ArrayTooLong);
+        }
+
+        let hash_count = ceil_to_usize(minus_log2_fpr).clamp(1, u32::MAX as usize) as u32;
+
+        let mut bits = DynamicArray::with_allocator(allocator);
+        bits.reserve_exact(word_count)?;
+        for _ in 0..word_count {
+            bits.push(0)?;
+        }
+
+        Ok(Self {
+            bits,
+            bit_count: word_count * BITS_PER_WORD,
+            hash_count,
+            hash_builder,
+        })
+    }
+
+    /// Returns the number of bits used by the [`BloomFilter`].
+    #[inline(always)]
+    #[must_use]
+    pub const fn bit_count(&self) -> usize {
+        self.bit_count
+    }
+
+    /// Returns the number of hashes (i.e. bits) used per item.
+    #[inline(always)]
+    #[must_use]
+    pub const fn hash_count(&self) -> u32 {
+        self.hash_count
+    }
+
+    /// Inserts `value` into the [`BloomFilter`].
+    pub fn insert<T: Hash + ?Sized>(&mut self, value: &T) {
+        let (h1, h2) = self.hashes(value);
+        let bit_count = self.bit_count as u64;
+        let words = self.bits.as_slice_mut();
+        for i in 0..u64::from(self.hash_count) {
+            let index = (h1.wrapping_add(i.wrapping_mul(h2)) % bit_count) as usize;
+            words[index / BITS_PER_WORD] |= 1 << (index % BITS_PER_WORD);
+        }
+    }
+
+    /// Checks whether `value` is possibly in the [`BloomFilter`].
+    ///
+    /// # Returns
+    ///
+    /// * `false` if `value` was definitely never inserted
+    /// * `true` if `value` was probably inserted
+    #[must_use]
+    pub fn contains<T: Hash + ?Sized>(&self, value: &T) -> bool {
+        let (h1, h2) = self.hashes(value);
+        let bit_count = self.bit_count as u64;
+        let words = self.bits.as_slice();
+        (0..u64::from(self.hash_count)).all(|i| {
+            let index = (h1.wrapping_add(i.wrapping_mul(h2)) % bit_count) as usize;
+            words[index / BITS_PER_WORD] & (1 << (index % BITS_PER_WORD)) != 0
+        })
+    }
+
+    /// Removes all items from the [`BloomFilter`]. Keeps the allocated memory.
+    pub fn clear(&mut self) {
+        self.bits.as_slice_mut().fill(0);
+    }
+
+    /// Produces the two base hashes for double hashing. The second one
+    /// is obtained by feeding the first one back to the same hasher,
+    /// and is forced to be odd, so that it is never zero.
+    fn hashes<T: Hash + ?Sized>(&self, value: &T) -> (u64, u64) {
+        let mut hasher = self.hash_builder.build_hasher();
+        value.hash(&mut hasher);
+        let h1 = hasher.finish();
+        hasher.write_u64(h1);
+        let h2 = hasher.finish() | 1;
+        (h1, h2)
+    }
+}
+
+/// Calculates `ceil(value)` for non-negative `value`, saturating at `usize::MAX`.
+fn ceil_to_usize(value: f64) -> usize {
+    if value >= usize::MAX as f64 {
+        return usize::MAX;
+    }
+    let truncated = value as usize;
+    if (truncated as f64) < value {
+        truncated + 1
+    } else {
+        truncated
+    }
+}
+
+/// Calculates `log2(value)` for positive, normal `value`. This is needed
+/// because `f64::log2` is not available in `no_std`.
+fn log2(value: f64) -> f64 {
+    let bits = value.to_bits();
+    let exponent = ((bits >> 52) & 0x7ff) as i32 - 1023;
+    let mantissa = f64::from_bits((bits & 0x000f_ffff_ffff_ffff) | 0x3ff0_0000_0000_0000);
+
+    // ln(m) = 2 * atanh(z) where z = (m - 1) / (m + 1). For m in [1, 2)
+    // we have z in [0, 1/3), so the series converges quickly.
+    let z = (mantissa - 1.0) / (mantissa + 1.0);
+    let z_squared = z * z;
+    let mut term = z;
+    let mut sum = 0.0;
+    let mut idx = 1.0;
+    while idx < 40.0 {
+        sum += term / idx;
+        term *= z_squared;
+        idx += 2.0;
+    }
+    f64::from(exponent) + 2.0 * sum / core::f64::consts::LN_2
+}
+
+#[cfg(feature = "std_alloc")]
+use osom_lib_alloc::StdAllocator;
+
+#[cfg(feature = "std_alloc")]
+use crate::hashers::Fnv1aHasherBuilder;
+
+#[cfg(feature = "std_alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std_alloc")))]
+/// Alias for [`BloomFilter`] with [`StdAllocator`] as the allocator
+/// and with [`Fnv1aHasherBuilder`] as the default hash builder.
+///
+/// This alias is available only if the `std_alloc` feature is enabled.
+pub type StdBloomFilter<TBuildHasher = Fnv1aHasherBuilder> = BloomFilter<TBuildHasher, StdAllocator>;
@@ -0,0 +1,218 @@
+//! A module containing the implementation of the frozen map.
+#![allow(clippy::cast_possible_truncation)]
+
+use core::borrow::Borrow;
+use core::hash::{BuildHasher, Hash};
+
+use osom_lib_alloc::Allocator;
+use osom_lib_arrays::{DynamicArray, ImmutableArray, errors::ArrayConstructionError};
+
+use crate::hashers::hash_one;
+
+/// An immutable hash map, built once from a slice of key-value pairs.
+/// After construction it supports lookups only.
+///
+/// # Notes
+///
+/// The entries are packed tightly, grouped by their bucket, in an
+/// [`ImmutableArray`]. A second [`ImmutableArray`] keeps the offset of
+/// each bucket. There are no empty slots and no tombstones, and a lookup
+/// only compares keys within a single bucket. The number of buckets
+/// is equal to the number of entries, so buckets are short on average.
+///
+/// Since the storage is made of [`ImmutableArray`]s, cloning is cheap.
+#[must_use]
+pub struct FrozenMap<TKey, TValue, TBuildHasher, TAllocator>
+where
+    TBuildHasher: BuildHasher,
+    TAllocator: Allocator,
+{
+    entries: ImmutableArray<(TKey, TValue), TAllocator>,
+    bucket_offsets: ImmutableArray<u32, TAllocator>,
+    hash_builder: TBuildHasher,
+}
+
+impl<TKey, TValue, TBuildHasher, TAllocator> FrozenMap<TKey, TValue, TBuildHasher, TAllocator>
+where
+    TKey: Hash + Eq + Clone,
+    TValue: Clone,
+    TBuildHasher: BuildHasher,
+    TAllocator: Allocator,
+{
+    /// Creates a new [`FrozenMap`] from the key-value pairs, with default
+    /// hash builder and allocator. Pairs are cloned into the map.
+    ///
+    /// # Notes
+    ///
+    /// If a key appears multiple times, lookups return the value of the
+    /// first occurrence.
+    ///
+    /// # Errors
+    ///
+    /// For details see [`ArrayConstructionError`].
+    #[inline(always)]
+    pub fn from_slice(items: &[(TKey, TValue)]) -> Result<Self, ArrayConstructionError>
+    where
+        TBuildHasher: Default,
+    {
+        Self::from_slice_with_hasher_and_allocator(items, TBuildHasher::default(), TAllocator::default())
+    }
+
+    /// Creates a new [`FrozenMap`] from the key-value pairs, with the given
+    /// hash builder and allocator. Pairs are cloned into the map.
+    ///
+    /// # Notes
+    ///
+    /// If a key appears multiple times, lookups return the value of the
+    /// first occurrence.
+    ///
+    /// # Errors
+    ///
+    /// For details see [`ArrayConstructionError`].
+    pub fn from_slice_with_hasher_and_allocator(
+        items: &[(TKey, TValue)],
+        hash_builder: TBuildHasher,
+        allocator: TAllocator,
+    ) -> Result<Self, ArrayConstructionError> {
+        let items_len = items.len();
+        if items_len > ImmutableArray::<(TKey, TValue), TAllocator>::MAX_LENGTH || items_len >= u32::MAX as usize {
+            return Err(ArrayConstructionError::ArrayTooLong);
+        }
+
+        let bucket_count = items_len.max(1);
+
+        let mut item_buckets = DynamicArray::<u32, TAllocator>::with_allocator(allocator.clone());
+        item_buckets.reserve_exact(items_len)?;
+        let mut bucket_offsets = DynamicArray::<u32, TAllocator>::with_allocator(allocator.clone());
+        bucket_offsets.reserve_exact(bucket_count + 1)?;
+        for _ in 0..=bucket_count {
+            bucket_offsets.push(0)?;
+        }
+
+        // Counting sort by bucket. First count the bucket sizes, then turn
+        // the counts into offsets, then place every item at its bucket's cursor.
+        for (key, _) in items {
+            let bucket = Self::bucket_of(&hash_builder, key, bucket_count);
+            item_buckets.push(bucket as u32)?;
+            bucket_offsets.as_slice_mut()[bucket + 1] += 1;
+        }
+        for idx in 1..=bucket_count {
+            let previous = bucket_offsets.as_slice()[idx - 1];
+            bucket_offsets.as_slice_mut()[idx] += previous;
+        }
+
+        let mut cursors = bucket_offsets.try_clone()?;
+        let mut order = DynamicArray::<u32, TAllocator>::with_allocator(allocator.clone());
+        order.reserve_exact(items_len)?;
+        for _ in 0..items_len {
+            order.push(0)?;
+        }
+        for (item_idx, bucket) in item_buckets.as_slice().iter().enumerate() {
+            let cursor = &mut cursors.as_slice_mut()[*bucket as usize];
+            order.as_slice_mut()[*cursor as usize] = item_idx as u32;
+            *cursor += 1;
+        }
+
+        let mut entries = DynamicArray::<(TKey, TValue), TAllocator>::with_allocator(allocator);
+        entries.reserve_exact(items_len)?;
+        for item_idx in order.as_slice() {
+            entries.push(items[*item_idx as usize].clone())?;
+        }
+
+        Ok(Self {
+            entries: ImmutableArray::freeze_from(entries)?,
+            bucket_offsets: ImmutableArray::freeze_from(bucket_offsets)?,
+            hash_builder,
+        })
+    }
+}
+
+impl<TKey, TValue, TBuildHasher, TAllocator> FrozenMap<TKey, TValue, TBuildHasher, TAllocator>
+where
+    TKey: Hash + Eq,
+    TBuildHasher: BuildHasher,
+    TAllocator: Allocator,
+{
+    /// Returns the number of entries in the [`FrozenMap`], including
+    /// duplicated keys.
+    #[inline(always)]
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.entries.as_slice().len()
+    }
+
+    /// Returns `true` if the [`FrozenMap`] has no entries.
+    #[inline(always)]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the value associated with `key`.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(&TValue)` if `key` is in the [`FrozenMap`]
+    /// * `None` otherwise
+    #[must_use]
+    pub fn get<TQuery>(&self, key: &TQuery) -> Option<&TValue>
+    where
+        TKey: Borrow<TQuery>,
+        TQuery: Hash + Eq + ?Sized,
+    {
+        let bucket_count = self.bucket_offsets.as_slice().len() - 1;
+        let bucket = Self::bucket_of(&self.hash_builder, key, bucket_count);
+        let offsets = self.bucket_offsets.as_slice();
+        let start = offsets[bucket] as usize;
+        let end = offsets[bucket + 1] as usize;
+        self.entries.as_slice()[start..end]
+            .iter()
+            .find(|(entry_key, _)| entry_key.borrow() == key)
+            .map(|(_, value)| value)
+    }
+
+    /// Returns `true` if `key` is in the [`FrozenMap`].
+    #[inline(always)]
+    #[must_use]
+    pub fn contains_key<TQuery>(&self, key: &TQuery) -> bool
+    where
+        TKey: Borrow<TQuery>,
+        TQuery: Hash + Eq + ?Sized,
+    {
+        self.get(key).is_some()
+    }
+
+    #[inline(always)]
+    fn bucket_of<TQuery: Hash + ?Sized>(hash_builder: &TBuildHasher, key: &TQuery, bucket_count: usize) -> usize {
+        (hash_one(hash_builder, key) % bucket_count as u64) as usize
+    }
+}
+
+impl<TKey, TValue, TBuildHasher, TAllocator> Clone for FrozenMap<TKey, TValue, TBuildHasher, TAllocator>
+where
+    TBuildHasher: BuildHasher + Clone,
+    TAllocator: Allocator,
+{
+    fn clone(&self) -> Self {
+        Self {
+            entries: self.entries.clone(),
+            bucket_offsets: self.bucket_offsets.clone(),
+            hash_builder: self.hash_builder.clone(),
+        }
+    }
+}
+
+#[cfg(feature = "std_alloc")]
+use osom_lib_alloc::StdAllocator;
+
+#[cfg(feature = "std_alloc")]
+use crate::hashers::Fnv1aHasherBuilder;
+
+#[cfg(feature = "std_alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std_alloc")))]
+/// Alias for [`FrozenMap`] with [`StdAllocator`] as the allocator
+/// and with [`Fnv1aHasherBuilder`] as the default hash builder.
+///
+/// This alias is available only if the `std_alloc` feature is enabled.
+pub type StdFrozenMap<TKey, TValue, TBuildHasher = Fnv1aHasherBuilder> =
+    FrozenMap<TKey, TValue, TBuildHasher, StdAllocator>;
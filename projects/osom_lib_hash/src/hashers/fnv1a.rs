@@ -53,6 +53,7 @@ impl Hasher for Fnv1aHasher {
     }
 }
 
+#[derive(Debug, Clone, Copy)]
 pub struct Fnv1aHasherBuilder;
 
 impl BuildHasher for Fnv1aHasherBuilder {
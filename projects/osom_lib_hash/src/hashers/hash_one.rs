@@ -0,0 +1,18 @@
+use core::hash::{BuildHasher, Hash};
+
+/// Hashes a single `value` with a fresh hasher built by `builder`.
+///
+/// # Notes
+///
+/// This is equivalent to [`BuildHasher::hash_one`], except it takes `value`
+/// by reference, and so works with unsized values as well. Hashing `&T`
+/// produces the same result as hashing `T`.
+#[inline]
+#[must_use]
+pub fn hash_one<T, TBuildHasher>(builder: &TBuildHasher, value: &T) -> u64
+where
+    T: Hash + ?Sized,
+    TBuildHasher: BuildHasher,
+{
+    builder.hash_one(value)
+}
@@ -3,3 +3,6 @@
 
 mod fnv1a;
 pub use fnv1a::*;
+
+mod hash_one;
+pub use hash_one::*;
@@ -13,4 +13,6 @@
 #![cfg_attr(docsrs, feature(doc_cfg))]
 #![cfg_attr(docsrs, allow(unused_attributes))]
 
+pub mod bloom_filter;
+pub mod frozen_map;
 pub mod hashers;
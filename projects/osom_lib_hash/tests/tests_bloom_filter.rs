@@ -0,0 +1,57 @@
+#![cfg(feature = "std_alloc")]
+
+use osom_lib_hash::bloom_filter::StdBloomFilter;
+use rstest::rstest;
+
+#[rstest]
+#[case(0.1, 4)]
+#[case(0.01, 7)]
+#[case(0.001, 10)]
+fn test_bloom_filter_parameters(#[case] fpr: f64, #[case] expected_hash_count: u32) {
+    let filter: StdBloomFilter = StdBloomFilter::with_capacity_and_fpr(1000, fpr).unwrap();
+    assert_eq!(filter.hash_count(), expected_hash_count);
+    let expected_bits = -1000.0 * fpr.ln() / (core::f64::consts::LN_2 * core::f64::consts::LN_2);
+    assert!(filter.bit_count() as f64 >= expected_bits);
+    assert!((filter.bit_count() as f64) < expected_bits + 64.0);
+    assert_eq!(filter.bit_count() % 64, 0);
+}
+
+#[test]
+fn test_bloom_filter_no_false_negatives() {
+    let mut filter: StdBloomFilter = StdBloomFilter::with_capacity_and_fpr(1000, 0.01).unwrap();
+    for i in 0..1000 {
+        filter.insert(&i);
+    }
+    for i in 0..1000 {
+        assert!(filter.contains(&i));
+    }
+}
+
+#[test]
+fn test_bloom_filter_false_positive_rate() {
+    let mut filter: StdBloomFilter = StdBloomFilter::with_capacity_and_fpr(1000, 0.01).unwrap();
+    for i in 0..1000 {
+        filter.insert(&i);
+    }
+    let false_positives = (1000..101000).filter(|i| filter.contains(i)).count();
+    assert!(false_positives < 3000, "Too many false positives: {false_positives}");
+}
+
+#[test]
+fn test_bloom_filter_clear() {
+    let mut filter: StdBloomFilter = StdBloomFilter::with_capacity_and_fpr(10, 0.01).unwrap();
+    assert!(!filter.contains("abc"));
+    filter.insert("abc");
+    assert!(filter.contains("abc"));
+    filter.clear();
+    assert!(!filter.contains("abc"));
+}
+
+#[rstest]
+#[case(0.0)]
+#[case(1.0)]
+#[case(-0.5)]
+#[should_panic(expected = "fpr has to be in the (0, 1) range.")]
+fn test_bloom_filter_invalid_fpr(#[case] fpr: f64) {
+    let _: StdBloomFilter = StdBloomFilter::with_capacity_and_fpr(10, fpr).unwrap();
+}
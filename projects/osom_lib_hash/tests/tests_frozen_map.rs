@@ -0,0 +1,46 @@
+#![cfg(feature = "std_alloc")]
+
+use osom_lib_hash::frozen_map::StdFrozenMap;
+use rstest::rstest;
+
+#[rstest]
+#[case(0)]
+#[case(1)]
+#[case(7)]
+#[case(1000)]
+fn test_frozen_map_get(#[case] size: i32) {
+    let items: Vec<(i32, i32)> = (0..size).map(|i| (i * 3, i)).collect();
+    let map: StdFrozenMap<i32, i32> = StdFrozenMap::from_slice(&items).unwrap();
+    assert_eq!(map.len(), size as usize);
+    assert_eq!(map.is_empty(), size == 0);
+    for i in 0..size {
+        assert_eq!(map.get(&(i * 3)), Some(&i));
+        assert!(!map.contains_key(&(i * 3 + 1)));
+    }
+    assert_eq!(map.get(&-1), None);
+}
+
+#[test]
+fn test_frozen_map_string_keys() {
+    let items = [
+        ("fn".to_string(), 1),
+        ("let".to_string(), 2),
+        ("match".to_string(), 3),
+        ("impl".to_string(), 4),
+    ];
+    let map: StdFrozenMap<String, i32> = StdFrozenMap::from_slice(&items).unwrap();
+    assert_eq!(map.get("let"), Some(&2));
+    assert_eq!(map.get("impl"), Some(&4));
+    assert_eq!(map.get("struct"), None);
+
+    let clone = map.clone();
+    drop(map);
+    assert_eq!(clone.get("fn"), Some(&1));
+}
+
+#[test]
+fn test_frozen_map_duplicates_first_wins() {
+    let map: StdFrozenMap<&str, i32> = StdFrozenMap::from_slice(&[("a", 1), ("b", 2), ("a", 3)]).unwrap();
+    assert_eq!(map.get("a"), Some(&1));
+    assert_eq!(map.get("b"), Some(&2));
+}
@@ -0,0 +1,29 @@
+use core::hash::{BuildHasher, Hasher};
+
+use osom_lib_hash::hashers::{Fnv1aHasher, Fnv1aHasherBuilder, hash_one};
+use rstest::rstest;
+
+#[rstest]
+#[case(0)]
+#[case(1)]
+#[case(-17)]
+#[case(i64::MAX)]
+fn test_hash_one_matches_manual_hashing(#[case] value: i64) {
+    let builder = Fnv1aHasherBuilder;
+    let mut hasher = builder.build_hasher();
+    core::hash::Hash::hash(&value, &mut hasher);
+    assert_eq!(hash_one(&builder, &value), hasher.finish());
+    assert_eq!(hash_one(&builder, &value), builder.hash_one(value));
+}
+
+#[test]
+fn test_hash_one_unsized() {
+    let builder = Fnv1aHasherBuilder;
+    assert_eq!(hash_one(&builder, "abc"), hash_one(&builder, "abc"));
+    assert_ne!(hash_one(&builder, "abc"), hash_one(&builder, "abd"));
+
+    let mut hasher = Fnv1aHasher::default();
+    hasher.write(b"abc");
+    hasher.write_u8(0xff);
+    assert_eq!(hash_one(&builder, "abc"), hasher.finish());
+}
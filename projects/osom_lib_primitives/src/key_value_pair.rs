@@ -15,8 +15,8 @@ impl<TKey, TValue> KeyValuePair<TKey, TValue> {
     /// Creates a new key-value pair from a tuple.
     #[inline(always)]
     pub const fn from_tuple(tuple: (TKey, TValue)) -> Self {
-        let key = unsafe { core::ptr::read(&tuple.0) };
-        let value = unsafe { core::ptr::read(&tuple.1) };
+        let key = unsafe { core::ptr::read(&raw const tuple.0) };
+        let value = unsafe { core::ptr::read(&raw const tuple.1) };
         let result = Self::new(key, value);
         core::mem::forget(tuple);
         result
@@ -46,12 +46,34 @@ impl<TKey, TValue> KeyValuePair<TKey, TValue> {
         &mut self.value
     }
 
+    /// Borrows both the key and the value, turning `&KeyValuePair<TKey, TValue>`
+    /// into `KeyValuePair<&TKey, &TValue>`.
+    #[inline(always)]
+    pub const fn as_ref(&self) -> KeyValuePair<&TKey, &TValue> {
+        KeyValuePair::new(&self.key, &self.value)
+    }
+
+    /// Borrows the key and mutably borrows the value, turning
+    /// `&mut KeyValuePair<TKey, TValue>` into `KeyValuePair<&TKey, &mut TValue>`.
+    ///
+    /// # Notes
+    ///
+    /// The key is borrowed immutably only, consistently with how trees
+    /// expose their pairs. Use [`key_mut`][`Self::key_mut`] to modify it.
+    #[inline(always)]
+    pub const fn as_mut(&mut self) -> KeyValuePair<&TKey, &mut TValue> {
+        KeyValuePair::new(&self.key, &mut self.value)
+    }
+
     /// Converts the key-value pair into a tuple.
     #[inline(always)]
     pub const fn into_tuple(self) -> (TKey, TValue) {
-        let key = unsafe { core::ptr::read(&self.key) };
-        let value = unsafe { core::ptr::read(&self.value) };
-        core::mem::forget(self);
+        // `self` is moved before the fields are read. Moving it afterwards,
+        // e.g. into `forget`, would invalidate `&mut` fields read out of it.
+        let this = core::mem::ManuallyDrop::new(self);
+        let ptr = core::ptr::from_ref(&this).cast::<Self>();
+        let key = unsafe { core::ptr::read(&raw const (*ptr).key) };
+        let value = unsafe { core::ptr::read(&raw const (*ptr).value) };
         (key, value)
     }
 }
@@ -29,8 +29,23 @@ pub enum LengthError {
 
     /// The length is negative.
     Negative,
+
+    /// The text is not a valid integer.
+    ParseError,
+}
+
+impl core::fmt::Display for LengthError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            LengthError::TooLarge => f.write_str("length exceeds the maximum length"),
+            LengthError::Negative => f.write_str("length is negative"),
+            LengthError::ParseError => f.write_str("length is not a valid integer"),
+        }
+    }
 }
 
+impl core::error::Error for LengthError {}
+
 impl Length {
     pub const MAX: usize = (i32::MAX - 1024) as usize;
     pub const ZERO: Self = unsafe { Self::new_unchecked(0) };
@@ -68,6 +83,22 @@ impl Length {
         Ok(unsafe { Self::new_unchecked(len) })
     }
 
+    /// Creates a new [`Length`] from a `i32`. Meant for constants and
+    /// literals, for runtime values prefer [`Length::try_from_i32`].
+    ///
+    /// # Panics
+    ///
+    /// When `len` is negative or exceeds [`Length::MAX`]. In const
+    /// context this is a compilation error.
+    #[inline(always)]
+    pub const fn of(len: i32) -> Self {
+        match Self::try_from_i32(len) {
+            Ok(length) => length,
+            Err(LengthError::Negative) => panic!("Length cannot be negative."),
+            Err(_) => panic!("Length exceeds the maximum length."),
+        }
+    }
+
     /// Creates a new [`Length`] from a `i32`.
     ///
     /// # Safety
@@ -86,6 +117,50 @@ impl Length {
         self.value
     }
 
+    /// Returns an iterator over all [`Length`] values from zero
+    /// (inclusive) up to `self` (exclusive).
+    #[inline(always)]
+    #[must_use]
+    pub fn range(self) -> impl DoubleEndedIterator<Item = Length> + ExactSizeIterator + Clone {
+        (0..self.value()).map(|value| unsafe { Self::new_unchecked(value) })
+    }
+
+    /// Returns the `0..self` range of `usize` indices, e.g. to index
+    /// slices with.
+    #[inline(always)]
+    #[must_use]
+    pub const fn indices(self) -> core::ops::Range<usize> {
+        0..self.value() as usize
+    }
+
+    /// Converts the [`Length`] to `u16`, clamping it to `u16::MAX`
+    /// if it doesn't fit.
+    #[inline(always)]
+    #[must_use]
+    pub const fn to_u16_saturating(self) -> u16 {
+        if self.value > u16::MAX as i32 {
+            u16::MAX
+        } else {
+            self.value as u16
+        }
+    }
+
+    /// Converts the [`Length`] to `u16`.
+    ///
+    /// # Returns
+    ///
+    /// * `Some` if the [`Length`] fits into `u16`
+    /// * `None` otherwise
+    #[inline(always)]
+    #[must_use]
+    pub const fn try_to_u16(self) -> Option<u16> {
+        if self.value > u16::MAX as i32 {
+            None
+        } else {
+            Some(self.value as u16)
+        }
+    }
+
     /// Increments the value of the [`Length`] by the given `value`.
     ///
     /// # Errors
@@ -143,6 +218,39 @@ impl TryFrom<i32> for Length {
     }
 }
 
+impl core::str::FromStr for Length {
+    type Err = LengthError;
+
+    /// Parses a decimal integer into a [`Length`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`LengthError::ParseError`] if `s` is not a valid integer.
+    /// Otherwise see [`try_from_usize`][`Length::try_from_usize`].
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let value = match i64::from_str(s) {
+            Ok(value) => value,
+            Err(error) => {
+                return Err(match error.kind() {
+                    core::num::IntErrorKind::PosOverflow => LengthError::TooLarge,
+                    core::num::IntErrorKind::NegOverflow => LengthError::Negative,
+                    _ => LengthError::ParseError,
+                });
+            }
+        };
+
+        if value < 0 {
+            return Err(LengthError::Negative);
+        }
+
+        if value as u64 > Self::MAX as u64 {
+            return Err(LengthError::TooLarge);
+        }
+
+        Ok(unsafe { Self::new_unchecked(value as i32) })
+    }
+}
+
 impl From<Length> for usize {
     fn from(value: Length) -> Self {
         value.value() as usize
@@ -0,0 +1,51 @@
+use crate::helpers::gen_ratio;
+use crate::traits::{Distribution, PseudoRandomNumberGenerator};
+
+/// Bernoulli distribution, i.e. a single trial that succeeds with
+/// probability exactly `numerator / denominator`.
+///
+/// Sampling is exact, for details see [`gen_ratio`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[must_use]
+pub struct Bernoulli {
+    numerator: u32,
+    denominator: u32,
+}
+
+impl Bernoulli {
+    /// Creates a new [`Bernoulli`] distribution with success
+    /// probability `numerator / denominator`.
+    ///
+    /// # Panics
+    ///
+    /// When `denominator` is zero or `numerator > denominator`.
+    #[inline]
+    pub fn from_ratio(numerator: u32, denominator: u32) -> Self {
+        assert!(
+            denominator > 0 && numerator <= denominator,
+            "Bernoulli requires 0 <= numerator <= denominator and denominator > 0."
+        );
+        Self { numerator, denominator }
+    }
+
+    /// Returns the numerator of the success probability.
+    #[inline(always)]
+    #[must_use]
+    pub const fn numerator(&self) -> u32 {
+        self.numerator
+    }
+
+    /// Returns the denominator of the success probability.
+    #[inline(always)]
+    #[must_use]
+    pub const fn denominator(&self) -> u32 {
+        self.denominator
+    }
+}
+
+impl Distribution<bool> for Bernoulli {
+    #[inline]
+    fn sample<TGenerator: PseudoRandomNumberGenerator>(&self, generator: &mut TGenerator) -> bool {
+        gen_ratio(generator, self.numerator, self.denominator)
+    }
+}
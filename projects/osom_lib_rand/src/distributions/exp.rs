@@ -0,0 +1,44 @@
+use crate::helpers::next_f64;
+use crate::traits::{Distribution, PseudoRandomNumberGenerator};
+
+/// Exponential distribution with given rate `lambda`.
+///
+/// Sampled with the inverse transform method.
+///
+/// This struct is available only if the `libm` feature is enabled.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[must_use]
+pub struct Exp {
+    lambda: f64,
+}
+
+impl Exp {
+    /// Creates a new [`Exp`] distribution.
+    ///
+    /// # Panics
+    ///
+    /// When `lambda` is not positive or not finite.
+    #[inline]
+    pub fn new(lambda: f64) -> Self {
+        assert!(
+            lambda.is_finite() && lambda > 0.0,
+            "Exp requires finite, positive lambda."
+        );
+        Self { lambda }
+    }
+
+    /// Returns the rate of the distribution.
+    #[inline(always)]
+    #[must_use]
+    pub const fn lambda(&self) -> f64 {
+        self.lambda
+    }
+}
+
+impl Distribution<f64> for Exp {
+    fn sample<TGenerator: PseudoRandomNumberGenerator>(&self, generator: &mut TGenerator) -> f64 {
+        // The argument is in `(0, 1]` range, so that logarithm is always finite.
+        let value = 1.0 - next_f64(generator);
+        -libm::log(value) / self.lambda
+    }
+}
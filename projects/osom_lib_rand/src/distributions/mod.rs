@@ -0,0 +1,23 @@
+//! Holds implementations of several probability distributions.
+mod uniform_int;
+pub use uniform_int::*;
+
+mod bernoulli;
+pub use bernoulli::*;
+
+mod weighted_index;
+pub use weighted_index::*;
+
+#[cfg(feature = "libm")]
+mod normal;
+
+#[cfg(feature = "libm")]
+#[cfg_attr(docsrs, doc(cfg(feature = "libm")))]
+pub use normal::*;
+
+#[cfg(feature = "libm")]
+mod exp;
+
+#[cfg(feature = "libm")]
+#[cfg_attr(docsrs, doc(cfg(feature = "libm")))]
+pub use exp::*;
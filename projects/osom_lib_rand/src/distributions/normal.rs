@@ -0,0 +1,56 @@
+use crate::helpers::next_f64;
+use crate::traits::{Distribution, PseudoRandomNumberGenerator};
+
+/// Normal (Gaussian) distribution with given mean and standard deviation.
+///
+/// Sampled with the Box–Muller transform.
+///
+/// This struct is available only if the `libm` feature is enabled.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[must_use]
+pub struct Normal {
+    mean: f64,
+    std_dev: f64,
+}
+
+impl Normal {
+    /// Creates a new [`Normal`] distribution.
+    ///
+    /// # Panics
+    ///
+    /// When `mean` is not finite, or when `std_dev` is negative or not finite.
+    #[inline]
+    pub fn new(mean: f64, std_dev: f64) -> Self {
+        assert!(mean.is_finite(), "Normal requires finite mean.");
+        assert!(
+            std_dev.is_finite() && std_dev >= 0.0,
+            "Normal requires finite, non-negative std_dev."
+        );
+        Self { mean, std_dev }
+    }
+
+    /// Returns the mean of the distribution.
+    #[inline(always)]
+    #[must_use]
+    pub const fn mean(&self) -> f64 {
+        self.mean
+    }
+
+    /// Returns the standard deviation of the distribution.
+    #[inline(always)]
+    #[must_use]
+    pub const fn std_dev(&self) -> f64 {
+        self.std_dev
+    }
+}
+
+impl Distribution<f64> for Normal {
+    fn sample<TGenerator: PseudoRandomNumberGenerator>(&self, generator: &mut TGenerator) -> f64 {
+        // `first` is in `(0, 1]` range, so that logarithm is always finite.
+        let first = 1.0 - next_f64(generator);
+        let second = next_f64(generator);
+        let radius = libm::sqrt(-2.0 * libm::log(first));
+        let standard = radius * libm::cos(core::f64::consts::TAU * second);
+        self.mean + self.std_dev * standard
+    }
+}
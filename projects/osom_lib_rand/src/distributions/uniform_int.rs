@@ -0,0 +1,45 @@
+use crate::helpers::next_in_range;
+use crate::number::Number;
+use crate::traits::{Distribution, PseudoRandomNumberGenerator};
+
+/// Uniform distribution over integers in the `[low, high)` range.
+///
+/// Sampling is unbiased, for details see [`next_in_range`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[must_use]
+pub struct UniformInt<ANumber: Number> {
+    low: ANumber,
+    high: ANumber,
+}
+
+impl<ANumber: Number> UniformInt<ANumber> {
+    /// Creates a new [`UniformInt`] distribution over `[low, high)`.
+    ///
+    /// # Panics
+    ///
+    /// When `low >= high`.
+    #[inline]
+    pub fn new(low: ANumber, high: ANumber) -> Self {
+        assert!(low < high, "UniformInt requires low < high.");
+        Self { low, high }
+    }
+
+    /// Returns the inclusive lower bound of the distribution.
+    #[inline(always)]
+    pub const fn low(&self) -> ANumber {
+        self.low
+    }
+
+    /// Returns the exclusive upper bound of the distribution.
+    #[inline(always)]
+    pub const fn high(&self) -> ANumber {
+        self.high
+    }
+}
+
+impl<ANumber: Number> Distribution<ANumber> for UniformInt<ANumber> {
+    #[inline]
+    fn sample<TGenerator: PseudoRandomNumberGenerator>(&self, generator: &mut TGenerator) -> ANumber {
+        next_in_range(generator, self.low, self.high)
+    }
+}
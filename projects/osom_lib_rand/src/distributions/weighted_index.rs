@@ -0,0 +1,86 @@
+use crate::helpers::next_in_range;
+use crate::traits::{Distribution, PseudoRandomNumberGenerator};
+
+/// Distribution over indexes `[0, len)`, with probability of each index
+/// proportional to its weight.
+///
+/// # Notes
+///
+/// The prefix sums of the weights are stored in a scratch slice supplied
+/// by the caller, and so this type does not allocate. Each draw picks
+/// a number from `[0, total)` with [`next_in_range`] and binary searches
+/// the prefix sums, i.e. it is `O(log len)`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[must_use]
+pub struct WeightedIndex<'a> {
+    cumulative_weights: &'a [u64],
+}
+
+impl<'a> WeightedIndex<'a> {
+    /// Creates a new [`WeightedIndex`] distribution, by writing the prefix
+    /// sums of `weights` into `cumulative_weights`. The `weights` are left
+    /// untouched.
+    ///
+    /// # Returns
+    ///
+    /// * `None` if `weights` is empty or all the weights are zero.
+    /// * `Some(WeightedIndex)` otherwise. Indexes with zero weight are never picked.
+    ///
+    /// # Panics
+    ///
+    /// When `weights.len() != cumulative_weights.len()` or when the total
+    /// of weights does not fit into `u64`.
+    pub fn from_weights(weights: &[u64], cumulative_weights: &'a mut [u64]) -> Option<Self> {
+        assert_eq!(
+            weights.len(),
+            cumulative_weights.len(),
+            "WeightedIndex requires the same number of weights and cumulative weights."
+        );
+
+        let mut total = 0u64;
+        for (weight, cumulative) in weights.iter().zip(cumulative_weights.iter_mut()) {
+            total = total
+                .checked_add(*weight)
+                .expect("WeightedIndex requires the total of weights to fit into u64.");
+            *cumulative = total;
+        }
+
+        if total == 0 {
+            return None;
+        }
+
+        Some(Self { cumulative_weights })
+    }
+
+    /// Returns the number of indexes, i.e. the number of the original weights.
+    #[inline(always)]
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.cumulative_weights.len()
+    }
+
+    /// Returns the total of all the weights.
+    #[inline(always)]
+    #[must_use]
+    pub const fn total(&self) -> u64 {
+        self.cumulative_weights[self.cumulative_weights.len() - 1]
+    }
+
+    /// Returns the prefix sums of the weights.
+    #[inline(always)]
+    #[must_use]
+    pub const fn cumulative_weights(&self) -> &'a [u64] {
+        self.cumulative_weights
+    }
+}
+
+impl Distribution<usize> for WeightedIndex<'_> {
+    #[inline]
+    fn sample<TGenerator: PseudoRandomNumberGenerator>(&self, generator: &mut TGenerator) -> usize {
+        let value = next_in_range(generator, 0u64, self.total());
+        // The first prefix sum above `value`. Zero weights repeat the previous
+        // prefix sum, and so they are skipped.
+        self.cumulative_weights
+            .partition_point(|cumulative| *cumulative <= value)
+    }
+}
@@ -0,0 +1,22 @@
+//! Holds all custom errors for that crate.
+
+/// Represents an error that occurs when a randomness source fails
+/// to produce random data.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[must_use]
+#[repr(u8)]
+pub enum RandError {
+    /// The underlying source of entropy, e.g. the operating system,
+    /// is unavailable or failed to provide random data.
+    SourceUnavailable,
+}
+
+impl core::fmt::Display for RandError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            RandError::SourceUnavailable => f.write_str("the source of randomness is unavailable"),
+        }
+    }
+}
+
+impl core::error::Error for RandError {}
@@ -0,0 +1,247 @@
+//! Holds free standing helpers built on top of [`PseudoRandomNumberGenerator`].
+use crate::number::Number;
+use crate::traits::PseudoRandomNumberGenerator;
+
+/// Generates a random [`Number`] of arbitrary type, regardless of
+/// the type the generator natively produces.
+#[inline]
+#[must_use]
+pub fn next_number_of<ANumber: Number, TGenerator: PseudoRandomNumberGenerator>(generator: &mut TGenerator) -> ANumber {
+    let mut bytes = ANumber::ByteRepr::default();
+    generator.fill_bytes(bytes.as_mut());
+    ANumber::from_bytes(bytes.as_ref())
+}
+
+/// Generates a uniformly distributed random `f64` in the `[0, 1)` range.
+///
+/// # Notes
+///
+/// Uses 53 random bits, i.e. the full precision of `f64` mantissa.
+/// All produced values are multiples of `2^-53`.
+#[inline]
+#[must_use]
+#[allow(clippy::cast_precision_loss)]
+pub fn next_f64<TGenerator: PseudoRandomNumberGenerator>(generator: &mut TGenerator) -> f64 {
+    const SCALE: f64 = 1.0 / ((1u64 << 53) as f64);
+    let value: u64 = next_number_of(generator);
+    ((value >> 11) as f64) * SCALE
+}
+
+/// Generates a uniformly distributed random number in the `[low, high)` range.
+///
+/// # Notes
+///
+/// The result is unbiased: numbers that would skew the distribution
+/// (the ones below `2^bits % (high - low)`) are rejected and redrawn.
+///
+/// # Panics
+///
+/// When `low >= high`.
+#[must_use]
+pub fn next_in_range<ANumber: Number, TGenerator: PseudoRandomNumberGenerator>(
+    generator: &mut TGenerator,
+    low: ANumber,
+    high: ANumber,
+) -> ANumber {
+    assert!(low < high, "next_in_range requires low < high.");
+    let range = high.wrapping_sub(low);
+    let threshold = ANumber::ZERO.wrapping_sub(range).wrapping_rem(range);
+    loop {
+        let value: ANumber = next_number_of(generator);
+        if value >= threshold {
+            return low.wrapping_add(value.wrapping_rem(range));
+        }
+    }
+}
+
+/// Returns `true` with probability exactly `numerator / denominator`.
+///
+/// # Notes
+///
+/// Draws a number from `[0, denominator)` with [`next_in_range`] and checks
+/// whether it is below `numerator`. Unlike comparing against a random `f64`,
+/// this introduces no rounding.
+///
+/// # Panics
+///
+/// When `denominator` is zero. In debug builds also when
+/// `numerator > denominator`, otherwise such ratio always yields `true`.
+#[must_use]
+pub fn gen_ratio<TGenerator: PseudoRandomNumberGenerator>(
+    generator: &mut TGenerator,
+    numerator: u32,
+    denominator: u32,
+) -> bool {
+    assert!(denominator > 0, "gen_ratio requires non-zero denominator.");
+    debug_assert!(numerator <= denominator, "gen_ratio requires numerator <= denominator.");
+    next_in_range(generator, 0u32, denominator) < numerator
+}
+
+/// Generates an array of `N` random bytes.
+///
+/// # Notes
+///
+/// This is a thin wrapper around [`PseudoRandomNumberGenerator::fill_bytes`],
+/// and so identically seeded generators produce identical arrays.
+#[inline]
+#[must_use]
+pub fn gen_array<const N: usize, TGenerator: PseudoRandomNumberGenerator>(generator: &mut TGenerator) -> [u8; N] {
+    let mut result = [0u8; N];
+    generator.fill_bytes(&mut result);
+    result
+}
+
+/// Fills the entire [`FixedArray`][`osom_lib_arrays::FixedArray`] with random bytes.
+/// Existing items are overwritten and the remaining capacity is filled up,
+/// so that afterwards `array.is_full()` holds.
+///
+/// This function is available only if the `arrays` feature is enabled.
+#[cfg(feature = "arrays")]
+#[cfg_attr(docsrs, doc(cfg(feature = "arrays")))]
+pub fn fill_fixed_array<const N: usize, TGenerator: PseudoRandomNumberGenerator>(
+    generator: &mut TGenerator,
+    array: &mut osom_lib_arrays::FixedArray<u8, N>,
+) {
+    while !array.is_full() {
+        // Cannot fail, the array is not full.
+        let _ = array.push(0);
+    }
+    generator.fill_bytes(array.as_mut_slice());
+}
+
+/// Generates a uniformly distributed random signed number in the `[low, high)` range.
+///
+/// The range is shifted into `[0, high - low)` unsigned range, sampled with
+/// [`next_in_range`] and shifted back. Both shifts are done with wrapping
+/// arithmetic, and so the widest `[i64::MIN, i64::MAX)` span is handled
+/// without overflows.
+///
+/// # Notes
+///
+/// Since `high` is exclusive, `i64::MAX` can never be produced. Use
+/// [`next_signed_in_inclusive_range`] for that. Narrower signed types
+/// (e.g. `i32`) can be sampled by casting the bounds to `i64` and
+/// the result back.
+///
+/// # Panics
+///
+/// When `low >= high`.
+#[must_use]
+#[allow(clippy::cast_sign_loss, clippy::cast_possible_wrap)]
+pub fn next_signed_in_range<TGenerator: PseudoRandomNumberGenerator>(
+    generator: &mut TGenerator,
+    low: i64,
+    high: i64,
+) -> i64 {
+    assert!(low < high, "next_signed_in_range requires low < high.");
+    let range = high.wrapping_sub(low) as u64;
+    let offset = next_in_range(generator, 0u64, range);
+    low.wrapping_add(offset as i64)
+}
+
+/// Generates a uniformly distributed random signed number in the `[low, high]` range.
+///
+/// Works like [`next_signed_in_range`], except that `high` is included.
+/// In particular the full `i64::MIN..=i64::MAX` span is supported, in which
+/// case all 64 bits of a single random number are used directly.
+///
+/// # Panics
+///
+/// When `low > high`.
+#[must_use]
+#[allow(clippy::cast_sign_loss, clippy::cast_possible_wrap)]
+pub fn next_signed_in_inclusive_range<TGenerator: PseudoRandomNumberGenerator>(
+    generator: &mut TGenerator,
+    low: i64,
+    high: i64,
+) -> i64 {
+    assert!(low <= high, "next_signed_in_inclusive_range requires low <= high.");
+    let range = high.wrapping_sub(low) as u64;
+    if range == u64::MAX {
+        let value: u64 = next_number_of(generator);
+        return value as i64;
+    }
+    let offset = next_in_range(generator, 0u64, range + 1);
+    low.wrapping_add(offset as i64)
+}
+
+/// Generates a uniformly distributed random Unicode scalar value, i.e.
+/// a code point from the `[0, 0x110000)` range excluding surrogates
+/// (`0xD800..=0xDFFF`).
+///
+/// # Notes
+///
+/// Surrogates are rejected and redrawn, so every valid [`char`] is
+/// equally likely.
+#[must_use]
+pub fn gen_char<TGenerator: PseudoRandomNumberGenerator>(generator: &mut TGenerator) -> char {
+    loop {
+        let code_point = next_in_range(generator, 0u32, 0x110000);
+        if let Some(result) = char::from_u32(code_point) {
+            return result;
+        }
+    }
+}
+
+/// Generates a uniformly distributed random ASCII character,
+/// i.e. a [`char`] from the `[0, 0x80)` range, including control characters.
+#[must_use]
+#[allow(clippy::cast_possible_truncation)]
+pub fn gen_ascii_char<TGenerator: PseudoRandomNumberGenerator>(generator: &mut TGenerator) -> char {
+    let code_point = next_in_range(generator, 0u32, 0x80);
+    char::from(code_point as u8)
+}
+
+/// Picks a random item, with probability of each item proportional
+/// to its weight.
+///
+/// # Returns
+///
+/// * `None` if `items` is empty or all the weights are zero.
+/// * `Some(&item)` otherwise. Items with zero weight are never picked.
+///
+/// # Notes
+///
+/// This function does not allocate and does not modify `weights`. Instead
+/// of storing the prefix sums it walks the weights twice: once to compute
+/// the total, and once to find the item the random number in `[0, total)`
+/// falls into. For repeated draws over the same weights build
+/// [`WeightedIndex`][crate::distributions::WeightedIndex] once, with a scratch slice for the prefix sums, and
+/// sample it directly.
+///
+/// # Panics
+///
+/// When `items.len() != weights.len()` or when the total of weights
+/// does not fit into `u64`.
+pub fn weighted_choice<'a, T, TGenerator: PseudoRandomNumberGenerator>(
+    items: &'a [T],
+    weights: &[u64],
+    generator: &mut TGenerator,
+) -> Option<&'a T> {
+    assert_eq!(
+        items.len(),
+        weights.len(),
+        "weighted_choice requires the same number of items and weights."
+    );
+
+    let mut total = 0u64;
+    for weight in weights {
+        total = total
+            .checked_add(*weight)
+            .expect("weighted_choice requires the total of weights to fit into u64.");
+    }
+
+    if total == 0 {
+        return None;
+    }
+
+    let mut value = next_in_range(generator, 0u64, total);
+    for (item, weight) in items.iter().zip(weights) {
+        if value < *weight {
+            return Some(item);
+        }
+        value -= *weight;
+    }
+
+    unreachable!("The drawn value is always below the total of weights.");
+}
@@ -12,6 +12,9 @@
 #![no_std]
 #![cfg_attr(docsrs, feature(doc_cfg))]
 #![cfg_attr(docsrs, allow(unused_attributes))]
+pub mod distributions;
+pub mod errors;
+pub mod helpers;
 pub mod number;
 pub mod pseudo_random_number_generators;
 pub mod randomness_sources;
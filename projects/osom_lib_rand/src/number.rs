@@ -5,6 +5,7 @@
 use core::fmt::{Debug, Display};
 use core::hash::Hash;
 use core::mem::size_of;
+use core::ops::BitXor;
 
 trait Private {}
 
@@ -27,7 +28,19 @@ pub enum NumberType {
 /// is not possible.
 #[allow(private_bounds)]
 pub trait Number:
-    'static + Clone + Copy + Debug + Display + PartialEq + Eq + Hash + PartialOrd + Ord + Default + Private
+    'static
+    + Clone
+    + Copy
+    + Debug
+    + Display
+    + PartialEq
+    + Eq
+    + Hash
+    + PartialOrd
+    + Ord
+    + Default
+    + BitXor<Output = Self>
+    + Private
 {
     /// Represents the associated byte representation of the number, e.g. `[u8; 4]` for `u32`.
     type ByteRepr: AsRef<[u8]> + AsMut<[u8]> + Default;
@@ -75,6 +88,18 @@ pub trait Number:
     #[must_use]
     fn wrapping_shr(self, other: u32) -> Self;
 
+    /// Returns the sum of two numbers, or `None` if the result is too large.
+    #[must_use]
+    fn checked_add(self, other: Self) -> Option<Self>;
+
+    /// Returns the difference of two numbers, or `None` if the result is too small.
+    #[must_use]
+    fn checked_sub(self, other: Self) -> Option<Self>;
+
+    /// Returns the product of two numbers, or `None` if the result is too large.
+    #[must_use]
+    fn checked_mul(self, other: Self) -> Option<Self>;
+
     /// Returns the number as a `u128`, which is guaranteed to be big enough to hold
     /// any [`Number`] value.
     #[must_use]
@@ -152,6 +177,15 @@ impl Number for u32 {
     fn wrapping_shr(self, other: u32) -> Self {
         self.wrapping_shr(other)
     }
+    fn checked_add(self, other: Self) -> Option<Self> {
+        self.checked_add(other)
+    }
+    fn checked_sub(self, other: Self) -> Option<Self> {
+        self.checked_sub(other)
+    }
+    fn checked_mul(self, other: Self) -> Option<Self> {
+        self.checked_mul(other)
+    }
     fn as_u128(self) -> u128 {
         u128::from(self)
     }
@@ -211,6 +245,15 @@ impl Number for u64 {
     fn wrapping_rem(self, other: Self) -> Self {
         self.wrapping_rem(other)
     }
+    fn checked_add(self, other: Self) -> Option<Self> {
+        self.checked_add(other)
+    }
+    fn checked_sub(self, other: Self) -> Option<Self> {
+        self.checked_sub(other)
+    }
+    fn checked_mul(self, other: Self) -> Option<Self> {
+        self.checked_mul(other)
+    }
     fn as_u128(self) -> u128 {
         u128::from(self)
     }
@@ -270,6 +313,15 @@ impl Number for u128 {
     fn wrapping_rem(self, other: Self) -> Self {
         self.wrapping_rem(other)
     }
+    fn checked_add(self, other: Self) -> Option<Self> {
+        self.checked_add(other)
+    }
+    fn checked_sub(self, other: Self) -> Option<Self> {
+        self.checked_sub(other)
+    }
+    fn checked_mul(self, other: Self) -> Option<Self> {
+        self.checked_mul(other)
+    }
     fn as_u128(self) -> u128 {
         self
     }
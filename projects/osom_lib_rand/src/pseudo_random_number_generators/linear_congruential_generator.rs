@@ -7,8 +7,9 @@
 //! if and only if `increment % 4 == 1`. This result can be found in
 //! "Notes on a New Pseudo-Random Number Generator" paper by Martin Greenberger.
 //! Thus we fix an appropriate prime increment for all generators.
+use crate::errors::RandError;
 use crate::number::{Number, NumberType};
-use crate::traits::{PseudoRandomNumberGenerator, RandomnessSource};
+use crate::traits::{PseudoRandomNumberGenerator, RandomnessSource, TryRandomnessSource};
 
 const PRIME_INCREMENT: u32 = const {
     let value = 3326489;
@@ -104,6 +105,17 @@ impl<ANumber: Number> PseudoRandomNumberGenerator for LinearCongruentialGenerato
     fn from_randomness_source(source: &mut impl RandomnessSource<TNumber = Self::TNumber>) -> Self {
         Self::new(source.next_number())
     }
+
+    fn try_from_randomness_source(
+        source: &mut impl TryRandomnessSource<TNumber = Self::TNumber>,
+    ) -> Result<Self, RandError> {
+        Ok(Self::new(source.try_next_number()?))
+    }
+
+    fn reseed(&mut self, source: &mut impl RandomnessSource<TNumber = Self::TNumber>) {
+        self.current = self.current ^ source.next_number();
+        self.next_value();
+    }
 }
 
 #[cfg(feature = "std_os_rand")]
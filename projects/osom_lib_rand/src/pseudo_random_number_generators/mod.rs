@@ -1,3 +1,6 @@
 //! Holds implementations of several pseudo random number generators.
 mod linear_congruential_generator;
 pub use linear_congruential_generator::*;
+
+mod mersenne_twister;
+pub use mersenne_twister::*;
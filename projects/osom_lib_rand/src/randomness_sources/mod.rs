@@ -4,4 +4,10 @@ use osom_lib_macros::reexport_if_feature;
 mod constant_randomness_source;
 pub use constant_randomness_source::*;
 
+mod sequence_randomness_source;
+pub use sequence_randomness_source::*;
+
+mod splitting_randomness_source;
+pub use splitting_randomness_source::*;
+
 reexport_if_feature!("std_os_rand", os_randomness_source);
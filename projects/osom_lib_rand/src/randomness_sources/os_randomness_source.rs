@@ -1,30 +1,46 @@
 use core::marker::PhantomData;
 
-use crate::{number::{Number, NumberType}, traits::RandomnessSource};
+use crate::{
+    errors::RandError,
+    number::{Number, NumberType},
+    traits::{RandomnessSource, TryRandomnessSource},
+};
 
 #[inline(always)]
-fn next_os_number<ANumber: Number>() -> ANumber {
+fn try_next_os_number<ANumber: Number>() -> Result<ANumber, getrandom::Error> {
     match ANumber::NUMBER_TYPE {
         NumberType::U32 => {
-            let no = getrandom::u32().expect("Failed to get u32 random number from OS");
-            ANumber::from_u32(no)
+            let no = getrandom::u32()?;
+            Ok(ANumber::from_u32(no))
         }
         NumberType::U64 => {
-            let no = getrandom::u64().expect("Failed to get u64 random number from OS");
-            unsafe { ANumber::from_u64_unchecked(no) }
+            let no = getrandom::u64()?;
+            Ok(unsafe { ANumber::from_u64_unchecked(no) })
         }
         NumberType::U128 => {
             let mut array = [0u8; size_of::<u128>()];
-            getrandom::fill(&mut array).expect("Failed to get u128 random number from OS");
+            getrandom::fill(&mut array)?;
             let no = u128::from_ne_bytes(array);
-            unsafe { ANumber::from_u128_unchecked(no) }
+            Ok(unsafe { ANumber::from_u128_unchecked(no) })
         }
     }
 }
 
+#[inline(always)]
+fn next_os_number<ANumber: Number>() -> ANumber {
+    try_next_os_number().expect("Failed to get random number from OS")
+}
+
 /// Randomness source that retrieves randomness from the operating system.
 /// 
 /// At the moment implemented using [`getrandom`] crate.
+///
+/// # Panics
+///
+/// When the operating system fails to provide randomness. This includes its
+/// [`TryRandomnessSource`] implementation, which comes from the blanket one
+/// and so never returns [`RandError`]. Use [`TryOsRandomnessSource`] to get
+/// [`RandError::SourceUnavailable`] instead.
 #[derive(Debug, Clone, PartialEq, Eq)]
 #[must_use]
 #[repr(transparent)]
@@ -55,3 +71,218 @@ impl<ANumber: Number> RandomnessSource for OsRandomnessSource<ANumber> {
         getrandom::fill(bytes).expect("Failed to fill bytes from OS");
     }
 }
+
+/// Fallible counterpart of [`OsRandomnessSource`]. Instead of panicking,
+/// it reports [`RandError::SourceUnavailable`] when the operating system
+/// fails to provide randomness.
+///
+/// # Notes
+///
+/// It implements [`TryRandomnessSource`] only, since [`OsRandomnessSource`]
+/// already implements it through the blanket implementation, which panics
+/// on failure.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[must_use]
+#[repr(transparent)]
+pub struct TryOsRandomnessSource<ANumber: Number> {
+    _phantom: PhantomData<ANumber>,
+}
+
+impl<ANumber: Number> TryOsRandomnessSource<ANumber> {
+    /// Creates a new [`TryOsRandomnessSource`].
+    #[inline(always)]
+    pub const fn new() -> Self {
+        Self {
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<ANumber: Number> Default for TryOsRandomnessSource<ANumber> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<ANumber: Number> TryRandomnessSource for TryOsRandomnessSource<ANumber> {
+    type TNumber = ANumber;
+
+    fn try_next_number(&mut self) -> Result<Self::TNumber, RandError> {
+        try_next_os_number().map_err(|_| RandError::SourceUnavailable)
+    }
+
+    fn try_fill_bytes(&mut self, bytes: &mut [u8]) -> Result<(), RandError> {
+        if bytes.is_empty() {
+            return Ok(());
+        }
+
+        getrandom::fill(bytes).map_err(|_| RandError::SourceUnavailable)
+    }
+}
+
+/// Randomness source that retrieves randomness from the operating system
+/// in chunks of `BUFFER_SIZE` bytes, and serves numbers from an internal
+/// buffer. This reduces the number of syscalls compared to
+/// [`OsRandomnessSource`], which reads from the OS on each call.
+///
+/// # Notes
+///
+/// `BUFFER_SIZE` has to be a positive multiple of `16`, so that the buffer
+/// holds a whole number of items of any [`Number`] type. The buffer
+/// lives inline, so the struct is at least `BUFFER_SIZE` bytes big.
+///
+/// The type is intentionally not [`Clone`], since a clone would
+/// produce the same numbers as the original until the next refill.
+///
+/// # Panics
+///
+/// When the operating system fails to refill the buffer, also through its
+/// blanket [`TryRandomnessSource`] implementation. Use
+/// [`TryBufferedOsRandomnessSource`] to get [`RandError::SourceUnavailable`]
+/// instead.
+#[must_use]
+pub struct BufferedOsRandomnessSource<ANumber: Number, const BUFFER_SIZE: usize = 256> {
+    buffer: [u8; BUFFER_SIZE],
+    position: usize,
+    _phantom: PhantomData<ANumber>,
+}
+
+impl<ANumber: Number, const BUFFER_SIZE: usize> BufferedOsRandomnessSource<ANumber, BUFFER_SIZE> {
+    /// Creates a new [`BufferedOsRandomnessSource`]. The buffer is filled lazily,
+    /// on the first request for randomness.
+    pub const fn new() -> Self {
+        const {
+            assert!(BUFFER_SIZE > 0, "BUFFER_SIZE must be positive");
+            assert!(BUFFER_SIZE % 16 == 0, "BUFFER_SIZE must be a multiple of 16");
+        }
+
+        Self {
+            buffer: [0; BUFFER_SIZE],
+            position: BUFFER_SIZE,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Returns the size of the internal buffer in bytes.
+    #[inline(always)]
+    #[must_use]
+    pub const fn buffer_size(&self) -> usize {
+        BUFFER_SIZE
+    }
+
+    fn try_refill(&mut self) -> Result<(), RandError> {
+        getrandom::fill(&mut self.buffer).map_err(|_| RandError::SourceUnavailable)?;
+        self.position = 0;
+        Ok(())
+    }
+
+    fn try_take_number(&mut self) -> Result<ANumber, RandError> {
+        // fill_bytes can leave the position unaligned, in which case
+        // the tail of the buffer that is too short is discarded.
+        if BUFFER_SIZE - self.position < ANumber::SIZE {
+            self.try_refill()?;
+        }
+
+        let start = self.position;
+        self.position += ANumber::SIZE;
+        Ok(ANumber::from_bytes(&self.buffer[start..self.position]))
+    }
+
+    fn try_take_bytes(&mut self, bytes: &mut [u8]) -> Result<(), RandError> {
+        if bytes.is_empty() {
+            return Ok(());
+        }
+
+        // Big requests bypass the buffer, since they need a syscall anyway.
+        if bytes.len() >= BUFFER_SIZE {
+            return getrandom::fill(bytes).map_err(|_| RandError::SourceUnavailable);
+        }
+
+        let mut written = 0;
+        while written < bytes.len() {
+            if self.position == BUFFER_SIZE {
+                self.try_refill()?;
+            }
+
+            let count = (bytes.len() - written).min(BUFFER_SIZE - self.position);
+            bytes[written..written + count].copy_from_slice(&self.buffer[self.position..self.position + count]);
+            written += count;
+            self.position += count;
+        }
+        Ok(())
+    }
+}
+
+impl<ANumber: Number, const BUFFER_SIZE: usize> Default for BufferedOsRandomnessSource<ANumber, BUFFER_SIZE> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<ANumber: Number, const BUFFER_SIZE: usize> core::fmt::Debug for BufferedOsRandomnessSource<ANumber, BUFFER_SIZE> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("BufferedOsRandomnessSource")
+            .field("buffer_size", &BUFFER_SIZE)
+            .field("position", &self.position)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<ANumber: Number, const BUFFER_SIZE: usize> RandomnessSource for BufferedOsRandomnessSource<ANumber, BUFFER_SIZE> {
+    type TNumber = ANumber;
+
+    fn next_number(&mut self) -> Self::TNumber {
+        self.try_take_number().expect("Failed to fill buffer from OS")
+    }
+
+    fn fill_bytes(&mut self, bytes: &mut [u8]) {
+        self.try_take_bytes(bytes).expect("Failed to fill bytes from OS");
+    }
+}
+
+/// Fallible counterpart of [`BufferedOsRandomnessSource`]. Instead of
+/// panicking, it reports [`RandError::SourceUnavailable`] when the operating
+/// system fails to refill the buffer.
+///
+/// # Notes
+///
+/// It implements [`TryRandomnessSource`] only, for the same reasons
+/// as [`TryOsRandomnessSource`].
+#[derive(Debug, Default)]
+#[must_use]
+#[repr(transparent)]
+pub struct TryBufferedOsRandomnessSource<ANumber: Number, const BUFFER_SIZE: usize = 256> {
+    source: BufferedOsRandomnessSource<ANumber, BUFFER_SIZE>,
+}
+
+impl<ANumber: Number, const BUFFER_SIZE: usize> TryBufferedOsRandomnessSource<ANumber, BUFFER_SIZE> {
+    /// Creates a new [`TryBufferedOsRandomnessSource`]. The buffer is filled lazily,
+    /// on the first request for randomness.
+    #[inline(always)]
+    pub const fn new() -> Self {
+        Self {
+            source: BufferedOsRandomnessSource::new(),
+        }
+    }
+
+    /// Returns the size of the internal buffer in bytes.
+    #[inline(always)]
+    #[must_use]
+    pub const fn buffer_size(&self) -> usize {
+        BUFFER_SIZE
+    }
+}
+
+impl<ANumber: Number, const BUFFER_SIZE: usize> TryRandomnessSource
+    for TryBufferedOsRandomnessSource<ANumber, BUFFER_SIZE>
+{
+    type TNumber = ANumber;
+
+    fn try_next_number(&mut self) -> Result<Self::TNumber, RandError> {
+        self.source.try_take_number()
+    }
+
+    fn try_fill_bytes(&mut self, bytes: &mut [u8]) -> Result<(), RandError> {
+        self.source.try_take_bytes(bytes)
+    }
+}
@@ -0,0 +1,90 @@
+use crate::number::Number;
+use crate::traits::RandomnessSource;
+
+/// Describes what [`SequenceRandomnessSource`] does once all
+/// the provided values were returned.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[repr(u8)]
+pub enum SequenceEndBehavior {
+    /// Starts over from the first value.
+    #[default]
+    Repeat,
+
+    /// Keeps returning the last value.
+    RepeatLast,
+
+    /// Panics.
+    Panic,
+}
+
+/// A [`RandomnessSource`] that returns a scripted sequence of values,
+/// in the order they were provided.
+///
+/// # Warning
+///
+/// This struct should only be used for testing purposes, not in production.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[must_use]
+pub struct SequenceRandomnessSource<'a, ANumber: Number> {
+    values: &'a [ANumber],
+    position: usize,
+    end_behavior: SequenceEndBehavior,
+}
+
+impl<'a, ANumber: Number> SequenceRandomnessSource<'a, ANumber> {
+    /// Creates a new [`SequenceRandomnessSource`] that cycles through `values`.
+    #[inline(always)]
+    pub const fn new(values: &'a [ANumber]) -> Self {
+        Self::with_end_behavior(values, SequenceEndBehavior::Repeat)
+    }
+
+    /// Creates a new [`SequenceRandomnessSource`] that returns `values`
+    /// in order, and then acts according to `end_behavior`.
+    #[inline(always)]
+    pub const fn with_end_behavior(values: &'a [ANumber], end_behavior: SequenceEndBehavior) -> Self {
+        Self {
+            values,
+            position: 0,
+            end_behavior,
+        }
+    }
+
+    /// Returns the number of values returned so far.
+    #[inline(always)]
+    #[must_use]
+    pub const fn position(&self) -> usize {
+        self.position
+    }
+}
+
+impl<ANumber: Number> RandomnessSource for SequenceRandomnessSource<'_, ANumber> {
+    type TNumber = ANumber;
+
+    /// Returns the next value of the sequence.
+    ///
+    /// # Panics
+    ///
+    /// When the sequence is empty, or when it is exhausted and
+    /// [`SequenceEndBehavior::Panic`] was chosen.
+    fn next_number(&mut self) -> Self::TNumber {
+        let len = self.values.len();
+        assert!(len > 0, "SequenceRandomnessSource has no values.");
+        let index = self.position;
+        self.position += 1;
+        if index < len {
+            return self.values[index];
+        }
+
+        match self.end_behavior {
+            SequenceEndBehavior::Repeat => self.values[index % len],
+            SequenceEndBehavior::RepeatLast => self.values[len - 1],
+            SequenceEndBehavior::Panic => panic!("SequenceRandomnessSource is exhausted."),
+        }
+    }
+}
+
+impl<ANumber: Number> Default for SequenceRandomnessSource<'_, ANumber> {
+    fn default() -> Self {
+        Self::with_end_behavior(&[], SequenceEndBehavior::Panic)
+    }
+}
@@ -0,0 +1,74 @@
+use core::marker::PhantomData;
+
+use crate::number::{MAX_NUMBER_SIZE, Number};
+use crate::traits::RandomnessSource;
+
+/// A [`RandomnessSource`] adapter that wraps a source of wider numbers
+/// and yields narrower ones, e.g. two `u32` values out of a single `u64`.
+///
+/// Each number produced by the inner source is split into consecutive
+/// byte chunks. Every bit of the inner number is used exactly once, and
+/// the inner source is queried only when the buffered bytes run out.
+///
+/// # Notes
+///
+/// `ANumber` has to be at most as wide as the inner source's number.
+/// This is verified at compile time.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[must_use]
+pub struct SplittingRandomnessSource<TSource: RandomnessSource, ANumber: Number> {
+    source: TSource,
+    buffer: [u8; MAX_NUMBER_SIZE],
+    position: usize,
+    phantom: PhantomData<ANumber>,
+}
+
+impl<TSource: RandomnessSource, ANumber: Number> SplittingRandomnessSource<TSource, ANumber> {
+    const VALIDATE: () = assert!(
+        ANumber::SIZE <= TSource::TNumber::SIZE,
+        "SplittingRandomnessSource cannot produce numbers wider than its source."
+    );
+
+    /// Creates a new [`SplittingRandomnessSource`] wrapping the given source.
+    pub fn new(source: TSource) -> Self {
+        let () = Self::VALIDATE;
+        Self {
+            source,
+            buffer: [0; MAX_NUMBER_SIZE],
+            position: TSource::TNumber::SIZE,
+            phantom: PhantomData,
+        }
+    }
+
+    /// Returns the wrapped source. Any bytes that are still buffered are lost.
+    pub fn into_inner(self) -> TSource {
+        self.source
+    }
+
+    fn refill(&mut self) {
+        let value = self.source.next_number().to_bytes();
+        let value = value.as_ref();
+        self.buffer[..value.len()].copy_from_slice(value);
+        self.position = 0;
+    }
+}
+
+impl<TSource: RandomnessSource, ANumber: Number> RandomnessSource for SplittingRandomnessSource<TSource, ANumber> {
+    type TNumber = ANumber;
+
+    fn next_number(&mut self) -> Self::TNumber {
+        if self.position + ANumber::SIZE > TSource::TNumber::SIZE {
+            self.refill();
+        }
+
+        let start = self.position;
+        self.position += ANumber::SIZE;
+        ANumber::from_bytes(&self.buffer[start..self.position])
+    }
+}
+
+impl<TSource: RandomnessSource, ANumber: Number> Default for SplittingRandomnessSource<TSource, ANumber> {
+    fn default() -> Self {
+        Self::new(TSource::default())
+    }
+}
@@ -1,5 +1,7 @@
 //! Holds traits for random number generators and randomness sources.
+use crate::errors::RandError;
 use crate::number::Number;
+use crate::randomness_sources::ConstantRandomnessSource;
 
 /// Simple trait for pseudo random number generators. Types implementing
 /// this trait should aim for efficiency above all.
@@ -23,6 +25,53 @@ pub trait PseudoRandomNumberGenerator {
     fn fill_bytes(&mut self, bytes: &mut [u8]) {
         fill_bytes_from_gens(bytes, || self.next_number());
     }
+
+    /// Folds fresh entropy from the [`RandomnessSource`] into the generator.
+    ///
+    /// # Notes
+    ///
+    /// The default implementation has no access to the generator's state.
+    /// It XORs the generator's next number with `source.next_number()`
+    /// and rebuilds the generator from the result through
+    /// [`from_randomness_source`][`Self::from_randomness_source`].
+    /// This means that generators with state larger than a single number
+    /// lose part of it, and thus they should override this method, mixing
+    /// the entropy into their state in place. All the generators of this
+    /// crate do so.
+    fn reseed(&mut self, source: &mut impl RandomnessSource<TNumber = Self::TNumber>)
+    where
+        Self: Sized,
+    {
+        let seed = self.next_number() ^ source.next_number();
+        let mut mixed = ConstantRandomnessSource::new(seed);
+        *self = Self::from_randomness_source(&mut mixed);
+    }
+
+    /// Creates a new [`PseudoRandomNumberGenerator`] seeded from a [`TryRandomnessSource`].
+    ///
+    /// # Notes
+    ///
+    /// The default implementation draws a single number from `source`, and
+    /// passes it to [`from_randomness_source`][`Self::from_randomness_source`]
+    /// through [`ConstantRandomnessSource`]. Generators that draw more than
+    /// one number when seeded should override this method.
+    ///
+    /// # Errors
+    ///
+    /// For details see [`RandError`]. Only the fallible sources, e.g.
+    /// `TryOsRandomnessSource`, report errors. Every [`RandomnessSource`]
+    /// goes through the blanket [`TryRandomnessSource`] implementation, and
+    /// so it panics on failure instead, e.g. `OsRandomnessSource`.
+    fn try_from_randomness_source(
+        source: &mut impl TryRandomnessSource<TNumber = Self::TNumber>,
+    ) -> Result<Self, RandError>
+    where
+        Self: Sized,
+    {
+        let seed = source.try_next_number()?;
+        let mut constant = ConstantRandomnessSource::new(seed);
+        Ok(Self::from_randomness_source(&mut constant))
+    }
 }
 
 /// Simple trait for randomness source.
@@ -51,9 +100,81 @@ pub trait RandomnessSource: Default {
     }
 }
 
+/// Fallible counterpart of [`RandomnessSource`], for sources that can fail
+/// to produce random data, e.g. when the operating system's entropy
+/// is unavailable.
+///
+/// # Notes
+///
+/// Every [`RandomnessSource`] implements this trait, and always returns `Ok`.
+/// If such a source fails, it panics just like its [`RandomnessSource`]
+/// methods do, e.g. `OsRandomnessSource`. Sources that report failures are
+/// exposed through separate types, e.g. `TryOsRandomnessSource`, which
+/// implement this trait only.
+pub trait TryRandomnessSource {
+    type TNumber: Number;
+
+    /// Returns the next random number.
+    ///
+    /// # Errors
+    ///
+    /// For details see [`RandError`].
+    fn try_next_number(&mut self) -> Result<Self::TNumber, RandError>;
+
+    /// Fills the given mut slice with random bytes.
+    ///
+    /// # Errors
+    ///
+    /// For details see [`RandError`]. On failure the content
+    /// of `bytes` is unspecified.
+    fn try_fill_bytes(&mut self, bytes: &mut [u8]) -> Result<(), RandError> {
+        try_fill_bytes_from_gens(bytes, || self.try_next_number())
+    }
+}
+
+impl<TSource: RandomnessSource> TryRandomnessSource for TSource {
+    type TNumber = TSource::TNumber;
+
+    #[inline(always)]
+    fn try_next_number(&mut self) -> Result<Self::TNumber, RandError> {
+        Ok(self.next_number())
+    }
+
+    #[inline(always)]
+    fn try_fill_bytes(&mut self, bytes: &mut [u8]) -> Result<(), RandError> {
+        self.fill_bytes(bytes);
+        Ok(())
+    }
+}
+
+/// Represents a probability distribution over values of type `T`,
+/// sampled with a [`PseudoRandomNumberGenerator`].
+///
+/// # Notes
+///
+/// [`PseudoRandomNumberGenerator`] is not dyn compatible, and so `sample`
+/// has to be generic over it. As a consequence this trait cannot be used
+/// as `dyn Distribution<T>`. Algorithms should be generic over
+/// `TDistribution: Distribution<T>` instead.
+pub trait Distribution<T> {
+    /// Draws a single value from the distribution.
+    fn sample<TGenerator: PseudoRandomNumberGenerator>(&self, generator: &mut TGenerator) -> T;
+}
+
 fn fill_bytes_from_gens<T: Number, F: FnMut() -> T>(bytes: &mut [u8], mut generator: F) {
+    let result = try_fill_bytes_from_gens(bytes, || Ok::<T, core::convert::Infallible>(generator()));
+    match result {
+        Ok(()) => {}
+        Err(never) => match never {},
+    }
+}
+
+fn try_fill_bytes_from_gens<T: Number, E, F: FnMut() -> Result<T, E>>(
+    bytes: &mut [u8],
+    mut generator: F,
+) -> Result<(), E> {
     if bytes.is_empty() {
-        return;
+        return Ok(());
     }
 
     let size = T::SIZE;
@@ -62,21 +183,20 @@ fn fill_bytes_from_gens<T: Number, F: FnMut() -> T>(bytes: &mut [u8], mut genera
     let missing_elements = bytes_len % size;
     let mut ptr = bytes.as_mut_ptr().cast::<T>();
     for _ in 0..number_of_chunks {
+        let value = generator()?;
         unsafe {
-            ptr.write(generator());
+            // The byte slice does not have to be aligned to `T`.
+            ptr.write_unaligned(value);
             ptr = ptr.add(1);
         }
     }
 
     if missing_elements > 0 {
-        let mut value_bytes = T::ByteRepr::default();
-        let value_bytes_slice = value_bytes.as_mut();
-        let value_ptr = value_bytes_slice.as_mut_ptr().cast::<T>();
-        unsafe {
-            value_ptr.write(generator());
-        }
+        let value_bytes = generator()?.to_bytes();
         let index = number_of_chunks * size;
         let remaining_bytes = &mut bytes[index..(index + missing_elements)];
-        remaining_bytes.copy_from_slice(&value_bytes_slice[..missing_elements]);
+        remaining_bytes.copy_from_slice(&value_bytes.as_ref()[..missing_elements]);
     }
+
+    Ok(())
 }
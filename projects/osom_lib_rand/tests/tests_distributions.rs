@@ -0,0 +1,173 @@
+use osom_lib_rand::distributions::UniformInt;
+use osom_lib_rand::helpers::next_in_range;
+use osom_lib_rand::pseudo_random_number_generators::LinearCongruentialGenerator;
+use osom_lib_rand::traits::Distribution;
+use rstest::rstest;
+
+#[rstest]
+#[case(0, 1)]
+#[case(0, 6)]
+#[case(10, 17)]
+#[case(u64::MAX - 3, u64::MAX)]
+fn test_uniform_int_stays_in_range(#[case] low: u64, #[case] high: u64) {
+    let mut generator = LinearCongruentialGenerator::<u64>::new(12345);
+    let distribution = UniformInt::new(low, high);
+    let mut seen_low = false;
+    let mut seen_high = false;
+    for _ in 0..1000 {
+        let value = distribution.sample(&mut generator);
+        assert!((low..high).contains(&value));
+        seen_low |= value == low;
+        seen_high |= value == high - 1;
+    }
+    assert!(seen_low && seen_high);
+}
+
+#[test]
+fn test_uniform_int_is_uniform() {
+    const BINS: usize = 6;
+    const ITERATIONS: usize = 600000;
+    let mut generator = LinearCongruentialGenerator::<u32>::new(7);
+    let distribution = UniformInt::<u32>::new(0, BINS as u32);
+    let mut bins = [0usize; BINS];
+    for _ in 0..ITERATIONS {
+        bins[distribution.sample(&mut generator) as usize] += 1;
+    }
+    let expected = ITERATIONS / BINS;
+    for count in bins {
+        assert!(count > expected * 95 / 100);
+        assert!(count < expected * 105 / 100);
+    }
+}
+
+#[test]
+fn test_next_in_range_different_number_types() {
+    let mut generator = LinearCongruentialGenerator::<u32>::new(3);
+    for _ in 0..1000 {
+        let value: u128 = next_in_range(&mut generator, 1u128 << 100, (1u128 << 100) + 10);
+        assert!(((1 << 100)..(1 << 100) + 10).contains(&value));
+    }
+}
+
+#[test]
+#[should_panic(expected = "UniformInt requires low < high.")]
+fn test_uniform_int_empty_range() {
+    let _ = UniformInt::<u32>::new(5, 5);
+}
+
+#[test]
+fn test_next_f64_range() {
+    let mut generator = LinearCongruentialGenerator::<u64>::new(42);
+    let mut sum = 0.0;
+    for _ in 0..100000 {
+        let value = osom_lib_rand::helpers::next_f64(&mut generator);
+        assert!((0.0..1.0).contains(&value));
+        sum += value;
+    }
+    let mean = sum / 100000.0;
+    assert!((mean - 0.5).abs() < 0.01);
+}
+
+#[cfg(feature = "libm")]
+fn mean_and_variance<TDistribution: Distribution<f64>>(distribution: &TDistribution) -> (f64, f64) {
+    const ITERATIONS: usize = 200000;
+    let mut generator = LinearCongruentialGenerator::<u64>::new(987654321);
+    let mut sum = 0.0;
+    let mut sum_of_squares = 0.0;
+    for _ in 0..ITERATIONS {
+        let value = distribution.sample(&mut generator);
+        assert!(value.is_finite());
+        sum += value;
+        sum_of_squares += value * value;
+    }
+    let mean = sum / ITERATIONS as f64;
+    let variance = sum_of_squares / ITERATIONS as f64 - mean * mean;
+    (mean, variance)
+}
+
+#[cfg(feature = "libm")]
+#[rstest]
+#[case(0.0, 1.0)]
+#[case(10.0, 2.5)]
+#[case(-3.0, 0.5)]
+fn test_normal_mean_and_variance(#[case] mean: f64, #[case] std_dev: f64) {
+    let (actual_mean, actual_variance) = mean_and_variance(&osom_lib_rand::distributions::Normal::new(mean, std_dev));
+    assert!((actual_mean - mean).abs() < 0.02 * std_dev.max(1.0));
+    assert!((actual_variance / (std_dev * std_dev) - 1.0).abs() < 0.03);
+}
+
+#[cfg(feature = "libm")]
+#[rstest]
+#[case(1.0)]
+#[case(0.25)]
+#[case(7.0)]
+fn test_exp_mean_and_variance(#[case] lambda: f64) {
+    let (actual_mean, actual_variance) = mean_and_variance(&osom_lib_rand::distributions::Exp::new(lambda));
+    assert!((actual_mean * lambda - 1.0).abs() < 0.02);
+    assert!((actual_variance * lambda * lambda - 1.0).abs() < 0.05);
+}
+
+#[test]
+fn test_bernoulli_is_reproducible() {
+    let distribution = osom_lib_rand::distributions::Bernoulli::from_ratio(1, 4);
+    let mut first = LinearCongruentialGenerator::<u32>::new(99);
+    let mut second = LinearCongruentialGenerator::<u32>::new(99);
+    let first_trials: Vec<bool> = (0..1000).map(|_| distribution.sample(&mut first)).collect();
+    let second_trials: Vec<bool> = (0..1000).map(|_| distribution.sample(&mut second)).collect();
+    assert_eq!(first_trials, second_trials);
+
+    let successes = first_trials.iter().filter(|trial| **trial).count();
+    assert!((200..300).contains(&successes));
+}
+
+#[test]
+#[should_panic(expected = "Bernoulli requires 0 <= numerator <= denominator and denominator > 0.")]
+fn test_bernoulli_invalid_ratio() {
+    let _ = osom_lib_rand::distributions::Bernoulli::from_ratio(2, 1);
+}
+
+#[test]
+fn test_weighted_index_distribution() {
+    use osom_lib_rand::distributions::WeightedIndex;
+
+    let weights = [0, 1, 0, 3, 6, 0];
+    let mut cumulative_weights = [0; 6];
+    let distribution = WeightedIndex::from_weights(&weights, &mut cumulative_weights).unwrap();
+    assert_eq!(distribution.len(), 6);
+    assert_eq!(distribution.total(), 10);
+    assert_eq!(distribution.cumulative_weights(), &[0, 1, 1, 4, 10, 10]);
+
+    let mut generator = LinearCongruentialGenerator::<u64>::new(4321);
+    let mut counts = [0usize; 6];
+    for _ in 0..100000 {
+        counts[distribution.sample(&mut generator)] += 1;
+    }
+
+    assert_eq!(counts[0], 0);
+    assert_eq!(counts[2], 0);
+    assert_eq!(counts[5], 0);
+    assert!((9000..11000).contains(&counts[1]));
+    assert!((28000..32000).contains(&counts[3]));
+    assert!((58000..62000).contains(&counts[4]));
+    assert_eq!(weights, [0, 1, 0, 3, 6, 0]);
+}
+
+#[test]
+fn test_weighted_index_empty() {
+    use osom_lib_rand::distributions::WeightedIndex;
+
+    assert!(WeightedIndex::from_weights(&[], &mut []).is_none());
+    assert!(WeightedIndex::from_weights(&[0, 0], &mut [0, 0]).is_none());
+}
+
+#[test]
+#[should_panic(expected = "WeightedIndex requires the total of weights to fit into u64.")]
+fn test_weighted_index_overflow() {
+    let _ = osom_lib_rand::distributions::WeightedIndex::from_weights(&[u64::MAX, 1], &mut [0, 0]);
+}
+
+#[test]
+#[should_panic(expected = "WeightedIndex requires the same number of weights and cumulative weights.")]
+fn test_weighted_index_length_mismatch() {
+    let _ = osom_lib_rand::distributions::WeightedIndex::from_weights(&[1, 2], &mut [0]);
+}
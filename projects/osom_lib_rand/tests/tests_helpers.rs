@@ -0,0 +1,274 @@
+use osom_lib_rand::helpers::gen_array;
+use osom_lib_rand::pseudo_random_number_generators::LinearCongruentialGenerator;
+use rstest::rstest;
+
+#[rstest]
+#[case(1)]
+#[case(13212)]
+#[case(u64::MAX)]
+fn test_gen_array_is_deterministic(#[case] seed: u64) {
+    let mut first = LinearCongruentialGenerator::<u64>::new(seed);
+    let mut second = LinearCongruentialGenerator::<u64>::new(seed);
+    let first_array: [u8; 37] = gen_array(&mut first);
+    let second_array: [u8; 37] = gen_array(&mut second);
+    assert_eq!(first_array, second_array);
+    assert_ne!(first_array, [0u8; 37]);
+
+    let third_array: [u8; 37] = gen_array(&mut first);
+    assert_ne!(first_array, third_array);
+}
+
+#[cfg(feature = "arrays")]
+#[test]
+fn test_fill_fixed_array() {
+    use osom_lib_arrays::FixedArray;
+    use osom_lib_rand::helpers::fill_fixed_array;
+
+    let mut generator = LinearCongruentialGenerator::<u32>::new(7);
+    let mut reference = LinearCongruentialGenerator::<u32>::new(7);
+
+    let mut array = FixedArray::<u8, 19>::new();
+    array.push(1).unwrap();
+    fill_fixed_array(&mut generator, &mut array);
+    assert!(array.is_full());
+
+    let expected: [u8; 19] = gen_array(&mut reference);
+    assert_eq!(array.as_slice(), &expected);
+}
+
+#[test]
+fn test_reseed() {
+    use osom_lib_rand::randomness_sources::ConstantRandomnessSource;
+    use osom_lib_rand::traits::PseudoRandomNumberGenerator;
+
+    let mut plain = LinearCongruentialGenerator::<u64>::new(5);
+    let mut first = LinearCongruentialGenerator::<u64>::new(5);
+    let mut second = LinearCongruentialGenerator::<u64>::new(5);
+    first.reseed(&mut ConstantRandomnessSource::new(99));
+    second.reseed(&mut ConstantRandomnessSource::new(99));
+
+    for _ in 0..100 {
+        let value = first.next_number();
+        assert_eq!(value, second.next_number());
+        assert_ne!(value, plain.next_number());
+    }
+}
+
+#[test]
+fn test_default_reseed() {
+    use osom_lib_rand::randomness_sources::ConstantRandomnessSource;
+    use osom_lib_rand::traits::{PseudoRandomNumberGenerator, RandomnessSource};
+
+    struct Counter(u32);
+
+    impl PseudoRandomNumberGenerator for Counter {
+        type TNumber = u32;
+
+        fn from_randomness_source(source: &mut impl RandomnessSource<TNumber = Self::TNumber>) -> Self {
+            Self(source.next_number())
+        }
+
+        fn next_number(&mut self) -> Self::TNumber {
+            self.0 = self.0.wrapping_add(1);
+            self.0
+        }
+    }
+
+    let mut first = Counter(10);
+    let mut second = Counter(10);
+    first.reseed(&mut ConstantRandomnessSource::new(3));
+    second.reseed(&mut ConstantRandomnessSource::new(3));
+    assert_eq!(first.0, second.0);
+    assert_ne!(first.0, 11);
+}
+
+#[rstest]
+#[case(-10, 10)]
+#[case(-1, 0)]
+#[case(i64::MIN, i64::MIN + 3)]
+#[case(i64::MAX - 3, i64::MAX)]
+#[case(i64::MIN, i64::MAX)]
+#[case(-5, i64::MAX)]
+fn test_next_signed_in_range(#[case] low: i64, #[case] high: i64) {
+    use osom_lib_rand::helpers::next_signed_in_range;
+
+    let mut generator = LinearCongruentialGenerator::<u64>::new(31);
+    for _ in 0..1000 {
+        let value = next_signed_in_range(&mut generator, low, high);
+        assert!((low..high).contains(&value));
+    }
+}
+
+#[test]
+fn test_next_signed_in_range_hits_both_ends() {
+    use osom_lib_rand::helpers::next_signed_in_range;
+    use osom_lib_rand::pseudo_random_number_generators::Mt19937;
+
+    let mut generator = Mt19937::from_seed(8);
+    let values: Vec<i64> = (0..1000)
+        .map(|_| next_signed_in_range(&mut generator, i64::MIN, i64::MIN + 2))
+        .collect();
+    assert!(values.contains(&i64::MIN));
+    assert!(values.contains(&(i64::MIN + 1)));
+
+    let values: Vec<i64> = (0..1000)
+        .map(|_| next_signed_in_range(&mut generator, i64::MAX - 2, i64::MAX))
+        .collect();
+    assert!(values.contains(&(i64::MAX - 2)));
+    assert!(values.contains(&(i64::MAX - 1)));
+}
+
+#[test]
+#[should_panic(expected = "next_signed_in_range requires low < high.")]
+fn test_next_signed_in_range_empty() {
+    let mut generator = LinearCongruentialGenerator::<u32>::new(8);
+    let _ = osom_lib_rand::helpers::next_signed_in_range(&mut generator, 3, 3);
+}
+
+#[rstest]
+#[case(0, 0)]
+#[case(-5, 5)]
+#[case(i64::MIN, i64::MIN + 1)]
+#[case(i64::MAX - 1, i64::MAX)]
+#[case(i64::MIN, i64::MAX)]
+fn test_next_signed_in_inclusive_range(#[case] low: i64, #[case] high: i64) {
+    use osom_lib_rand::helpers::next_signed_in_inclusive_range;
+
+    let mut generator = LinearCongruentialGenerator::<u64>::new(31);
+    for _ in 0..1000 {
+        let value = next_signed_in_inclusive_range(&mut generator, low, high);
+        assert!((low..=high).contains(&value));
+    }
+}
+
+#[test]
+fn test_next_signed_in_inclusive_range_full_span() {
+    use osom_lib_rand::helpers::next_signed_in_inclusive_range;
+    use osom_lib_rand::pseudo_random_number_generators::Mt19937;
+
+    let mut generator = Mt19937::from_seed(8);
+    let values: Vec<i64> = (0..1000)
+        .map(|_| next_signed_in_inclusive_range(&mut generator, i64::MIN, i64::MAX))
+        .collect();
+    assert!(values.iter().any(|value| *value < 0));
+    assert!(values.iter().any(|value| *value > 0));
+
+    let values: Vec<i64> = (0..1000)
+        .map(|_| next_signed_in_inclusive_range(&mut generator, i64::MAX - 1, i64::MAX))
+        .collect();
+    assert!(values.contains(&(i64::MAX - 1)));
+    assert!(values.contains(&i64::MAX));
+}
+
+#[test]
+#[should_panic(expected = "next_signed_in_inclusive_range requires low <= high.")]
+fn test_next_signed_in_inclusive_range_empty() {
+    let mut generator = LinearCongruentialGenerator::<u32>::new(8);
+    let _ = osom_lib_rand::helpers::next_signed_in_inclusive_range(&mut generator, 3, 2);
+}
+
+#[test]
+fn test_gen_char() {
+    use osom_lib_rand::helpers::gen_char;
+
+    let mut generator = LinearCongruentialGenerator::<u64>::new(77);
+    let mut seen_non_bmp = false;
+    for _ in 0..10000 {
+        let value = gen_char(&mut generator);
+        let code_point = value as u32;
+        assert!(code_point < 0x110000);
+        assert!(!(0xD800..=0xDFFF).contains(&code_point));
+        seen_non_bmp |= code_point > 0xFFFF;
+    }
+    assert!(seen_non_bmp);
+}
+
+#[test]
+fn test_gen_ascii_char() {
+    use osom_lib_rand::helpers::gen_ascii_char;
+
+    let mut generator = LinearCongruentialGenerator::<u64>::new(77);
+    let text: String = (0..1000).map(|_| gen_ascii_char(&mut generator)).collect();
+    assert!(text.is_ascii());
+    assert_eq!(text.len(), 1000);
+}
+
+#[test]
+fn test_weighted_choice_distribution() {
+    use osom_lib_rand::helpers::weighted_choice;
+
+    let items = ['a', 'b', 'c', 'd'];
+    let mut generator = LinearCongruentialGenerator::<u64>::new(1234);
+    let mut counts = [0usize; 4];
+    let weights = [1, 0, 3, 6];
+    for _ in 0..100000 {
+        let item = weighted_choice(&items, &weights, &mut generator).unwrap();
+        let index = items.iter().position(|value| value == item).unwrap();
+        counts[index] += 1;
+    }
+
+    assert_eq!(counts[1], 0);
+    assert!((9000..11000).contains(&counts[0]));
+    assert!((28000..32000).contains(&counts[2]));
+    assert!((58000..62000).contains(&counts[3]));
+}
+
+#[test]
+fn test_weighted_choice_edge_cases() {
+    use osom_lib_rand::helpers::weighted_choice;
+
+    let mut generator = LinearCongruentialGenerator::<u64>::new(1);
+    let empty: [i32; 0] = [];
+    assert_eq!(weighted_choice(&empty, &[], &mut generator), None);
+    assert_eq!(weighted_choice(&[1, 2], &[0, 0], &mut generator), None);
+    assert_eq!(weighted_choice(&[1, 2], &[0, 5], &mut generator), Some(&2));
+    assert_eq!(weighted_choice(&[7], &[u64::MAX], &mut generator), Some(&7));
+}
+
+#[test]
+fn test_weighted_choice_keeps_weights() {
+    use osom_lib_rand::helpers::weighted_choice;
+
+    let mut generator = LinearCongruentialGenerator::<u64>::new(7);
+    let items = [1, 2, 3];
+    let weights = [0, 3, 0];
+    for _ in 0..100 {
+        assert_eq!(weighted_choice(&items, &weights, &mut generator), Some(&2));
+    }
+    assert_eq!(weights, [0, 3, 0]);
+}
+
+#[test]
+#[should_panic(expected = "weighted_choice requires the same number of items and weights.")]
+fn test_weighted_choice_length_mismatch() {
+    let mut generator = LinearCongruentialGenerator::<u64>::new(1);
+    let _ = osom_lib_rand::helpers::weighted_choice(&[1, 2], &[1], &mut generator);
+}
+
+#[rstest]
+#[case(0, 1)]
+#[case(1, 1)]
+#[case(1, 3)]
+#[case(7, 10)]
+fn test_gen_ratio(#[case] numerator: u32, #[case] denominator: u32) {
+    const ITERATIONS: u32 = 300000;
+    let mut generator = LinearCongruentialGenerator::<u64>::new(2024);
+    let hits = (0..ITERATIONS)
+        .filter(|_| osom_lib_rand::helpers::gen_ratio(&mut generator, numerator, denominator))
+        .count() as f64;
+    let expected = f64::from(ITERATIONS) * f64::from(numerator) / f64::from(denominator);
+    assert!((hits - expected).abs() <= f64::from(ITERATIONS) / 100.0);
+    if numerator == 0 {
+        assert_eq!(hits, 0.0);
+    }
+    if numerator == denominator {
+        assert_eq!(hits, f64::from(ITERATIONS));
+    }
+}
+
+#[test]
+#[should_panic(expected = "gen_ratio requires non-zero denominator.")]
+fn test_gen_ratio_zero_denominator() {
+    let mut generator = LinearCongruentialGenerator::<u64>::new(1);
+    let _ = osom_lib_rand::helpers::gen_ratio(&mut generator, 0, 0);
+}
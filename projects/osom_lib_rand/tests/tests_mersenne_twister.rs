@@ -0,0 +1,59 @@
+use osom_lib_rand::pseudo_random_number_generators::Mt19937;
+use osom_lib_rand::randomness_sources::ConstantRandomnessSource;
+use osom_lib_rand::traits::{PseudoRandomNumberGenerator, RandomnessSource, TryRandomnessSource};
+
+#[test]
+fn test_mt19937_default_seed_10000th_output() {
+    let mut generator = Mt19937::default();
+    let mut value = 0;
+    for _ in 0..10000 {
+        value = generator.next_value();
+    }
+    assert_eq!(value, 4123659995);
+}
+
+#[test]
+fn test_mt19937_from_seed() {
+    let mut generator = Mt19937::from_seed(5489);
+    assert_eq!(generator.next_value(), 3499211612);
+    assert_eq!(generator.next_value(), 581869302);
+    assert_eq!(generator.next_value(), 3890346734);
+}
+
+#[test]
+fn test_mt19937_from_key() {
+    // The first outputs of the reference `mt19937ar.c` test driver.
+    let mut generator = Mt19937::from_key(&[0x123, 0x234, 0x345, 0x456]);
+    let expected = [1067595299u32, 955945823, 477289528, 4107218783, 4228976476];
+    for value in expected {
+        assert_eq!(generator.next_number(), value);
+    }
+}
+
+#[test]
+fn test_mt19937_reseed() {
+    let mut first = Mt19937::from_seed(1);
+    let mut second = Mt19937::from_seed(1);
+    let mut plain = Mt19937::from_seed(1);
+    first.reseed(&mut ConstantRandomnessSource::new(17));
+    second.reseed(&mut ConstantRandomnessSource::new(17));
+    for _ in 0..1000 {
+        let value = first.next_number();
+        assert_eq!(value, second.next_number());
+        assert_ne!(value, plain.next_number());
+    }
+}
+
+#[test]
+fn test_mt19937_try_from_randomness_source() {
+    let mut source = ConstantRandomnessSource::new(7u32);
+    let mut expected = Mt19937::from_randomness_source(&mut source.clone());
+    let mut generator = Mt19937::try_from_randomness_source(&mut source).unwrap();
+    for _ in 0..100 {
+        assert_eq!(generator.next_value(), expected.next_value());
+    }
+
+    let mut reference = ConstantRandomnessSource::new(7u32);
+    let _ = reference.next_number();
+    assert_eq!(source.try_next_number().unwrap(), reference.next_number());
+}
@@ -0,0 +1,30 @@
+use osom_lib_rand::number::Number;
+use rstest::rstest;
+
+fn checked_ops<T: Number>(left: T, right: T) -> (Option<T>, Option<T>, Option<T>) {
+    (
+        Number::checked_add(left, right),
+        Number::checked_sub(left, right),
+        Number::checked_mul(left, right),
+    )
+}
+
+#[rstest]
+#[case(5, 3, (Some(8), Some(2), Some(15)))]
+#[case(3, 5, (Some(8), None, Some(15)))]
+#[case(u32::MAX, 1, (None, Some(u32::MAX - 1), Some(u32::MAX)))]
+#[case(u32::MAX, 2, (None, Some(u32::MAX - 2), None))]
+fn test_checked_u32(#[case] left: u32, #[case] right: u32, #[case] expected: (Option<u32>, Option<u32>, Option<u32>)) {
+    assert_eq!(checked_ops(left, right), expected);
+}
+
+#[test]
+fn test_checked_u64_and_u128() {
+    assert_eq!(checked_ops(u64::MAX, 0), (Some(u64::MAX), Some(u64::MAX), Some(0)));
+    assert_eq!(
+        checked_ops(u64::MAX / 2 + 1, 2),
+        (Some(u64::MAX / 2 + 3), Some(u64::MAX / 2 - 1), None)
+    );
+    assert_eq!(checked_ops(u128::MAX, u128::MAX), (None, Some(0), None));
+    assert_eq!(checked_ops(0u128, 1), (Some(1), None, Some(0)));
+}
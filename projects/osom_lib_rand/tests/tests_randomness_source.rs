@@ -1,8 +1,11 @@
 #![cfg(all(not(osom_running_env = "github"), feature = "std_os_rand"))]
 mod common;
 
-use osom_lib_rand::randomness_sources::OsRandomnessSource;
-use osom_lib_rand::traits::RandomnessSource;
+use osom_lib_rand::pseudo_random_number_generators::Mt19937;
+use osom_lib_rand::randomness_sources::{
+    BufferedOsRandomnessSource, OsRandomnessSource, TryBufferedOsRandomnessSource, TryOsRandomnessSource,
+};
+use osom_lib_rand::traits::{PseudoRandomNumberGenerator, RandomnessSource, TryRandomnessSource};
 
 #[test]
 fn test_statistical_properties_os_randomness_source_u32() {
@@ -24,3 +27,66 @@ fn test_statistical_properties_os_randomness_source_u128() {
     common::test_statistical_properties(|| source.next_number());
     common::test_fill_bytes::<u128, _>(|bytes| source.fill_bytes(bytes));
 }
+
+#[test]
+fn test_statistical_properties_buffered_os_randomness_source_u32() {
+    let mut source = BufferedOsRandomnessSource::<u32>::default();
+    common::test_statistical_properties(|| source.next_number());
+    common::test_fill_bytes::<u32, _>(|bytes| source.fill_bytes(bytes));
+}
+
+#[test]
+fn test_statistical_properties_buffered_os_randomness_source_u128() {
+    let mut source = BufferedOsRandomnessSource::<u128, 64>::new();
+    assert_eq!(source.buffer_size(), 64);
+    common::test_statistical_properties(|| source.next_number());
+    common::test_fill_bytes::<u128, _>(|bytes| source.fill_bytes(bytes));
+}
+
+#[test]
+fn test_buffered_os_randomness_source_small_fill_bytes() {
+    let mut source = BufferedOsRandomnessSource::<u64, 16>::new();
+    let mut bytes = [0u8; 1000];
+    for chunk in bytes.chunks_mut(7) {
+        source.fill_bytes(chunk);
+        let _ = source.next_number();
+    }
+    assert!(bytes.iter().filter(|byte| **byte == 0).count() < 50);
+}
+
+#[test]
+fn test_try_randomness_source_os() {
+    let mut source = TryOsRandomnessSource::<u64>::new();
+    let numbers: Vec<u64> = (0..8).map(|_| source.try_next_number().unwrap()).collect();
+    assert!(numbers.windows(2).any(|pair| pair[0] != pair[1]));
+
+    let mut bytes = [0u8; 1000];
+    source.try_fill_bytes(&mut bytes).unwrap();
+    assert!(bytes.iter().filter(|byte| **byte == 0).count() < 50);
+}
+
+#[test]
+fn test_try_randomness_source_buffered_os() {
+    let mut source = TryBufferedOsRandomnessSource::<u32, 16>::new();
+    assert_eq!(source.buffer_size(), 16);
+    let mut bytes = [0u8; 1000];
+    for chunk in bytes.chunks_mut(7) {
+        source.try_fill_bytes(chunk).unwrap();
+        let _ = source.try_next_number().unwrap();
+    }
+    assert!(bytes.iter().filter(|byte| **byte == 0).count() < 50);
+}
+
+#[test]
+fn test_try_randomness_source_blanket_os() {
+    let mut source = OsRandomnessSource::<u32>::default();
+    let mut generator = Mt19937::try_from_randomness_source(&mut source).unwrap();
+    let _ = generator.next_number();
+
+    let mut buffered = BufferedOsRandomnessSource::<u64, 16>::new();
+    let numbers: Vec<u64> = (0..8).map(|_| buffered.try_next_number().unwrap()).collect();
+    assert!(numbers.windows(2).any(|pair| pair[0] != pair[1]));
+
+    let mut fallible = TryOsRandomnessSource::<u32>::default();
+    let _ = Mt19937::try_from_randomness_source(&mut fallible).unwrap();
+}
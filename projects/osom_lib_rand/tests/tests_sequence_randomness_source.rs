@@ -0,0 +1,41 @@
+use osom_lib_rand::randomness_sources::{SequenceEndBehavior, SequenceRandomnessSource};
+use osom_lib_rand::traits::RandomnessSource;
+
+#[test]
+fn test_sequence_repeat() {
+    let mut source = SequenceRandomnessSource::<u32>::new(&[1, 2, 3]);
+    let values: Vec<u32> = (0..7).map(|_| source.next_number()).collect();
+    assert_eq!(values, [1, 2, 3, 1, 2, 3, 1]);
+    assert_eq!(source.position(), 7);
+}
+
+#[test]
+fn test_sequence_repeat_last() {
+    let mut source = SequenceRandomnessSource::<u64>::with_end_behavior(&[5, 8], SequenceEndBehavior::RepeatLast);
+    let values: Vec<u64> = (0..5).map(|_| source.next_number()).collect();
+    assert_eq!(values, [5, 8, 8, 8, 8]);
+}
+
+#[test]
+#[should_panic(expected = "SequenceRandomnessSource is exhausted.")]
+fn test_sequence_panic() {
+    let mut source = SequenceRandomnessSource::<u128>::with_end_behavior(&[1], SequenceEndBehavior::Panic);
+    assert_eq!(source.next_number(), 1);
+    source.next_number();
+}
+
+#[test]
+#[should_panic(expected = "SequenceRandomnessSource has no values.")]
+fn test_sequence_default_is_empty() {
+    let mut source = SequenceRandomnessSource::<u32>::default();
+    source.next_number();
+}
+
+#[test]
+fn test_sequence_fill_bytes() {
+    let mut source = SequenceRandomnessSource::<u32>::new(&[0x04030201, 0x08070605]);
+    let mut bytes = [0u8; 6];
+    source.fill_bytes(&mut bytes);
+    assert_eq!(&bytes[..4], &0x04030201u32.to_ne_bytes());
+    assert_eq!(&bytes[4..], &0x08070605u32.to_ne_bytes()[..2]);
+}
@@ -0,0 +1,56 @@
+use osom_lib_rand::number::Number;
+use osom_lib_rand::randomness_sources::{ConstantRandomnessSource, SplittingRandomnessSource};
+use osom_lib_rand::traits::RandomnessSource;
+use rstest::rstest;
+
+#[rstest]
+#[case(1)]
+#[case(13212)]
+#[case(u64::MAX)]
+fn test_split_u64_into_u32(#[case] seed: u64) {
+    let mut reference = ConstantRandomnessSource::<u64>::new(seed);
+    let mut source = SplittingRandomnessSource::<_, u32>::new(ConstantRandomnessSource::<u64>::new(seed));
+
+    for _ in 0..100 {
+        let expected = reference.next_number().to_bytes();
+        let first = source.next_number().to_bytes();
+        let second = source.next_number().to_bytes();
+        assert_eq!(&expected[..4], &first);
+        assert_eq!(&expected[4..], &second);
+    }
+}
+
+#[rstest]
+#[case(1)]
+#[case(713243213243217654322541)]
+fn test_split_u128_into_u64(#[case] seed: u128) {
+    let mut reference = ConstantRandomnessSource::<u128>::new(seed);
+    let mut source = SplittingRandomnessSource::<_, u64>::new(ConstantRandomnessSource::<u128>::new(seed));
+
+    for _ in 0..100 {
+        let expected = reference.next_number().to_bytes();
+        let mut actual = [0u8; 16];
+        actual[..8].copy_from_slice(&source.next_number().to_bytes());
+        actual[8..].copy_from_slice(&source.next_number().to_bytes());
+        assert_eq!(expected, actual);
+    }
+}
+
+#[test]
+fn test_split_same_size_is_passthrough() {
+    let mut reference = ConstantRandomnessSource::<u64>::new(7);
+    let mut source = SplittingRandomnessSource::<_, u64>::new(ConstantRandomnessSource::<u64>::new(7));
+    for _ in 0..100 {
+        assert_eq!(reference.next_number(), source.next_number());
+    }
+}
+
+#[test]
+fn test_split_into_inner_drops_buffer() {
+    let mut reference = ConstantRandomnessSource::<u64>::new(3);
+    let mut source = SplittingRandomnessSource::<_, u32>::new(ConstantRandomnessSource::<u64>::new(3));
+    let _ = source.next_number();
+    let mut inner = source.into_inner();
+    reference.next_number();
+    assert_eq!(reference.next_number(), inner.next_number());
+}
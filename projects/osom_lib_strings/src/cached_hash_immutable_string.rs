@@ -0,0 +1,114 @@
+//! Holds [`CachedHashImmutableString`] struct.
+
+use core::hash::{Hash, Hasher};
+
+use osom_lib_alloc::Allocator;
+use osom_lib_hash::hashers::{Fnv1aHasherBuilder, hash_one};
+
+use crate::ImmutableString;
+
+/// Represents an [`ImmutableString`] together with its precomputed hash.
+/// Since the content never changes, the hash is computed only once, on
+/// construction, and stays valid for the entire lifetime of the string.
+///
+/// # Notes
+///
+/// The [`Hash`] implementation feeds only the cached `u64` into the hasher,
+/// making repeated hashing `O(1)` regardless of the length of the string.
+/// The cached value comes from [`Fnv1aHasherBuilder`], independently of the
+/// hasher used by the caller.
+///
+/// For that reason [`CachedHashImmutableString`] does not implement
+/// [`Borrow<str>`][`core::borrow::Borrow`]: its hash differs from the hash of
+/// the corresponding `str`, and so it cannot be looked up by `&str` in hash
+/// based collections.
+#[derive(Clone)]
+#[must_use]
+pub struct CachedHashImmutableString<TAllocator>
+where
+    TAllocator: Allocator,
+{
+    string: ImmutableString<TAllocator>,
+    hash: u64,
+}
+
+impl<TAllocator: Allocator> CachedHashImmutableString<TAllocator> {
+    /// Creates a new [`CachedHashImmutableString`] from `string`,
+    /// computing its hash.
+    #[inline]
+    pub fn new(string: ImmutableString<TAllocator>) -> Self {
+        let hash = hash_one(&Fnv1aHasherBuilder, string.as_str());
+        Self { string, hash }
+    }
+
+    /// Returns the cached hash of the [`CachedHashImmutableString`].
+    #[inline(always)]
+    #[must_use]
+    pub const fn cached_hash(&self) -> u64 {
+        self.hash
+    }
+
+    /// Returns a reference to the underlying [`ImmutableString`].
+    #[inline(always)]
+    pub const fn as_immutable_string(&self) -> &ImmutableString<TAllocator> {
+        &self.string
+    }
+
+    /// Converts the [`CachedHashImmutableString`] back into
+    /// the underlying [`ImmutableString`].
+    #[inline(always)]
+    pub fn into_inner(self) -> ImmutableString<TAllocator> {
+        self.string
+    }
+}
+
+impl<TAllocator: Allocator> From<ImmutableString<TAllocator>> for CachedHashImmutableString<TAllocator> {
+    fn from(value: ImmutableString<TAllocator>) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<TAllocator: Allocator> core::ops::Deref for CachedHashImmutableString<TAllocator> {
+    type Target = str;
+
+    fn deref(&self) -> &Self::Target {
+        self.string.as_str()
+    }
+}
+
+impl<TAllocator: Allocator> core::fmt::Debug for CachedHashImmutableString<TAllocator> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("CachedHashImmutableString")
+            .field("string", &self.string.as_str())
+            .field("hash", &self.hash)
+            .finish()
+    }
+}
+
+impl<TAllocator1: Allocator, TAllocator2: Allocator> PartialEq<CachedHashImmutableString<TAllocator1>>
+    for CachedHashImmutableString<TAllocator2>
+{
+    fn eq(&self, other: &CachedHashImmutableString<TAllocator1>) -> bool {
+        // Different hashes mean different strings, which lets most
+        // non-equal pairs skip the full comparison.
+        self.hash == other.hash && self.string == other.string
+    }
+}
+
+impl<TAllocator: Allocator> Eq for CachedHashImmutableString<TAllocator> {}
+
+impl<TAllocator: Allocator> Hash for CachedHashImmutableString<TAllocator> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        state.write_u64(self.hash);
+    }
+}
+
+#[cfg(feature = "std_alloc")]
+use osom_lib_alloc::StdAllocator;
+
+#[cfg(feature = "std_alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std_alloc")))]
+/// Alias for [`CachedHashImmutableString`] with [`StdAllocator`] as the allocator.
+///
+/// This alias is available only if the `std_alloc` feature is enabled.
+pub type StdCachedHashImmutableString = CachedHashImmutableString<StdAllocator>;
@@ -0,0 +1,196 @@
+//! Holds [`FixedString`] struct.
+#![allow(clippy::cast_possible_truncation, clippy::cast_possible_wrap)]
+
+use osom_lib_arrays::{FixedArray, OutOfRangeError};
+use osom_lib_primitives::Length;
+
+/// Represents a UTF-8 string stored inline, with the maximum size of `N` bytes
+/// known at compile time. A thin wrapper around [`FixedArray<u8, N>`] that
+/// guarantees its content is always valid UTF-8.
+///
+/// # Notes
+///
+/// Pushing is all-or-nothing: if the pushed text does not fit, then nothing
+/// is written. In particular a multi-byte character is never split.
+#[derive(Clone, Default)]
+#[must_use]
+pub struct FixedString<const N: usize> {
+    bytes: FixedArray<u8, N>,
+}
+
+impl<const N: usize> FixedString<N> {
+    /// Creates a new empty [`FixedString`].
+    #[inline(always)]
+    pub const fn new() -> Self {
+        Self {
+            bytes: FixedArray::new(),
+        }
+    }
+
+    /// Returns the length of the [`FixedString`] in bytes.
+    #[inline(always)]
+    pub const fn len(&self) -> Length {
+        self.bytes.len()
+    }
+
+    /// Returns the capacity of the [`FixedString`] in bytes, i.e. `N`.
+    #[inline(always)]
+    pub const fn capacity(&self) -> Length {
+        self.bytes.capacity()
+    }
+
+    /// Returns `true` if the [`FixedString`] is empty, `false` otherwise.
+    #[inline(always)]
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.bytes.is_empty()
+    }
+
+    /// Represents the [`FixedString`] as a string slice.
+    #[inline(always)]
+    #[must_use]
+    pub const fn as_str(&self) -> &str {
+        unsafe { core::str::from_utf8_unchecked(self.bytes.as_slice()) }
+    }
+
+    /// Appends `text` to the end of the [`FixedString`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`OutOfRangeError`] if the [`FixedString`] does not have
+    /// enough space left for `text`. In that case it is left untouched.
+    #[inline(always)]
+    pub fn push_str(&mut self, text: &str) -> Result<(), OutOfRangeError> {
+        self.bytes.extend_from_slice(text.as_bytes())
+    }
+
+    /// Appends `ch` to the end of the [`FixedString`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`OutOfRangeError`] if the [`FixedString`] does not have
+    /// enough space left for the UTF-8 encoding of `ch`. In that case
+    /// it is left untouched.
+    #[inline(always)]
+    pub fn push(&mut self, ch: char) -> Result<(), OutOfRangeError> {
+        self.push_str(ch.encode_utf8(&mut [0; 4]))
+    }
+
+    /// Removes the last character of the [`FixedString`] and returns it.
+    ///
+    /// # Returns
+    ///
+    /// * `Some` with the removed character if the [`FixedString`] is not empty
+    /// * `None` otherwise
+    pub fn pop(&mut self) -> Option<char> {
+        let ch = self.as_str().chars().next_back()?;
+        let new_len = self.bytes.len().value() - ch.len_utf8() as i32;
+        unsafe { self.bytes.set_len(Length::new_unchecked(new_len)) };
+        Some(ch)
+    }
+
+    /// Removes all content of the [`FixedString`].
+    #[inline(always)]
+    pub const fn clear(&mut self) {
+        unsafe { self.bytes.set_len(Length::ZERO) };
+    }
+}
+
+impl<const N: usize> core::fmt::Write for FixedString<N> {
+    fn write_str(&mut self, s: &str) -> core::fmt::Result {
+        self.push_str(s).map_err(|_| core::fmt::Error)
+    }
+}
+
+impl<const N: usize> core::ops::Deref for FixedString<N> {
+    type Target = str;
+
+    fn deref(&self) -> &Self::Target {
+        self.as_str()
+    }
+}
+
+impl<const N: usize> AsRef<str> for FixedString<N> {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<const N: usize> core::borrow::Borrow<str> for FixedString<N> {
+    fn borrow(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<const N: usize> core::str::FromStr for FixedString<N> {
+    type Err = OutOfRangeError;
+
+    /// Creates a new [`FixedString`] with the content of `s`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`OutOfRangeError`] if `s` is longer than `N` bytes.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut result = Self::new();
+        result.push_str(s)?;
+        Ok(result)
+    }
+}
+
+impl<const N: usize> TryFrom<&str> for FixedString<N> {
+    type Error = OutOfRangeError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl<const N: usize> core::fmt::Debug for FixedString<N> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        core::fmt::Debug::fmt(self.as_str(), f)
+    }
+}
+
+impl<const N: usize> core::fmt::Display for FixedString<N> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        core::fmt::Display::fmt(self.as_str(), f)
+    }
+}
+
+impl<const N: usize, const M: usize> PartialEq<FixedString<M>> for FixedString<N> {
+    fn eq(&self, other: &FixedString<M>) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
+impl<const N: usize> Eq for FixedString<N> {}
+
+impl<const N: usize> PartialEq<str> for FixedString<N> {
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl<const N: usize> PartialEq<&str> for FixedString<N> {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+impl<const N: usize> PartialOrd for FixedString<N> {
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<const N: usize> Ord for FixedString<N> {
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        self.as_str().cmp(other.as_str())
+    }
+}
+
+impl<const N: usize> core::hash::Hash for FixedString<N> {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.as_str().hash(state);
+    }
+}
@@ -0,0 +1,171 @@
+//! Holds [`ImmutableStr`] enum.
+
+use osom_lib_alloc::Allocator;
+
+use crate::{ImmutableString, ImmutableStringConstructionError};
+
+/// Represents a string that is either borrowed or owned by an [`ImmutableString`].
+/// Analogous to `Cow<str>` from the standard library, but the owned variant
+/// is the ref counted [`ImmutableString`].
+///
+/// # Notes
+///
+/// Cloning the [`ImmutableStr::Owned`] variant only increments the strong
+/// counter, it never copies the data.
+#[derive(Clone)]
+#[must_use]
+pub enum ImmutableStr<'a, TAllocator>
+where
+    TAllocator: Allocator,
+{
+    Borrowed(&'a str),
+    Owned(ImmutableString<TAllocator>),
+}
+
+impl<TAllocator: Allocator> ImmutableStr<'_, TAllocator> {
+    /// Represents the [`ImmutableStr`] as a string slice.
+    #[inline(always)]
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Borrowed(text) => text,
+            Self::Owned(string) => string.as_str(),
+        }
+    }
+
+    /// Returns `true` if the [`ImmutableStr`] is the [`ImmutableStr::Borrowed`] variant.
+    #[inline(always)]
+    #[must_use]
+    pub const fn is_borrowed(&self) -> bool {
+        matches!(self, Self::Borrowed(_))
+    }
+
+    /// Returns `true` if the [`ImmutableStr`] is the [`ImmutableStr::Owned`] variant.
+    #[inline(always)]
+    #[must_use]
+    pub const fn is_owned(&self) -> bool {
+        matches!(self, Self::Owned(_))
+    }
+
+    /// Returns the length of the [`ImmutableStr`] in bytes.
+    #[inline(always)]
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.as_str().len()
+    }
+
+    /// Returns `true` if the [`ImmutableStr`] is empty, `false` otherwise.
+    #[inline(always)]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.as_str().is_empty()
+    }
+
+    /// Converts the [`ImmutableStr`] into an [`ImmutableString`]. The borrowed
+    /// variant is copied into a new [`ImmutableString`] with default allocator,
+    /// the owned variant is returned as is.
+    ///
+    /// # Errors
+    ///
+    /// For details see [`ImmutableStringConstructionError`].
+    pub fn into_owned(self) -> Result<ImmutableString<TAllocator>, ImmutableStringConstructionError> {
+        match self {
+            Self::Borrowed(text) => ImmutableString::new(text),
+            Self::Owned(string) => Ok(string),
+        }
+    }
+}
+
+impl<'a, TAllocator: Allocator> From<&'a str> for ImmutableStr<'a, TAllocator> {
+    fn from(value: &'a str) -> Self {
+        Self::Borrowed(value)
+    }
+}
+
+impl<TAllocator: Allocator> From<ImmutableString<TAllocator>> for ImmutableStr<'_, TAllocator> {
+    fn from(value: ImmutableString<TAllocator>) -> Self {
+        Self::Owned(value)
+    }
+}
+
+impl<TAllocator: Allocator> core::ops::Deref for ImmutableStr<'_, TAllocator> {
+    type Target = str;
+
+    fn deref(&self) -> &Self::Target {
+        self.as_str()
+    }
+}
+
+impl<TAllocator: Allocator> AsRef<str> for ImmutableStr<'_, TAllocator> {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<TAllocator: Allocator> core::borrow::Borrow<str> for ImmutableStr<'_, TAllocator> {
+    fn borrow(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<TAllocator: Allocator> core::fmt::Debug for ImmutableStr<'_, TAllocator> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        core::fmt::Debug::fmt(self.as_str(), f)
+    }
+}
+
+impl<TAllocator: Allocator> core::fmt::Display for ImmutableStr<'_, TAllocator> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<TAllocator1: Allocator, TAllocator2: Allocator> PartialEq<ImmutableStr<'_, TAllocator1>>
+    for ImmutableStr<'_, TAllocator2>
+{
+    fn eq(&self, other: &ImmutableStr<'_, TAllocator1>) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
+impl<TAllocator: Allocator> Eq for ImmutableStr<'_, TAllocator> {}
+
+impl<TAllocator: Allocator> PartialEq<str> for ImmutableStr<'_, TAllocator> {
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl<TAllocator: Allocator> PartialEq<&str> for ImmutableStr<'_, TAllocator> {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+impl<TAllocator: Allocator> PartialOrd for ImmutableStr<'_, TAllocator> {
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<TAllocator: Allocator> Ord for ImmutableStr<'_, TAllocator> {
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        self.as_str().cmp(other.as_str())
+    }
+}
+
+impl<TAllocator: Allocator> core::hash::Hash for ImmutableStr<'_, TAllocator> {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.as_str().hash(state);
+    }
+}
+
+#[cfg(feature = "std_alloc")]
+use osom_lib_alloc::StdAllocator;
+
+#[cfg(feature = "std_alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std_alloc")))]
+/// Alias for [`ImmutableStr`] with [`StdAllocator`] as the allocator.
+///
+/// This alias is available only if the `std_alloc` feature is enabled.
+pub type StdImmutableStr<'a> = ImmutableStr<'a, StdAllocator>;
@@ -1,9 +1,10 @@
 //! Holds [`ImmutableString`] struct and related tools.
+#![allow(clippy::cast_possible_truncation, clippy::cast_possible_wrap)]
 
 use core::mem::ManuallyDrop;
 
 use osom_lib_alloc::Allocator;
-use osom_lib_arrays::{ImmutableArray, ImmutableWeakArray, errors::ArrayConstructionError};
+use osom_lib_arrays::{ImmutableArray, ImmutableArrayBuilder, ImmutableWeakArray, errors::ArrayConstructionError};
 use osom_lib_primitives::Length;
 
 /// Represents an error that occurs when constructing new [`ImmutableString`].
@@ -18,6 +19,28 @@ pub enum ImmutableStringConstructionError {
     StringTooLong,
 }
 
+impl ImmutableStringConstructionError {
+    /// Returns `true` if the error was caused by the allocator, `false` otherwise.
+    #[inline(always)]
+    #[must_use]
+    pub const fn is_allocation_error(&self) -> bool {
+        matches!(self, ImmutableStringConstructionError::AllocationError)
+    }
+}
+
+impl core::fmt::Display for ImmutableStringConstructionError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            ImmutableStringConstructionError::AllocationError => {
+                f.write_str("failed to allocate memory for the string")
+            }
+            ImmutableStringConstructionError::StringTooLong => f.write_str("string length exceeds the maximum length"),
+        }
+    }
+}
+
+impl core::error::Error for ImmutableStringConstructionError {}
+
 impl From<ArrayConstructionError> for ImmutableStringConstructionError {
     fn from(error: ArrayConstructionError) -> Self {
         match error {
@@ -38,7 +61,9 @@ impl From<ArrayConstructionError> for ImmutableStringConstructionError {
 /// In order to build [`ImmutableString`] incrementally, use
 /// [`ImmutableArrayBuilder<u8>`][`osom_lib_arrays::ImmutableArrayBuilder<u8>`]
 /// and convert the final [`ImmutableArray<u8>`] to [`ImmutableString`] either safely
-/// (with UTF-8 validation) or unsafely (without validation).
+/// (with UTF-8 validation) or unsafely (without validation). The safe path
+/// is also available in one step through [`ImmutableString::from_builder`], or
+/// [`build_string`][`ImmutableArrayBuilderExt::build_string`] on the builder itself.
 #[derive(Clone)]
 #[repr(transparent)]
 #[must_use]
@@ -82,6 +107,22 @@ impl<TAllocator: Allocator> ImmutableWeakString<TAllocator> {
         Some(ImmutableString::from_internal(internal))
     }
 
+    /// Temporarily upgrades the [`ImmutableWeakString`] and calls `f` with the
+    /// content of the string. The strong reference is released right after `f` returns.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(R)` with the result of `f` if the string is still alive
+    /// * `None` if the string has been deallocated, `f` is not called then
+    #[inline]
+    pub fn with_upgraded<R, F>(&self, f: F) -> Option<R>
+    where
+        F: FnOnce(&str) -> R,
+    {
+        let strong = self.upgrade()?;
+        Some(f(strong.as_str()))
+    }
+
     /// Returns the number of strong references to the [`ImmutableWeakString`].
     #[inline(always)]
     #[must_use]
@@ -146,6 +187,70 @@ impl<TAllocator: Allocator> ImmutableString<TAllocator> {
         })
     }
 
+    /// Constructs a new [`ImmutableString`] from bytes with default allocator.
+    /// Invalid UTF-8 sequences are replaced with `U+FFFD REPLACEMENT CHARACTER`.
+    /// Same as `String::from_utf8_lossy`.
+    ///
+    /// # Errors
+    ///
+    /// For details see [`ImmutableStringConstructionError`].
+    #[inline(always)]
+    pub fn from_bytes_lossy(bytes: &[u8]) -> Result<Self, ImmutableStringConstructionError> {
+        Self::from_bytes_lossy_with_allocator(bytes, TAllocator::default())
+    }
+
+    /// Constructs a new [`ImmutableString`] from bytes and an allocator.
+    /// Invalid UTF-8 sequences are replaced with `U+FFFD REPLACEMENT CHARACTER`.
+    ///
+    /// # Notes
+    ///
+    /// If `bytes` are valid UTF-8, they are copied directly. Otherwise
+    /// the result is built incrementally with [`ImmutableArrayBuilder<u8>`].
+    ///
+    /// # Errors
+    ///
+    /// For details see [`ImmutableStringConstructionError`].
+    pub fn from_bytes_lossy_with_allocator(
+        bytes: &[u8],
+        allocator: TAllocator,
+    ) -> Result<Self, ImmutableStringConstructionError> {
+        if let Ok(text) = core::str::from_utf8(bytes) {
+            return Self::with_allocator(text, allocator);
+        }
+
+        let mut builder = ImmutableArrayBuilder::with_allocator(allocator)?;
+        for chunk in bytes.utf8_chunks() {
+            builder.extend_from_slice(chunk.valid().as_bytes())?;
+            if !chunk.invalid().is_empty() {
+                builder.extend_from_slice(char::REPLACEMENT_CHARACTER.encode_utf8(&mut [0; 4]).as_bytes())?;
+            }
+        }
+        Ok(Self::from_internal(builder.build()))
+    }
+
+    /// Builds the [`ImmutableArray<u8>`] out of `builder` and converts it into
+    /// [`ImmutableString`], validating UTF-8 on the way.
+    ///
+    /// # Notes
+    ///
+    /// This is the terminal step of building [`ImmutableString`] incrementally
+    /// with [`ImmutableArrayBuilder<u8>`]. It does not copy the data.
+    ///
+    /// # Errors
+    ///
+    /// Returns the built [`ImmutableArray<u8>`] back, together with the
+    /// [`Utf8Error`][`core::str::Utf8Error`], if the bytes are not valid UTF-8.
+    pub fn from_builder(
+        builder: ImmutableArrayBuilder<u8, TAllocator>,
+    ) -> Result<Self, (ImmutableArray<u8, TAllocator>, core::str::Utf8Error)> {
+        let array = builder.build();
+        match core::str::from_utf8(array.as_slice()) {
+            Ok(_) => Ok(Self::from_internal(array)),
+            Err(error) => Err((array, error)),
+        }
+    }
+
+    /// Represents the [`ImmutableString`] as a string slice.
     #[inline(always)]
     #[must_use]
     pub fn as_str(&self) -> &str {
@@ -153,6 +258,132 @@ impl<TAllocator: Allocator> ImmutableString<TAllocator> {
         unsafe { core::str::from_utf8_unchecked(slice) }
     }
 
+    /// Represents the [`ImmutableString`] as a byte slice.
+    #[inline(always)]
+    #[must_use]
+    pub fn as_bytes(&self) -> &[u8] {
+        self.internal.as_slice()
+    }
+
+    /// Checks whether the [`ImmutableString`] is equal to `other`, ignoring
+    /// the case of ASCII letters.
+    ///
+    /// # Notes
+    ///
+    /// Only ASCII letters are case folded, this is not a full Unicode
+    /// case-insensitive comparison. Same as [`str::eq_ignore_ascii_case`].
+    #[inline(always)]
+    #[must_use]
+    pub fn eq_ignore_ascii_case(&self, other: &str) -> bool {
+        self.as_bytes().eq_ignore_ascii_case(other.as_bytes())
+    }
+
+    /// Compares the [`ImmutableString`] with `other` lexicographically byte by byte,
+    /// ignoring the case of ASCII letters.
+    ///
+    /// # Notes
+    ///
+    /// Only ASCII letters are case folded (to lowercase), this is not a full
+    /// Unicode case-insensitive comparison. In particular the result is consistent
+    /// with [`eq_ignore_ascii_case`][`Self::eq_ignore_ascii_case`].
+    #[must_use]
+    pub fn cmp_ignore_ascii_case<TOtherAllocator: Allocator>(
+        &self,
+        other: &ImmutableString<TOtherAllocator>,
+    ) -> core::cmp::Ordering {
+        let left = self.as_bytes().iter().map(u8::to_ascii_lowercase);
+        let right = other.as_bytes().iter().map(u8::to_ascii_lowercase);
+        left.cmp(right)
+    }
+
+    /// Creates a new [`ImmutableString`] with all ASCII letters converted to
+    /// lowercase. Same as [`str::to_ascii_lowercase`].
+    ///
+    /// # Notes
+    ///
+    /// Non-ASCII characters pass through unchanged, this is not a full
+    /// Unicode case mapping. The result is allocated with a clone of the allocator.
+    ///
+    /// # Errors
+    ///
+    /// For details see [`ImmutableStringConstructionError`].
+    pub fn to_ascii_lowercase(&self) -> Result<Self, ImmutableStringConstructionError> {
+        self.map_bytes(u8::to_ascii_lowercase)
+    }
+
+    /// Creates a new [`ImmutableString`] with all ASCII letters converted to
+    /// uppercase. Same as [`str::to_ascii_uppercase`].
+    ///
+    /// # Notes
+    ///
+    /// Non-ASCII characters pass through unchanged, this is not a full
+    /// Unicode case mapping. The result is allocated with a clone of the allocator.
+    ///
+    /// # Errors
+    ///
+    /// For details see [`ImmutableStringConstructionError`].
+    pub fn to_ascii_uppercase(&self) -> Result<Self, ImmutableStringConstructionError> {
+        self.map_bytes(u8::to_ascii_uppercase)
+    }
+
+    /// Copies the bytes into a new [`ImmutableString`], mapping each with `f`.
+    /// `f` has to preserve UTF-8 validity, which ASCII case folding does,
+    /// since it only touches bytes below `0x80`.
+    fn map_bytes(&self, f: fn(&u8) -> u8) -> Result<Self, ImmutableStringConstructionError> {
+        let mut builder = ImmutableArrayBuilder::with_allocator(self.internal.allocator().clone())?;
+        builder.try_extend(self.as_bytes().iter().map(f))?;
+        Ok(Self::from_internal(builder.build()))
+    }
+
+    /// Returns an iterator over the lines of the [`ImmutableString`], as
+    /// [`ImmutableString`] sub views. Lines are split on `\n`, and an optional
+    /// trailing `\r` is stripped.
+    ///
+    /// # Notes
+    ///
+    /// The behaviour, including handling of empty and trailing lines,
+    /// is exactly the same as of [`str::lines`]. No data is copied, each item
+    /// shares the underlying buffer and increments the strong counter.
+    pub fn lines(&self) -> impl Iterator<Item = ImmutableString<TAllocator>> + '_ {
+        self.as_str().lines().map(|line| self.sub_string(line))
+    }
+
+    /// Returns an iterator over non-empty, whitespace separated parts of the
+    /// [`ImmutableString`], as [`ImmutableString`] sub views.
+    ///
+    /// # Notes
+    ///
+    /// The behaviour is exactly the same as of [`str::split_whitespace`].
+    /// No data is copied, each item shares the underlying buffer and increments
+    /// the strong counter.
+    pub fn split_whitespace(&self) -> impl Iterator<Item = ImmutableString<TAllocator>> + '_ {
+        self.as_str().split_whitespace().map(|part| self.sub_string(part))
+    }
+
+    /// Creates a sub view out of `part`, which has to be a subslice of `self.as_str()`.
+    fn sub_string(&self, part: &str) -> Self {
+        let base = self.as_str().as_ptr();
+        let offset = part.as_ptr().addr() - base.addr();
+        debug_assert!(
+            offset + part.len() <= self.as_str().len(),
+            "Part is not a subslice of the ImmutableString."
+        );
+        let start = unsafe { Length::new_unchecked(offset as i32) };
+        let length = unsafe { Length::new_unchecked(part.len() as i32) };
+        Self::from_internal(ImmutableArray::sub_array(&self.internal, start, length))
+    }
+
+    /// Converts the [`ImmutableString`] into the underlying [`ImmutableArray<u8>`].
+    ///
+    /// # Notes
+    ///
+    /// This is the same as the [`From`] conversion. It does not copy
+    /// the data nor modify ref counters.
+    #[inline(always)]
+    pub fn into_bytes(self) -> ImmutableArray<u8, TAllocator> {
+        self.into()
+    }
+
     /// Downgrades the [`ImmutableString`] to a [`ImmutableWeakString`] and increments the internal weak counter.
     #[inline(always)]
     pub fn downgrade(instance: &Self) -> ImmutableWeakString<TAllocator> {
@@ -186,6 +417,43 @@ impl<TAllocator: Allocator> ImmutableString<TAllocator> {
         self.len().value() == 0
     }
 
+    /// Returns the number of characters (Unicode scalar values) of the [`ImmutableString`].
+    ///
+    /// # Notes
+    ///
+    /// Unlike [`len`][`Self::len`], which is O(1) and returns the number of bytes,
+    /// this walks the entire string.
+    #[inline(always)]
+    #[must_use]
+    pub fn char_count(&self) -> usize {
+        self.as_str().chars().count()
+    }
+
+    /// Checks whether `index` is a char boundary of the [`ImmutableString`].
+    /// Same as [`str::is_char_boundary`], in particular `0` and `self.len()`
+    /// are always boundaries.
+    #[inline(always)]
+    #[must_use]
+    pub fn is_char_boundary(&self, index: usize) -> bool {
+        self.as_str().is_char_boundary(index)
+    }
+
+    /// Converts a byte offset into a char offset of the [`ImmutableString`].
+    ///
+    /// # Returns
+    ///
+    /// * `Some(char_index)` if `byte_index` is a char boundary, with `self.len()`
+    ///   mapped to [`char_count`][`Self::char_count`]
+    /// * `None` otherwise
+    #[must_use]
+    pub fn byte_to_char_index(&self, byte_index: usize) -> Option<usize> {
+        let text = self.as_str();
+        if !text.is_char_boundary(byte_index) {
+            return None;
+        }
+        Some(text[..byte_index].chars().count())
+    }
+
     /// Releases the [`ImmutableString`]. If this was the last strong reference,
     /// it will return a [`ImmutableWeakString`]. Otherwise it will return `None`.
     pub fn release(mut instance: Self) -> Option<ImmutableWeakString<TAllocator>> {
@@ -243,9 +511,30 @@ impl<TAllocator1: Allocator, TAllocator2: Allocator> core::cmp::PartialEq<Immuta
 
 impl<TAllocator: Allocator> core::cmp::Eq for ImmutableString<TAllocator> {}
 
+impl<TAllocator1: Allocator, TAllocator2: Allocator> core::cmp::PartialOrd<ImmutableString<TAllocator1>>
+    for ImmutableString<TAllocator2>
+{
+    fn partial_cmp(&self, other: &ImmutableString<TAllocator1>) -> Option<core::cmp::Ordering> {
+        self.as_str().partial_cmp(other.as_str())
+    }
+}
+
+impl<TAllocator: Allocator> core::cmp::Ord for ImmutableString<TAllocator> {
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        self.as_str().cmp(other.as_str())
+    }
+}
+
 impl<TAllocator: Allocator> core::hash::Hash for ImmutableString<TAllocator> {
     fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
-        self.internal.hash(state);
+        // Has to be the same as hashing `str`, because of the `Borrow<str>` implementation.
+        self.as_str().hash(state);
+    }
+}
+
+impl<TAllocator: Allocator> core::borrow::Borrow<str> for ImmutableString<TAllocator> {
+    fn borrow(&self) -> &str {
+        self.as_str()
     }
 }
 
@@ -268,6 +557,36 @@ impl<TAllocator: Allocator> From<ImmutableString<TAllocator>> for ImmutableArray
     }
 }
 
+/// Extends [`ImmutableArrayBuilder<u8>`] with the terminal step of building
+/// [`ImmutableString`].
+///
+/// # Notes
+///
+/// This is a trait, because `osom_lib_arrays` cannot depend on this crate
+/// (that would be a dependency cycle). Bring it into scope to call
+/// [`build_string`][`Self::build_string`] on the builder.
+pub trait ImmutableArrayBuilderExt<TAllocator: Allocator> {
+    /// Builds the [`ImmutableArray<u8>`] and converts it into [`ImmutableString`],
+    /// validating UTF-8 on the way. For details see [`ImmutableString::from_builder`].
+    ///
+    /// # Errors
+    ///
+    /// Returns the built [`ImmutableArray<u8>`] back, together with the
+    /// [`Utf8Error`][`core::str::Utf8Error`], if the bytes are not valid UTF-8.
+    fn build_string(
+        self,
+    ) -> Result<ImmutableString<TAllocator>, (ImmutableArray<u8, TAllocator>, core::str::Utf8Error)>;
+}
+
+impl<TAllocator: Allocator> ImmutableArrayBuilderExt<TAllocator> for ImmutableArrayBuilder<u8, TAllocator> {
+    #[inline(always)]
+    fn build_string(
+        self,
+    ) -> Result<ImmutableString<TAllocator>, (ImmutableArray<u8, TAllocator>, core::str::Utf8Error)> {
+        ImmutableString::from_builder(self)
+    }
+}
+
 #[cfg(feature = "std_alloc")]
 use osom_lib_alloc::StdAllocator;
 
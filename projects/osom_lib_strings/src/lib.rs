@@ -15,3 +15,16 @@
 
 mod immutable_string;
 pub use immutable_string::*;
+
+mod immutable_str;
+pub use immutable_str::*;
+
+mod fixed_string;
+pub use fixed_string::*;
+
+mod cached_hash_immutable_string;
+pub use cached_hash_immutable_string::*;
+
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+mod serde_impls;
@@ -0,0 +1,40 @@
+//! Implements [`serde`] traits for the strings of this crate.
+use core::{fmt, marker::PhantomData};
+
+use osom_lib_alloc::Allocator;
+use serde::{
+    Deserialize, Deserializer, Serialize, Serializer,
+    de::{Error, Visitor},
+};
+
+use crate::ImmutableString;
+
+impl<TAllocator: Allocator> Serialize for ImmutableString<TAllocator> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+struct ImmutableStringVisitor<TAllocator> {
+    phantom: PhantomData<TAllocator>,
+}
+
+impl<TAllocator: Allocator> Visitor<'_> for ImmutableStringVisitor<TAllocator> {
+    type Value = ImmutableString<TAllocator>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a string")
+    }
+
+    fn visit_str<E: Error>(self, v: &str) -> Result<Self::Value, E> {
+        ImmutableString::new(v).map_err(E::custom)
+    }
+}
+
+/// Copies the deserialized text into a new [`ImmutableString`] with
+/// the default allocator, see [`ImmutableString::new`].
+impl<'de, TAllocator: Allocator> Deserialize<'de> for ImmutableString<TAllocator> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_str(ImmutableStringVisitor { phantom: PhantomData })
+    }
+}
@@ -0,0 +1,53 @@
+#![cfg(feature = "std_alloc")]
+
+use std::{
+    collections::HashSet,
+    hash::{BuildHasher, RandomState},
+};
+
+use osom_lib_strings::{StdCachedHashImmutableString, StdImmutableString};
+use rstest::rstest;
+
+fn new_cached(text: &str) -> StdCachedHashImmutableString {
+    StdImmutableString::new(text).unwrap().into()
+}
+
+#[rstest]
+#[case("")]
+#[case("a")]
+#[case("Hello, world!")]
+fn test_cached_hash_is_stable(#[case] text: &str) {
+    let first = new_cached(text);
+    let second = new_cached(text);
+    assert_eq!(first.cached_hash(), second.cached_hash());
+    assert_eq!(first, second);
+    assert_eq!(&*first, text);
+
+    let state = RandomState::new();
+    assert_eq!(state.hash_one(&first), state.hash_one(&second));
+    assert_eq!(state.hash_one(&first), state.hash_one(first.clone()));
+}
+
+#[test]
+fn test_cached_hash_not_equal() {
+    assert_ne!(new_cached("abc"), new_cached("abd"));
+    assert_ne!(new_cached("abc"), new_cached("ab"));
+}
+
+#[test]
+fn test_cached_hash_in_hash_set() {
+    let mut set = HashSet::new();
+    assert!(set.insert(new_cached("foo")));
+    assert!(set.insert(new_cached("bar")));
+    assert!(!set.insert(new_cached("foo")));
+    assert!(set.contains(&new_cached("bar")));
+    assert!(!set.contains(&new_cached("baz")));
+}
+
+#[test]
+fn test_cached_hash_into_inner() {
+    let string = StdImmutableString::new("text").unwrap();
+    let cached = StdCachedHashImmutableString::new(string.clone());
+    assert_eq!(cached.as_immutable_string(), &string);
+    assert_eq!(cached.into_inner(), string);
+}
@@ -0,0 +1,81 @@
+use core::fmt::Write;
+
+use osom_lib_strings::FixedString;
+use rstest::rstest;
+
+#[test]
+fn test_push_and_pop() {
+    let mut text = FixedString::<8>::new();
+    assert!(text.is_empty());
+    assert_eq!(text.capacity().value(), 8);
+
+    text.push_str("ab").unwrap();
+    text.push('ż').unwrap();
+    assert_eq!(text, "abż");
+    assert_eq!(text.len().value(), 4);
+
+    assert_eq!(text.pop(), Some('ż'));
+    assert_eq!(text.pop(), Some('b'));
+    assert_eq!(text, "a");
+
+    text.clear();
+    assert!(text.is_empty());
+    assert_eq!(text.pop(), None);
+}
+
+#[rstest]
+#[case("abc", "", true)]
+#[case("abc", "de", true)]
+#[case("abc", "def", false)]
+#[case("abcd", "ż", false)]
+#[case("abc", "ż", true)]
+fn test_push_str_overflow(#[case] initial: &str, #[case] pushed: &str, #[case] fits: bool) {
+    let mut text: FixedString<5> = initial.parse().unwrap();
+    assert_eq!(text.push_str(pushed).is_ok(), fits);
+    if fits {
+        assert_eq!(text.len().value() as usize, initial.len() + pushed.len());
+    } else {
+        assert_eq!(text, initial);
+    }
+}
+
+#[test]
+fn test_push_char_never_splits() {
+    let mut text = FixedString::<3>::try_from("ab").unwrap();
+    assert!(text.push('ż').is_err());
+    assert_eq!(text, "ab");
+    text.push('c').unwrap();
+    assert_eq!(text, "abc");
+    assert!(text.push('d').is_err());
+}
+
+#[test]
+fn test_from_str_too_long() {
+    assert!("abcdef".parse::<FixedString<5>>().is_err());
+    assert!(FixedString::<5>::try_from("abcde").is_ok());
+}
+
+#[test]
+fn test_fmt_write() {
+    let mut text = FixedString::<16>::new();
+    let id = "id";
+    write!(text, "{id}-{:03}", 7).unwrap();
+    assert_eq!(text.as_str(), "id-007");
+    assert_eq!(format!("{text}"), "id-007");
+    assert_eq!(format!("{text:?}"), "\"id-007\"");
+
+    assert!(write!(text, "{id} is too long").is_err());
+    assert!(core::str::from_utf8(text.as_bytes()).is_ok());
+}
+
+#[test]
+fn test_comparisons() {
+    let short = FixedString::<4>::try_from("abc").unwrap();
+    let long = FixedString::<16>::try_from("abc").unwrap();
+    assert!(short == long);
+    assert!(short == *"abc");
+
+    let other = FixedString::<4>::try_from("abd").unwrap();
+    assert!(short < other);
+    assert_eq!(short.clone(), short);
+}
@@ -0,0 +1,45 @@
+use osom_lib_strings::{StdImmutableStr, StdImmutableString};
+use rstest::rstest;
+
+#[rstest]
+#[case("")]
+#[case("abc")]
+#[case("zażółć gęślą jaźń")]
+fn test_borrowed(#[case] text: &str) {
+    let value = StdImmutableStr::from(text);
+    assert!(value.is_borrowed());
+    assert!(!value.is_owned());
+    assert_eq!(value.as_str(), text);
+    assert_eq!(value.len(), text.len());
+    assert_eq!(value.is_empty(), text.is_empty());
+
+    let owned = value.into_owned().unwrap();
+    assert_eq!(owned.as_str(), text);
+    assert_eq!(StdImmutableString::strong_count(&owned), 1);
+}
+
+#[test]
+fn test_owned() {
+    let string = StdImmutableString::new("hello").unwrap();
+    let value = StdImmutableStr::from(string.clone());
+    assert!(value.is_owned());
+    assert_eq!(StdImmutableString::strong_count(&string), 2);
+
+    let owned = value.into_owned().unwrap();
+    assert_eq!(owned, string);
+    assert_eq!(owned.as_str().as_ptr(), string.as_str().as_ptr());
+    assert_eq!(StdImmutableString::strong_count(&string), 2);
+}
+
+#[test]
+fn test_comparisons() {
+    let borrowed = StdImmutableStr::from("abc");
+    let owned = StdImmutableStr::from(StdImmutableString::new("abc").unwrap());
+    let other = StdImmutableStr::from("abd");
+    assert_eq!(borrowed, owned);
+    assert_eq!(borrowed, "abc");
+    assert!(owned < other);
+    assert_eq!(format!("{owned}"), "abc");
+    assert_eq!(format!("{borrowed:?}"), "\"abc\"");
+    assert!(owned.starts_with("ab"));
+}
@@ -1,5 +1,7 @@
 #![cfg(feature = "std_alloc")]
 
+use core::cmp::Ordering;
+
 use osom_lib_strings::{StdImmutableString, StdImmutableWeakString};
 use rstest::rstest;
 
@@ -104,3 +106,226 @@ fn test_immutable_string_not_equal(#[case] left: &str, #[case] right: &str) {
     let right = new_string(right);
     assert_ne!(left, right);
 }
+
+#[rstest]
+#[case("")]
+#[case("abc")]
+#[case("zażółć gęślą jaźń")]
+fn test_immutable_string_bytes(#[case] text: &str) {
+    let string = new_string(text);
+    assert_eq!(string.as_bytes(), text.as_bytes());
+
+    let clone = string.clone();
+    let bytes = clone.into_bytes();
+    assert_eq!(bytes.as_slice(), text.as_bytes());
+    assert_eq!(StdImmutableString::strong_count(&string), 2);
+}
+
+#[rstest]
+#[case("", "", true)]
+#[case("Content-Type", "content-type", true)]
+#[case("HELLO", "hello", true)]
+#[case("hello", "hellO!", false)]
+#[case("Łódź", "łódź", false)]
+#[case("Łódź", "Łódź", true)]
+fn test_immutable_string_eq_ignore_ascii_case(#[case] left: &str, #[case] right: &str, #[case] expected: bool) {
+    let string = new_string(left);
+    assert_eq!(string.eq_ignore_ascii_case(right), expected);
+}
+
+#[rstest]
+#[case("", "", Ordering::Equal)]
+#[case("abc", "ABC", Ordering::Equal)]
+#[case("abc", "ABD", Ordering::Less)]
+#[case("B", "a", Ordering::Greater)]
+#[case("ab", "ABC", Ordering::Less)]
+#[case("Zebra", "apple", Ordering::Greater)]
+fn test_immutable_string_cmp_ignore_ascii_case(#[case] left: &str, #[case] right: &str, #[case] expected: Ordering) {
+    let left = new_string(left);
+    let right = new_string(right);
+    assert_eq!(left.cmp_ignore_ascii_case(&right), expected);
+    assert_eq!(right.cmp_ignore_ascii_case(&left), expected.reverse());
+}
+
+#[rstest]
+#[case("")]
+#[case("\n")]
+#[case("single")]
+#[case("first\nsecond")]
+#[case("first\r\nsecond\r\n")]
+#[case("a\n\nb\n")]
+#[case("trailing\r")]
+#[case("\r\n\r\n")]
+fn test_immutable_string_lines(#[case] text: &str) {
+    let string = new_string(text);
+    let lines: Vec<StdImmutableString> = string.lines().collect();
+    let expected: Vec<&str> = text.lines().collect();
+    assert_eq!(lines.len(), expected.len());
+    for (line, expected) in lines.iter().zip(expected) {
+        assert_eq!(line.as_str(), expected);
+    }
+    assert_eq!(StdImmutableString::strong_count(&string), 1 + lines.len());
+}
+
+#[rstest]
+#[case("")]
+#[case("   ")]
+#[case("single")]
+#[case("  a  b\tc\n d ")]
+#[case("zażółć\u{2003}gęślą jaźń")]
+fn test_immutable_string_split_whitespace(#[case] text: &str) {
+    let string = new_string(text);
+    let parts: Vec<StdImmutableString> = string.split_whitespace().collect();
+    let expected: Vec<&str> = text.split_whitespace().collect();
+    assert_eq!(parts.len(), expected.len());
+    for (part, expected) in parts.iter().zip(expected) {
+        assert_eq!(part.as_str(), expected);
+        assert_eq!(part.len().value() as usize, expected.len());
+    }
+    drop(string);
+    for part in &parts {
+        assert!(!part.is_empty());
+    }
+}
+
+#[test]
+fn test_weak_with_upgraded() {
+    let string = StdImmutableString::new("hello").unwrap();
+    let weak = StdImmutableString::downgrade(&string);
+    assert_eq!(weak.with_upgraded(str::len), Some(5));
+    assert_eq!(StdImmutableString::strong_count(&string), 1);
+
+    drop(string);
+    let mut called = false;
+    assert_eq!(weak.with_upgraded(|_| called = true), None);
+    assert!(!called);
+}
+
+#[test]
+fn test_construction_error_display() {
+    use osom_lib_strings::ImmutableStringConstructionError;
+
+    assert_eq!(
+        ImmutableStringConstructionError::AllocationError.to_string(),
+        "failed to allocate memory for the string"
+    );
+    assert_eq!(
+        ImmutableStringConstructionError::StringTooLong.to_string(),
+        "string length exceeds the maximum length"
+    );
+}
+
+#[test]
+fn test_construction_error_is_allocation_error() {
+    use osom_lib_strings::ImmutableStringConstructionError;
+
+    assert!(ImmutableStringConstructionError::AllocationError.is_allocation_error());
+    assert!(!ImmutableStringConstructionError::StringTooLong.is_allocation_error());
+}
+
+#[rstest]
+#[case("", 0)]
+#[case("abc", 3)]
+#[case("zażółć", 6)]
+#[case("日本語", 3)]
+#[case("a🦀b", 3)]
+fn test_char_count(#[case] text: &str, #[case] expected: usize) {
+    let string = new_string(text);
+    assert_eq!(string.char_count(), expected);
+    assert_eq!(string.byte_to_char_index(text.len()), Some(expected));
+}
+
+#[rstest]
+#[case(0, Some(0))]
+#[case(1, Some(1))]
+#[case(2, None)]
+#[case(3, Some(2))]
+#[case(6, None)]
+#[case(7, Some(3))]
+#[case(8, None)]
+fn test_byte_to_char_index(#[case] byte_index: usize, #[case] expected: Option<usize>) {
+    let string = new_string("aż🦀");
+    assert_eq!(string.is_char_boundary(byte_index), expected.is_some());
+    assert_eq!(string.byte_to_char_index(byte_index), expected);
+}
+
+#[test]
+fn test_from_builder() {
+    use osom_lib_arrays::StdImmutableArrayBuilder;
+
+    let mut builder = StdImmutableArrayBuilder::<u8>::new().unwrap();
+    builder.extend_from_slice("zażółć".as_bytes()).unwrap();
+    builder.extend_from_slice(b" jazn").unwrap();
+    let string = StdImmutableString::from_builder(builder).unwrap();
+    assert_eq!(string.as_str(), "zażółć jazn");
+    assert_eq!(StdImmutableString::strong_count(&string), 1);
+    assert_eq!(StdImmutableString::weak_count(&string), 1);
+}
+
+#[test]
+fn test_build_string() {
+    use osom_lib_arrays::StdImmutableArrayBuilder;
+    use osom_lib_strings::ImmutableArrayBuilderExt;
+
+    let mut builder = StdImmutableArrayBuilder::<u8>::new().unwrap();
+    builder.extend_from_slice(b"foo bar").unwrap();
+    let string = builder.build_string().unwrap();
+    assert_eq!(string.as_str(), "foo bar");
+
+    let mut builder = StdImmutableArrayBuilder::<u8>::new().unwrap();
+    builder.extend_from_slice(&[0xC3, b'x']).unwrap();
+    let (array, error) = builder.build_string().unwrap_err();
+    assert_eq!(array.as_slice(), &[0xC3, b'x']);
+    assert_eq!(error.valid_up_to(), 0);
+}
+
+#[test]
+fn test_from_builder_invalid_utf8() {
+    use osom_lib_arrays::StdImmutableArrayBuilder;
+
+    let mut builder = StdImmutableArrayBuilder::<u8>::new().unwrap();
+    builder.extend_from_slice(&[b'a', 0xFF, b'b']).unwrap();
+    let (array, error) = StdImmutableString::from_builder(builder).unwrap_err();
+    assert_eq!(array.as_slice(), &[b'a', 0xFF, b'b']);
+    assert_eq!(error.valid_up_to(), 1);
+}
+
+#[rstest]
+#[case(b"", "")]
+#[case(b"hello", "hello")]
+#[case("zażółć".as_bytes(), "zażółć")]
+#[case(b"a\xFFb", "a\u{FFFD}b")]
+#[case(b"\xF0\x9F\xA6", "\u{FFFD}")]
+#[case(b"ab\xC3", "ab\u{FFFD}")]
+#[case(b"\xFF\xFEx\xC3\xB3", "\u{FFFD}\u{FFFD}xó")]
+fn test_from_bytes_lossy(#[case] bytes: &[u8], #[case] expected: &str) {
+    let string = StdImmutableString::from_bytes_lossy(bytes).unwrap();
+    assert_eq!(string.as_str(), expected);
+    assert_eq!(string.as_str(), String::from_utf8_lossy(bytes));
+    assert_eq!(StdImmutableString::strong_count(&string), 1);
+}
+
+#[rstest]
+#[case("", "", "")]
+#[case("Content-Type", "content-type", "CONTENT-TYPE")]
+#[case("Zażółć GĘŚLĄ", "zażółć gĘŚlĄ", "ZAżółć GĘŚLĄ")]
+fn test_to_ascii_case(#[case] text: &str, #[case] lower: &str, #[case] upper: &str) {
+    let string = StdImmutableString::new(text).unwrap();
+    let lowercase = string.to_ascii_lowercase().unwrap();
+    let uppercase = string.to_ascii_uppercase().unwrap();
+    assert_eq!(lowercase.as_str(), lower);
+    assert_eq!(uppercase.as_str(), upper);
+    assert_eq!(StdImmutableString::strong_count(&lowercase), 1);
+    assert!(lowercase.eq_ignore_ascii_case(upper));
+}
+
+#[test]
+fn test_borrow_as_str_key() {
+    let mut map = std::collections::HashMap::new();
+    map.insert(new_string(TEXT), 1);
+    map.insert(new_string("foo"), 2);
+
+    assert_eq!(map.get(TEXT), Some(&1));
+    assert_eq!(map.get("foo"), Some(&2));
+    assert_eq!(map.get("bar"), None);
+}
@@ -0,0 +1,21 @@
+#![cfg(all(feature = "std_alloc", feature = "serde"))]
+
+use osom_lib_strings::StdImmutableString;
+use rstest::rstest;
+use serde_test::{Token, assert_de_tokens, assert_de_tokens_error, assert_tokens};
+
+#[rstest]
+#[case("")]
+#[case("Hello, world!")]
+#[case("zażółć gęślą jaźń")]
+fn test_immutable_string_serde(#[case] text: &'static str) {
+    let string = StdImmutableString::new(text).unwrap();
+    assert_tokens(&string, &[Token::Str(text)]);
+    assert_de_tokens(&string, &[Token::BorrowedStr(text)]);
+    assert_de_tokens(&string, &[Token::String(text)]);
+}
+
+#[test]
+fn test_immutable_string_deserialize_wrong_type() {
+    assert_de_tokens_error::<StdImmutableString>(&[Token::I32(5)], "invalid type: integer `5`, expected a string");
+}
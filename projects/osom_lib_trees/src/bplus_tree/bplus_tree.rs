@@ -3,11 +3,12 @@
 use core::ops::{Bound, RangeBounds};
 
 use osom_lib_alloc::Allocator;
-
-use osom_lib_primitives::Length;
+use osom_lib_arrays::{Array, DynamicArray, errors::ArrayConstructionError};
+use osom_lib_primitives::{KeyValuePair, Length};
 
 use crate::{
     bplus_tree::{
+        BPlusTreeCursor, BPlusTreeExtractIf,
         helpers::{self, deallocate_recursive},
         nodes::{LeafItem, LeafItemRange, NodeTaggedPtr},
         operation_results::{BPlusTreeQueryMutResult, BPlusTreeQueryResult},
@@ -77,7 +78,303 @@ where
         self.len
     }
 
-    pub(super) fn search_for_infimum<K>(&self, key: &K) -> LeafItem<NODE_CAPACITY, TKey, TValue>
+    /// Inserts a key-value pair into the [`BPlusTree`]. If the key is already
+    /// present, its value is replaced and the tree's length stays unchanged.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(old_value)` if the key was already present
+    /// * `None` if the key was inserted
+    ///
+    /// # Errors
+    ///
+    /// For details see [`TreeError`].
+    pub fn insert_or_replace(&mut self, key: TKey, value: TValue) -> Result<Option<TValue>, TreeError> {
+        self.internal_insert_or_replace(key, value)
+    }
+
+    /// Returns the height of the [`BPlusTree`], i.e. the number of levels
+    /// from the root down to the leaves. Returns `0` for an empty tree.
+    #[must_use]
+    pub fn height(&self) -> usize {
+        if self.root.is_null() {
+            return 0;
+        }
+
+        let mut height = 1;
+        let mut current_node = &self.root;
+        while !current_node.is_leaf() {
+            let internal_node = unsafe { current_node.as_internal() };
+            current_node = &internal_node.edges()[0];
+            height += 1;
+        }
+        height
+    }
+
+    /// Returns the number of nodes of the [`BPlusTree`], as
+    /// `(internal_nodes, leaf_nodes)`.
+    ///
+    /// # Notes
+    ///
+    /// This traverses the entire tree.
+    #[must_use]
+    pub fn node_count(&self) -> (usize, usize) {
+        helpers::count_nodes(&self.root)
+    }
+
+    /// Returns an iterator over all keys of the [`BPlusTree`], in ascending order.
+    pub fn keys(&self) -> impl Iterator<Item = &TKey> + '_ {
+        let leaf_item_range = self.full_range();
+        BPlusTreeQueryResult::new(leaf_item_range, Ordering::Ascending).map(|pair| pair.into_tuple().0)
+    }
+
+    /// Returns an iterator over mutable references to all values of
+    /// the [`BPlusTree`], in ascending order of their keys.
+    ///
+    /// # Notes
+    ///
+    /// The iterator walks the linked leaves, and every value is visited
+    /// exactly once. Thus the references never alias.
+    pub fn values_mut(&mut self) -> impl Iterator<Item = &mut TValue> + '_ {
+        let leaf_item_range = self.full_range();
+        BPlusTreeQueryMutResult::new(leaf_item_range, Ordering::Ascending).map(|pair| pair.into_tuple().1)
+    }
+
+    /// Clones all key-value pairs of the [`BPlusTree`], in ascending order
+    /// of keys, into a new [`Array`] allocated with the tree's allocator.
+    ///
+    /// # Errors
+    ///
+    /// For details see [`ArrayConstructionError`].
+    pub fn to_sorted_array(&self) -> Result<Array<KeyValuePair<TKey, TValue>, TAllocator>, ArrayConstructionError>
+    where
+        TValue: Clone,
+    {
+        let mut result = DynamicArray::with_capacity_and_allocator(self.len, self.allocator.clone())?;
+        let leaf_item_range = self.full_range();
+        for pair in BPlusTreeQueryResult::new(leaf_item_range, Ordering::Ascending) {
+            let (key, value) = pair.into_tuple();
+            result.push(KeyValuePair::new(key.clone(), value.clone()))?;
+        }
+        debug_assert!(result.len() == self.len, "Traversal produced wrong number of items.");
+        result.into_array()
+    }
+
+    /// Returns an iterator over clones of the key-value pairs of the [`BPlusTree`]
+    /// with key within `range`, in the order specified by `ordering`.
+    ///
+    /// # Notes
+    ///
+    /// Every yielded pair costs a clone of both the key and the value, done
+    /// lazily while iterating. If references are enough, prefer
+    /// [`Tree::query_range`] which does not clone anything.
+    pub fn range_cloned<K: ?Sized>(
+        &self,
+        range: impl RangeBounds<K>,
+        ordering: Ordering,
+    ) -> impl Iterator<Item = (TKey, TValue)> + '_
+    where
+        TKey: Compare<K>,
+        TValue: Clone,
+    {
+        let leaf_item_range = self.search_range(range);
+        BPlusTreeQueryResult::new(leaf_item_range, ordering).map(|pair| {
+            let (key, value) = pair.into_tuple();
+            (key.clone(), value.clone())
+        })
+    }
+
+    /// Tries to deeply clone the [`BPlusTree`]. The new tree has the same
+    /// shape, and its nodes are allocated with a clone of the allocator.
+    ///
+    /// # Errors
+    ///
+    /// For details see [`TreeError`].
+    pub fn try_clone(&self) -> Result<Self, TreeError>
+    where
+        TValue: Clone,
+    {
+        let mut allocator = self.allocator.clone();
+        let mut prev_leaf = core::ptr::null_mut();
+        let root = helpers::clone_recursive(&self.root, &mut allocator, core::ptr::null_mut(), &mut prev_leaf)?;
+        Ok(Self {
+            allocator,
+            root,
+            len: self.len,
+        })
+    }
+
+    /// Creates a read-only snapshot of the current state of the [`BPlusTree`].
+    /// The snapshot is not affected by later modifications of the tree and
+    /// vice versa.
+    ///
+    /// # Notes
+    ///
+    /// Currently this is an eager deep copy, same as [`try_clone`][`Self::try_clone`].
+    /// It costs `O(n)` time and memory, nodes are not shared between
+    /// the tree and the snapshot.
+    ///
+    /// # Errors
+    ///
+    /// For details see [`TreeError`].
+    #[inline(always)]
+    pub fn snapshot(&self) -> Result<Self, TreeError>
+    where
+        TValue: Clone,
+    {
+        self.try_clone()
+    }
+
+    /// Calls `f` on every key-value pair of the [`BPlusTree`] with key
+    /// within `range`, in ascending order of keys. The values are
+    /// updated in place.
+    ///
+    /// # Notes
+    ///
+    /// Keys are passed by shared reference only, since modifying
+    /// them could break the order of the [`BPlusTree`].
+    pub fn update_range<K: ?Sized, F>(&mut self, range: impl RangeBounds<K>, mut f: F)
+    where
+        TKey: Compare<K>,
+        F: FnMut(&TKey, &mut TValue),
+    {
+        let leaf_item_range = self.search_range(range);
+        if leaf_item_range.is_null() {
+            return;
+        }
+
+        let mut current = leaf_item_range.start;
+        loop {
+            let (key, value) = unsafe { (&*current.key_ptr(), &mut *current.value_ptr()) };
+            f(key, value);
+            if current.is_equal(&leaf_item_range.end) {
+                break;
+            }
+            current = current.next();
+        }
+    }
+
+    /// Returns mutable references to the values of all `keys` at once,
+    /// so that several distinct entries can be updated together.
+    ///
+    /// # Returns
+    ///
+    /// An array where the item at position `i` corresponds to `keys[i]` and is:
+    /// * `None` if `keys[i]` is not present in the [`BPlusTree`]
+    /// * `None` if `keys[i]` is equal to `keys[j]` for some `j < i`, i.e.
+    ///   only the first occurrence of a duplicated key gets the reference
+    /// * `Some` with the reference to the value otherwise
+    ///
+    /// # Notes
+    ///
+    /// Duplicates are detected by comparing the found leaf positions, which
+    /// takes `O(M^2)` comparisons on top of `M` lookups. Values are not compared
+    /// by address, since zero-sized values all share the same one.
+    pub fn get_many_mut<K: ?Sized, const M: usize>(&mut self, keys: [&K; M]) -> [Option<&mut TValue>; M]
+    where
+        TKey: Compare<K>,
+    {
+        let mut leaf_items: [LeafItem<NODE_CAPACITY, TKey, TValue>; M] = core::array::from_fn(|_| LeafItem::null());
+        for (index, key) in keys.into_iter().enumerate() {
+            let leaf_item = self.search_for_infimum(key);
+            if leaf_item.is_null() || !unsafe { leaf_item.key() }.is_equal(key) {
+                continue;
+            }
+
+            if !leaf_items[..index].iter().any(|other| other.is_equal(&leaf_item)) {
+                leaf_items[index] = leaf_item;
+            }
+        }
+
+        // All non-null leaf items are distinct, so the references never alias.
+        leaf_items.map(|leaf_item| {
+            if leaf_item.is_null() {
+                None
+            } else {
+                unsafe { leaf_item.value_ptr().as_mut() }
+            }
+        })
+    }
+
+    /// Returns an iterator that removes all key-value pairs for which `f`
+    /// returns `true`, and yields them in ascending order of keys. The values
+    /// can be modified by `f`, regardless of whether they are removed or not.
+    ///
+    /// # Notes
+    ///
+    /// If the iterator is dropped before it is fully consumed, then the remaining
+    /// key-value pairs stay in the [`BPlusTree`], not visited by `f`.
+    pub fn extract_if<F>(&mut self, f: F) -> BPlusTreeExtractIf<'_, TKey, TValue, TAllocator, NODE_CAPACITY, F>
+    where
+        F: FnMut(&TKey, &mut TValue) -> bool,
+    {
+        let start = self.full_range().start;
+        BPlusTreeExtractIf::new(self, start, f)
+    }
+
+    /// Removes all key-value pairs of the [`BPlusTree`] with key within `range`.
+    ///
+    /// # Returns
+    ///
+    /// The number of removed key-value pairs.
+    ///
+    /// # Notes
+    ///
+    /// This removes the pairs one by one, which costs `O(m)` removals for `m`
+    /// pairs in the range. A faster approach would detach entire leaves within
+    /// the range at once, and rebalance only the boundary nodes.
+    ///
+    /// Removal never allocates, and so unlike insertion it cannot fail.
+    pub fn remove_range<K: ?Sized>(&mut self, range: impl RangeBounds<K>) -> usize
+    where
+        TKey: Compare<K>,
+    {
+        let leaf_item_range = self.search_range(range);
+        if leaf_item_range.is_null() {
+            return 0;
+        }
+
+        let mut count = 1;
+        let mut current = leaf_item_range.start.clone();
+        while !current.is_equal(&leaf_item_range.end) {
+            current = current.next();
+            count += 1;
+        }
+
+        let mut current = leaf_item_range.start;
+        for _ in 0..count {
+            // After the removal `current` points to the following item.
+            drop(unsafe { self.remove_leaf_item(&mut current) });
+        }
+        count
+    }
+
+    /// Returns a [`BPlusTreeCursor`] pointing at the first key-value pair
+    /// with key greater or equal to `key`. If there is no such pair, then
+    /// the cursor points at the ghost position past the last pair.
+    pub fn cursor_at<K: ?Sized>(&self, key: &K) -> BPlusTreeCursor<'_, TKey, TValue, TAllocator, NODE_CAPACITY>
+    where
+        TKey: Compare<K>,
+    {
+        BPlusTreeCursor::new(self, self.search_for_infimum(key))
+    }
+
+    pub(super) fn full_range(&self) -> LeafItemRange<NODE_CAPACITY, TKey, TValue> {
+        if self.root.is_null() {
+            return LeafItemRange::null();
+        }
+
+        unsafe {
+            LeafItemRange {
+                start: self.min(),
+                end: self.max(),
+            }
+        }
+    }
+
+    /// Returns the first item with key greater or equal to `key`,
+    /// or null if there is no such item.
+    pub(super) fn search_for_infimum<K: ?Sized>(&self, key: &K) -> LeafItem<NODE_CAPACITY, TKey, TValue>
     where
         TKey: Compare<K>,
     {
@@ -92,11 +389,46 @@ where
             current_node = &internal_node.edges()[index as usize];
         }
 
-        let leaf = unsafe { current_node.as_leaf_mut() };
+        let leaf = unsafe { current_node.as_leaf() };
         let index = helpers::scan_node(key, leaf.data().keys());
-        LeafItem {
-            node: core::ptr::from_mut(leaf),
-            index,
+        if index < leaf.data().keys().len().value() {
+            return LeafItem {
+                node: current_node.as_leaf_ptr(),
+                index,
+            };
+        }
+
+        // The key is greater than all keys in this leaf, so the infimum
+        // (if any) is the first item of the next leaf.
+        let next = leaf.get_next();
+        if next.is_null() {
+            LeafItem::null()
+        } else {
+            LeafItem { node: next, index: 0 }
+        }
+    }
+
+    /// Returns the first item with key strictly greater than `key`,
+    /// or null if there is no such item.
+    fn search_for_supremum<K: ?Sized>(&self, key: &K) -> LeafItem<NODE_CAPACITY, TKey, TValue>
+    where
+        TKey: Compare<K>,
+    {
+        let leaf_item = self.search_for_infimum(key);
+        if !leaf_item.is_null() && unsafe { leaf_item.key().is_equal(key) } {
+            leaf_item.next()
+        } else {
+            leaf_item
+        }
+    }
+
+    /// Returns the item directly before `leaf_item`. If `leaf_item` is null,
+    /// i.e. it points past the end of the tree, then returns the last item.
+    fn prev_or_max(&self, leaf_item: &LeafItem<NODE_CAPACITY, TKey, TValue>) -> LeafItem<NODE_CAPACITY, TKey, TValue> {
+        if leaf_item.is_null() {
+            unsafe { self.max() }
+        } else {
+            leaf_item.prev()
         }
     }
 
@@ -110,9 +442,8 @@ where
             current_node = &internal_node.edges()[0];
         }
 
-        let leaf = unsafe { current_node.as_leaf_mut() };
         LeafItem {
-            node: core::ptr::from_mut(leaf),
+            node: current_node.as_leaf_ptr(),
             index: 0,
         }
     }
@@ -124,18 +455,21 @@ where
         let mut current_node = &self.root;
         while !current_node.is_leaf() {
             let internal_node = unsafe { current_node.as_internal() };
-            let index = internal_node.data().keys().len() - 1;
-            current_node = &internal_node.edges().as_slice()[index.value() as usize];
+            let edges = internal_node.edges().as_slice();
+            current_node = &edges[edges.len() - 1];
         }
 
-        let leaf = unsafe { current_node.as_leaf_mut() };
+        let leaf = unsafe { current_node.as_leaf() };
         LeafItem {
-            node: core::ptr::from_mut(leaf),
+            node: current_node.as_leaf_ptr(),
             index: leaf.data().keys().len().value() - 1,
         }
     }
 
-    pub(super) fn search_range<K>(&self, range: impl RangeBounds<K>) -> LeafItemRange<NODE_CAPACITY, TKey, TValue>
+    pub(super) fn search_range<K: ?Sized>(
+        &self,
+        range: impl RangeBounds<K>,
+    ) -> LeafItemRange<NODE_CAPACITY, TKey, TValue>
     where
         TKey: Compare<K>,
     {
@@ -144,32 +478,20 @@ where
         }
 
         let start = match range.start_bound() {
-            Bound::Included(key) => {
-                let leaf_item = self.search_for_infimum(key);
-                if unsafe { leaf_item.key().is_equal(key) } {
-                    leaf_item
-                } else {
-                    leaf_item.next()
-                }
-            }
-            Bound::Excluded(key) => {
-                let leaf_item = self.search_for_infimum(key);
-                leaf_item.next()
-            }
+            Bound::Included(key) => self.search_for_infimum(key),
+            Bound::Excluded(key) => self.search_for_supremum(key),
             Bound::Unbounded => unsafe { self.min() },
         };
         let end = match range.end_bound() {
-            Bound::Included(key) => self.search_for_infimum(key),
-            Bound::Excluded(key) => {
-                let leaf_item = self.search_for_infimum(key);
-                if unsafe { leaf_item.key().is_equal(key) } {
-                    leaf_item.prev()
-                } else {
-                    leaf_item
-                }
-            }
+            Bound::Included(key) => self.prev_or_max(&self.search_for_supremum(key)),
+            Bound::Excluded(key) => self.prev_or_max(&self.search_for_infimum(key)),
             Bound::Unbounded => unsafe { self.max() },
         };
+
+        if start.is_null() || end.is_null() || unsafe { start.key() > end.key() } {
+            return LeafItemRange::null();
+        }
+
         LeafItemRange { start, end }
     }
 }
@@ -185,6 +507,48 @@ where
     }
 }
 
+impl<TKey, TValue, TAllocator, const NODE_CAPACITY: usize> Clone for BPlusTree<TKey, TValue, TAllocator, NODE_CAPACITY>
+where
+    TKey: Clone + Ord,
+    TValue: Clone,
+    TAllocator: Allocator,
+{
+    fn clone(&self) -> Self {
+        self.try_clone().expect("Failed to clone the tree")
+    }
+}
+
+/// Two [`BPlusTree`]s are equal if they hold the same key-value pairs,
+/// regardless of their allocators, node capacities and internal shapes.
+impl<TKey, TValue, TAllocator1, TAllocator2, const NODE_CAPACITY1: usize, const NODE_CAPACITY2: usize>
+    PartialEq<BPlusTree<TKey, TValue, TAllocator1, NODE_CAPACITY1>>
+    for BPlusTree<TKey, TValue, TAllocator2, NODE_CAPACITY2>
+where
+    TKey: Clone + Ord,
+    TValue: PartialEq,
+    TAllocator1: Allocator,
+    TAllocator2: Allocator,
+{
+    fn eq(&self, other: &BPlusTree<TKey, TValue, TAllocator1, NODE_CAPACITY1>) -> bool {
+        if self.len != other.len {
+            return false;
+        }
+
+        let left = BPlusTreeQueryResult::new(self.full_range(), Ordering::Ascending);
+        let right = BPlusTreeQueryResult::new(other.full_range(), Ordering::Ascending);
+        left.map(KeyValuePair::into_tuple)
+            .eq(right.map(KeyValuePair::into_tuple))
+    }
+}
+
+impl<TKey, TValue, TAllocator, const NODE_CAPACITY: usize> Eq for BPlusTree<TKey, TValue, TAllocator, NODE_CAPACITY>
+where
+    TKey: Clone + Ord,
+    TValue: Eq,
+    TAllocator: Allocator,
+{
+}
+
 impl<TKey, TValue, TAllocator, const NODE_CAPACITY: usize> Drop for BPlusTree<TKey, TValue, TAllocator, NODE_CAPACITY>
 where
     TKey: Clone + Ord,
@@ -208,11 +572,14 @@ where
         self.internal_try_insert(key, value)
     }
 
-    fn query_exact<K>(&self, key: &K) -> TreeQueryExactResult<'_, Self::TKey, Self::TValue>
+    fn query_exact<K: ?Sized>(&self, key: &K) -> TreeQueryExactResult<'_, Self::TKey, Self::TValue>
     where
         Self::TKey: Compare<K>,
     {
         let leaf_item = self.search_for_infimum(key);
+        if leaf_item.is_null() {
+            return TreeQueryExactResult::NotFound;
+        }
         let leaf_key = unsafe { &*leaf_item.key_ptr() };
         if leaf_key.is_equal(key) {
             let leaf_value = unsafe { &*leaf_item.value_ptr() };
@@ -225,11 +592,14 @@ where
         }
     }
 
-    fn query_exact_mut<K>(&mut self, key: &K) -> TreeQueryExactMutResult<'_, Self::TKey, Self::TValue>
+    fn query_exact_mut<K: ?Sized>(&mut self, key: &K) -> TreeQueryExactMutResult<'_, Self::TKey, Self::TValue>
     where
         Self::TKey: Compare<K>,
     {
         let leaf_item = self.search_for_infimum(key);
+        if leaf_item.is_null() {
+            return TreeQueryExactMutResult::NotFound;
+        }
         let leaf_key = unsafe { &*leaf_item.key_ptr() };
         if leaf_key.is_equal(key) {
             let leaf_value = unsafe { &mut *leaf_item.value_ptr() };
@@ -242,7 +612,7 @@ where
         }
     }
 
-    fn query_range<K>(
+    fn query_range<K: ?Sized>(
         &self,
         range: impl RangeBounds<K>,
         ordering: Ordering,
@@ -254,7 +624,7 @@ where
         BPlusTreeQueryResult::new(leaf_item_range, ordering)
     }
 
-    fn query_range_mut<K>(
+    fn query_range_mut<K: ?Sized>(
         &mut self,
         range: impl RangeBounds<K>,
         ordering: Ordering,
@@ -0,0 +1,101 @@
+use osom_lib_alloc::Allocator;
+
+use super::BPlusTree;
+use super::nodes::LeafItem;
+
+/// A read-only cursor over a [`BPlusTree`], that can move in both directions.
+/// See [`BPlusTree::cursor_at`].
+///
+/// # Notes
+///
+/// Apart from pointing at key-value pairs, the cursor can point at a "ghost"
+/// position placed past the last and before the first pair. In that position
+/// both [`key`][`Self::key`] and [`value`][`Self::value`] return `None`. Moving
+/// forward from the ghost goes to the first pair, moving backward goes to
+/// the last one.
+#[must_use]
+pub struct BPlusTreeCursor<'a, TKey, TValue, TAllocator, const NODE_CAPACITY: usize>
+where
+    TKey: Clone + Ord,
+    TAllocator: Allocator,
+{
+    tree: &'a BPlusTree<TKey, TValue, TAllocator, NODE_CAPACITY>,
+    current: LeafItem<NODE_CAPACITY, TKey, TValue>,
+}
+
+impl<'a, TKey, TValue, TAllocator, const NODE_CAPACITY: usize>
+    BPlusTreeCursor<'a, TKey, TValue, TAllocator, NODE_CAPACITY>
+where
+    TKey: Clone + Ord,
+    TAllocator: Allocator,
+{
+    /// Creates a new [`BPlusTreeCursor`] pointing at `current`, which has
+    /// to be either null (the ghost position) or an item of `tree`.
+    #[inline(always)]
+    pub(super) const fn new(
+        tree: &'a BPlusTree<TKey, TValue, TAllocator, NODE_CAPACITY>,
+        current: LeafItem<NODE_CAPACITY, TKey, TValue>,
+    ) -> Self {
+        Self { tree, current }
+    }
+
+    /// Returns the key the [`BPlusTreeCursor`] points at,
+    /// or `None` if it is at the ghost position.
+    #[inline(always)]
+    #[must_use]
+    pub fn key(&self) -> Option<&'a TKey> {
+        if self.current.is_null() {
+            None
+        } else {
+            Some(unsafe { &*self.current.key_ptr() })
+        }
+    }
+
+    /// Returns the value the [`BPlusTreeCursor`] points at,
+    /// or `None` if it is at the ghost position.
+    #[inline(always)]
+    #[must_use]
+    pub fn value(&self) -> Option<&'a TValue> {
+        if self.current.is_null() {
+            None
+        } else {
+            Some(unsafe { &*self.current.value_ptr() })
+        }
+    }
+
+    /// Moves the [`BPlusTreeCursor`] to the next key-value pair in
+    /// ascending order of keys. Moving past the last pair lands on
+    /// the ghost position, moving from the ghost lands on the first pair.
+    pub fn move_next(&mut self) {
+        self.current = if self.current.is_null() {
+            self.tree.full_range().start
+        } else {
+            self.current.next()
+        };
+    }
+
+    /// Moves the [`BPlusTreeCursor`] to the previous key-value pair in
+    /// ascending order of keys. Moving before the first pair lands on
+    /// the ghost position, moving from the ghost lands on the last pair.
+    pub fn move_prev(&mut self) {
+        self.current = if self.current.is_null() {
+            self.tree.full_range().end
+        } else {
+            self.current.prev()
+        };
+    }
+}
+
+impl<TKey, TValue, TAllocator, const NODE_CAPACITY: usize> Clone
+    for BPlusTreeCursor<'_, TKey, TValue, TAllocator, NODE_CAPACITY>
+where
+    TKey: Clone + Ord,
+    TAllocator: Allocator,
+{
+    fn clone(&self) -> Self {
+        Self {
+            tree: self.tree,
+            current: self.current.clone(),
+        }
+    }
+}
@@ -0,0 +1,77 @@
+use osom_lib_alloc::Allocator;
+
+use super::BPlusTree;
+use super::nodes::LeafItem;
+
+/// An iterator that removes the key-value pairs of a [`BPlusTree`] matching
+/// a predicate, and yields them in ascending order of keys.
+/// See [`BPlusTree::extract_if`].
+///
+/// # Notes
+///
+/// The pairs are removed lazily, while iterating. Dropping a partially
+/// consumed iterator keeps all the not yet visited pairs in the tree.
+#[must_use]
+pub struct BPlusTreeExtractIf<'a, TKey, TValue, TAllocator, const NODE_CAPACITY: usize, F>
+where
+    TKey: Clone + Ord,
+    TAllocator: Allocator,
+    F: FnMut(&TKey, &mut TValue) -> bool,
+{
+    tree: &'a mut BPlusTree<TKey, TValue, TAllocator, NODE_CAPACITY>,
+    current: LeafItem<NODE_CAPACITY, TKey, TValue>,
+    predicate: F,
+}
+
+impl<'a, TKey, TValue, TAllocator, const NODE_CAPACITY: usize, F>
+    BPlusTreeExtractIf<'a, TKey, TValue, TAllocator, NODE_CAPACITY, F>
+where
+    TKey: Clone + Ord,
+    TAllocator: Allocator,
+    F: FnMut(&TKey, &mut TValue) -> bool,
+{
+    /// Creates a new [`BPlusTreeExtractIf`] starting at `start`, which has
+    /// to be either null or the first item of `tree`.
+    #[inline(always)]
+    pub(super) fn new(
+        tree: &'a mut BPlusTree<TKey, TValue, TAllocator, NODE_CAPACITY>,
+        start: LeafItem<NODE_CAPACITY, TKey, TValue>,
+        predicate: F,
+    ) -> Self {
+        Self {
+            tree,
+            current: start,
+            predicate,
+        }
+    }
+}
+
+impl<TKey, TValue, TAllocator, const NODE_CAPACITY: usize, F> Iterator
+    for BPlusTreeExtractIf<'_, TKey, TValue, TAllocator, NODE_CAPACITY, F>
+where
+    TKey: Clone + Ord,
+    TAllocator: Allocator,
+    F: FnMut(&TKey, &mut TValue) -> bool,
+{
+    type Item = (TKey, TValue);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while !self.current.is_null() {
+            let (key, value) = unsafe { (&*self.current.key_ptr(), &mut *self.current.value_ptr()) };
+            if !(self.predicate)(key, value) {
+                self.current = self.current.next();
+                continue;
+            }
+
+            // After the removal `self.current` points to the following item.
+            let pair = unsafe { self.tree.remove_leaf_item(&mut self.current) };
+            return Some(pair);
+        }
+
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(self.tree.len().into()))
+    }
+}
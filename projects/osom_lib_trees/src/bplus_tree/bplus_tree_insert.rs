@@ -1,11 +1,12 @@
 #![allow(clippy::cast_sign_loss, clippy::cast_possible_truncation, clippy::cast_possible_wrap)]
 
 use osom_lib_alloc::Allocator;
+use osom_lib_arrays::FixedArray;
 use osom_lib_primitives::Length;
 
 use crate::traits::{Compare, TreeError, TreeTryInsertResult};
 
-use super::nodes::{LeafNode, NodeTaggedPtr};
+use super::nodes::{InternalNode, LeafNode, NodeData, NodeTaggedPtr};
 use super::{BPlusTree, helpers};
 
 impl<TKey, TValue, TAllocator, const NODE_CAPACITY: usize> BPlusTree<TKey, TValue, TAllocator, NODE_CAPACITY>
@@ -15,54 +16,269 @@ where
 {
     pub(super) fn internal_try_insert(&mut self, key: TKey, value: TValue) -> Result<TreeTryInsertResult, TreeError> {
         if self.root.is_null() {
-            let mut leaf = LeafNode::new();
-            leaf.data_mut().keys_mut().push(key).unwrap();
-            leaf.values_mut().push(value).unwrap();
-            self.root = NodeTaggedPtr::box_leaf(&mut self.allocator, leaf)?;
-            self.len = Length::ONE;
+            self.insert_into_empty(key, value)?;
             return Ok(TreeTryInsertResult::Inserted);
         }
 
-        let mut current_node = &mut self.root;
-        while !current_node.is_leaf() {
-            let internal_node = unsafe { current_node.as_internal_mut() };
-            let index = helpers::scan_node(&key, internal_node.data().keys());
-            current_node = &mut internal_node.edges_mut()[index as usize];
+        let (leaf, existing_index) = self.search_for_slot(&key);
+        if existing_index.is_some() {
+            return Ok(TreeTryInsertResult::AlreadyExists);
         }
 
-        let leaf = unsafe { current_node.as_leaf_mut() };
+        unsafe { self.insert_into_leaf(leaf, key, value)? };
+        Ok(TreeTryInsertResult::Inserted)
+    }
 
-        let index = helpers::scan_node(&key, leaf.data().keys());
-        {
-            if index < leaf.data().keys().len().value() {
-                let leaf_key = &leaf.data().keys()[index as usize];
-                if leaf_key.is_equal(&key) {
-                    return Ok(TreeTryInsertResult::AlreadyExists);
-                }
-            }
+    pub(super) fn internal_insert_or_replace(&mut self, key: TKey, value: TValue) -> Result<Option<TValue>, TreeError> {
+        if self.root.is_null() {
+            self.insert_into_empty(key, value)?;
+            return Ok(None);
+        }
 
-            if index > 0 {
-                let leaf_key = &leaf.data().keys()[(index - 1) as usize];
-                if leaf_key.is_equal(&key) {
-                    return Ok(TreeTryInsertResult::AlreadyExists);
-                }
-            }
+        let (leaf, existing_index) = self.search_for_slot(&key);
+        if let Some(index) = existing_index {
+            let leaf = unsafe { &mut *leaf };
+            let old_value = core::mem::replace(&mut leaf.values_mut()[index], value);
+            return Ok(Some(old_value));
+        }
+
+        unsafe { self.insert_into_leaf(leaf, key, value)? };
+        Ok(None)
+    }
+
+    fn insert_into_empty(&mut self, key: TKey, value: TValue) -> Result<(), TreeError> {
+        debug_assert!(self.root.is_null(), "The tree is not empty.");
+        let mut leaf = LeafNode::new();
+        leaf.data_mut().keys_mut().push(key).unwrap();
+        leaf.values_mut().push(value).unwrap();
+        self.root = NodeTaggedPtr::box_leaf(&mut self.allocator, leaf)?;
+        self.len = Length::ONE;
+        Ok(())
+    }
+
+    /// Descends a non-empty tree to the leaf where `key` belongs. Returns
+    /// that leaf, together with the index of `key` in it if the key
+    /// is already present.
+    fn search_for_slot(&self, key: &TKey) -> (*mut LeafNode<NODE_CAPACITY, TKey, TValue>, Option<usize>) {
+        debug_assert!(!self.root.is_null(), "The tree is empty.");
+        let mut current_node = &self.root;
+        while !current_node.is_leaf() {
+            let internal_node = unsafe { current_node.as_internal() };
+            let index = helpers::scan_node(key, internal_node.data().keys());
+            current_node = &internal_node.edges()[index as usize];
         }
 
+        let leaf = unsafe { current_node.as_leaf() };
+        let keys = leaf.data().keys();
+        let index = helpers::scan_node(key, keys) as usize;
+        let existing_index = if index < keys.len().value() as usize && keys[index].is_equal(key) {
+            Some(index)
+        } else {
+            None
+        };
+
+        (current_node.as_leaf_ptr(), existing_index)
+    }
+
+    /// Inserts a new key into `leaf`, keeping the keys ordered. If `leaf`
+    /// is full, then it is split, and the split is propagated up the tree.
+    ///
+    /// # Safety
+    ///
+    /// `leaf` has to be the result of [`Self::search_for_slot`] for `key`,
+    /// and `key` must not be present in the tree.
+    unsafe fn insert_into_leaf(
+        &mut self,
+        leaf: *mut LeafNode<NODE_CAPACITY, TKey, TValue>,
+        key: TKey,
+        value: TValue,
+    ) -> Result<(), TreeError> {
         if self.len.value() as usize >= Self::MAX_SIZE {
             return Err(TreeError::TreeTooBig);
         }
+
+        if unsafe { &*leaf }.data().keys().len().value() < NODE_CAPACITY as i32 {
+            Self::insert_ordered(unsafe { &mut *leaf }, key, value);
+        } else {
+            unsafe { self.split_leaf_and_insert(leaf, key, value)? };
+        }
+
         self.len.add(1).unwrap();
+        Ok(())
+    }
+
+    fn insert_ordered(leaf: &mut LeafNode<NODE_CAPACITY, TKey, TValue>, key: TKey, value: TValue) {
+        leaf.data_mut().keys_mut().push(key).unwrap();
+        leaf.values_mut().push(value).unwrap();
+        let index = helpers::move_last_into_order(leaf.data_mut().keys_mut());
+        helpers::move_last_into_position(leaf.values_mut(), index);
+    }
+
+    /// Splits the full `leaf` in halves, inserts the key into the matching
+    /// half, and inserts the new right half into the parent.
+    ///
+    /// # Notes
+    ///
+    /// All the nodes needed for the split are allocated upfront, and so on
+    /// allocation failure the tree is left untouched.
+    ///
+    /// # Safety
+    ///
+    /// `leaf_ptr` has to point to a full leaf of this tree.
+    unsafe fn split_leaf_and_insert(
+        &mut self,
+        leaf_ptr: *mut LeafNode<NODE_CAPACITY, TKey, TValue>,
+        key: TKey,
+        value: TValue,
+    ) -> Result<(), TreeError> {
+        let leaf = unsafe { &mut *leaf_ptr };
+        let mut new_nodes = self.allocate_split_nodes(leaf.data().get_parent())?;
+        let right_ptr = new_nodes.pop().unwrap();
+        let right_raw = right_ptr.as_leaf_ptr();
+        let right = unsafe { &mut *right_raw };
 
-        if leaf.data().keys().len().value() < NODE_CAPACITY as i32 {
-            leaf.data_mut().keys_mut().push(key).unwrap();
-            leaf.values_mut().push(value).unwrap();
-            let index = helpers::move_last_into_order(leaf.data_mut().keys_mut());
-            helpers::move_last_into_position(leaf.values_mut(), index);
+        let moved_count = NODE_CAPACITY - NODE_CAPACITY / 2;
+        helpers::move_tail(leaf.data_mut().keys_mut(), right.data_mut().keys_mut(), moved_count);
+        helpers::move_tail(leaf.values_mut(), right.values_mut(), moved_count);
+
+        let next = leaf.get_next();
+        if !next.is_null() {
+            unsafe { (*next).set_prev(right_raw) };
+        }
+        right.set_next(next);
+        right.set_prev(leaf_ptr);
+        leaf.set_next(right_raw);
+
+        if key < right.data().keys()[0] {
+            Self::insert_ordered(leaf, key, value);
         } else {
-            todo!("Implement leaf node split")
+            Self::insert_ordered(right, key, value);
         }
 
-        Ok(TreeTryInsertResult::Inserted)
+        // All keys of `leaf` are less or equal to its last key, and all keys
+        // of `right` are greater, which is exactly what the separator needs.
+        let keys = leaf.data().keys();
+        let separator = keys[keys.len().value() as usize - 1].clone();
+        unsafe { self.insert_into_parent(leaf_ptr.cast(), separator, right_ptr, &mut new_nodes) };
+        debug_assert!(new_nodes.is_empty(), "Not all preallocated nodes were used.");
+        Ok(())
+    }
+
+    /// Allocates the nodes needed to split a full leaf with `parent` as
+    /// the parent: the new leaf, a new internal node for each full ancestor
+    /// and a new root if all ancestors are full. The leaf is the last item.
+    fn allocate_split_nodes(
+        &mut self,
+        parent: *mut InternalNode<NODE_CAPACITY, TKey, TValue>,
+    ) -> Result<FixedArray<NodeTaggedPtr<NODE_CAPACITY, TKey, TValue>, MAX_HEIGHT>, TreeError> {
+        let mut internal_count = 0;
+        let mut current = parent;
+        while !current.is_null() {
+            let internal = unsafe { &*current };
+            if (internal.data().keys().len().value() as usize) < NODE_CAPACITY {
+                break;
+            }
+            internal_count += 1;
+            current = internal.data().get_parent();
+        }
+        if current.is_null() {
+            // The split reaches the root, so a new root is needed.
+            internal_count += 1;
+        }
+
+        let mut new_nodes = FixedArray::new();
+        let result = (|| {
+            for _ in 0..internal_count {
+                new_nodes
+                    .push(NodeTaggedPtr::box_internal(&mut self.allocator, InternalNode::new())?)
+                    .unwrap();
+            }
+            new_nodes
+                .push(NodeTaggedPtr::box_leaf(&mut self.allocator, LeafNode::new())?)
+                .unwrap();
+            Ok(())
+        })();
+
+        if let Err(error) = result {
+            while let Some(mut node) = new_nodes.pop() {
+                helpers::deallocate_recursive(&mut node, &mut self.allocator);
+            }
+            return Err(error);
+        }
+        Ok(new_nodes)
+    }
+
+    /// Inserts `separator` and `right` into the parent of `left`, right after
+    /// `left`. Splits the parent if needed, and creates a new root when
+    /// `left` is the root.
+    ///
+    /// # Safety
+    ///
+    /// `left` has to point to the [`NodeData`] of a node of this tree, `right`
+    /// has to be its new right sibling, and `new_nodes` has to contain enough
+    /// internal nodes for the split.
+    unsafe fn insert_into_parent(
+        &mut self,
+        left: *mut NodeData<NODE_CAPACITY, TKey, TValue>,
+        separator: TKey,
+        mut right: NodeTaggedPtr<NODE_CAPACITY, TKey, TValue>,
+        new_nodes: &mut FixedArray<NodeTaggedPtr<NODE_CAPACITY, TKey, TValue>, MAX_HEIGHT>,
+    ) {
+        let parent_ptr = unsafe { &*left }.get_parent();
+        if parent_ptr.is_null() {
+            let root_ptr = new_nodes.pop().unwrap();
+            let root_raw = root_ptr.as_internal_ptr();
+            unsafe { (*left).set_parent(root_raw) };
+            right.node_data_mut().set_parent(root_raw);
+            let root = unsafe { &mut *root_raw };
+            root.data_mut().keys_mut().push(separator).unwrap();
+            let old_root = core::mem::replace(&mut self.root, root_ptr.clone());
+            root.edges_mut().push(old_root).unwrap();
+            root.edges_mut().push(right).unwrap();
+            return;
+        }
+
+        let parent = unsafe { &mut *parent_ptr };
+        let position = helpers::child_position(parent, left);
+        if (parent.data().keys().len().value() as usize) < NODE_CAPACITY {
+            right.node_data_mut().set_parent(parent_ptr);
+            helpers::insert_at_position(parent.data_mut().keys_mut(), position, separator);
+            helpers::insert_at_position(parent.edges_mut(), position + 1, right);
+            return;
+        }
+
+        // The parent keeps keys `[0, mid)` and edges `[0, mid]`, the key at `mid`
+        // goes up, and the sibling takes the rest.
+        let mid = NODE_CAPACITY / 2;
+        let sibling_ptr = new_nodes.pop().unwrap();
+        let sibling_raw = sibling_ptr.as_internal_ptr();
+        let sibling = unsafe { &mut *sibling_raw };
+        helpers::move_tail(
+            parent.data_mut().keys_mut(),
+            sibling.data_mut().keys_mut(),
+            NODE_CAPACITY - mid - 1,
+        );
+        helpers::move_tail(parent.edges_mut(), sibling.edges_mut(), NODE_CAPACITY - mid);
+        let pushed_up = parent.data_mut().keys_mut().pop().unwrap();
+        for edge in sibling.edges_mut().iter_mut() {
+            edge.node_data_mut().set_parent(sibling_raw);
+        }
+
+        let (target, target_position) = if position <= mid {
+            (parent_ptr, position)
+        } else {
+            (sibling_raw, position - mid - 1)
+        };
+        right.node_data_mut().set_parent(target);
+        let target = unsafe { &mut *target };
+        helpers::insert_at_position(target.data_mut().keys_mut(), target_position, separator);
+        helpers::insert_at_position(target.edges_mut(), target_position + 1, right);
+
+        unsafe { self.insert_into_parent(parent_ptr.cast(), pushed_up, sibling_ptr, new_nodes) };
     }
 }
+
+/// Upper bound of the height of any [`BPlusTree`]. Every node has at least
+/// two children (leaves at least two items), and so a tree of height `h`
+/// has at least `2^h` items, while the length is limited to `i32::MAX`.
+const MAX_HEIGHT: usize = 32;
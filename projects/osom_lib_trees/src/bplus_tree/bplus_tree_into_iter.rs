@@ -0,0 +1,156 @@
+#![allow(clippy::cast_sign_loss)]
+
+use core::mem::ManuallyDrop;
+use core::ptr::NonNull;
+
+use osom_lib_alloc::Allocator;
+use osom_lib_arrays::FixedArray;
+
+use super::BPlusTree;
+use super::helpers::deallocate_internal_recursive;
+use super::nodes::{LeafNode, NodeTaggedPtr};
+
+/// An owning iterator over the key-value pairs of a [`BPlusTree`],
+/// in ascending order of keys.
+///
+/// # Notes
+///
+/// Leaves are deallocated as soon as they are exhausted. Dropping
+/// a partially consumed iterator drops all the remaining pairs and
+/// deallocates all the remaining leaves.
+#[must_use]
+pub struct BPlusTreeIntoIter<TKey, TValue, TAllocator, const NODE_CAPACITY: usize>
+where
+    TAllocator: Allocator,
+{
+    allocator: TAllocator,
+    next_leaf: *mut LeafNode<NODE_CAPACITY, TKey, TValue>,
+    keys: ManuallyDrop<FixedArray<TKey, NODE_CAPACITY>>,
+    values: ManuallyDrop<FixedArray<TValue, NODE_CAPACITY>>,
+    index: usize,
+    remaining: usize,
+}
+
+impl<TKey, TValue, TAllocator, const NODE_CAPACITY: usize> BPlusTreeIntoIter<TKey, TValue, TAllocator, NODE_CAPACITY>
+where
+    TAllocator: Allocator,
+{
+    /// Moves the content of the next leaf into the iterator and deallocates the leaf.
+    /// Returns `false` if there are no more leaves.
+    fn load_next_leaf(&mut self) -> bool {
+        let leaf = self.next_leaf;
+        if leaf.is_null() {
+            return false;
+        }
+
+        let leaf_ref = unsafe { &mut *leaf };
+        self.next_leaf = leaf_ref.get_next();
+        self.keys = ManuallyDrop::new(core::mem::take(leaf_ref.data_mut().keys_mut()));
+        self.values = ManuallyDrop::new(core::mem::take(leaf_ref.values_mut()));
+        self.index = 0;
+
+        unsafe {
+            core::ptr::drop_in_place(leaf);
+            self.allocator.deallocate_for_type(NonNull::new_unchecked(leaf));
+        }
+        true
+    }
+}
+
+impl<TKey, TValue, TAllocator, const NODE_CAPACITY: usize> Iterator
+    for BPlusTreeIntoIter<TKey, TValue, TAllocator, NODE_CAPACITY>
+where
+    TAllocator: Allocator,
+{
+    type Item = (TKey, TValue);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.index >= self.keys.len().value() as usize {
+            if !self.load_next_leaf() {
+                return None;
+            }
+        }
+
+        let index = self.index;
+        self.index += 1;
+        self.remaining -= 1;
+        unsafe {
+            let key = core::ptr::read(self.keys.as_slice().as_ptr().add(index));
+            let value = core::ptr::read(self.values.as_slice().as_ptr().add(index));
+            Some((key, value))
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<TKey, TValue, TAllocator, const NODE_CAPACITY: usize> ExactSizeIterator
+    for BPlusTreeIntoIter<TKey, TValue, TAllocator, NODE_CAPACITY>
+where
+    TAllocator: Allocator,
+{
+}
+
+impl<TKey, TValue, TAllocator, const NODE_CAPACITY: usize> Drop
+    for BPlusTreeIntoIter<TKey, TValue, TAllocator, NODE_CAPACITY>
+where
+    TAllocator: Allocator,
+{
+    fn drop(&mut self) {
+        loop {
+            // Only items at `self.index` and beyond were not moved out yet.
+            unsafe {
+                let keys: *mut [TKey] = &raw mut self.keys.as_mut_slice()[self.index..];
+                let values: *mut [TValue] = &raw mut self.values.as_mut_slice()[self.index..];
+                core::ptr::drop_in_place(keys);
+                core::ptr::drop_in_place(values);
+            }
+            self.keys = ManuallyDrop::new(FixedArray::new());
+            self.values = ManuallyDrop::new(FixedArray::new());
+            self.index = 0;
+
+            if !self.load_next_leaf() {
+                break;
+            }
+        }
+    }
+}
+
+impl<TKey, TValue, TAllocator, const NODE_CAPACITY: usize> IntoIterator
+    for BPlusTree<TKey, TValue, TAllocator, NODE_CAPACITY>
+where
+    TKey: Clone + Ord,
+    TAllocator: Allocator,
+{
+    type Item = (TKey, TValue);
+
+    type IntoIter = BPlusTreeIntoIter<TKey, TValue, TAllocator, NODE_CAPACITY>;
+
+    fn into_iter(mut self) -> Self::IntoIter {
+        let mut root = core::mem::replace(&mut self.root, NodeTaggedPtr::null());
+        let mut allocator = self.allocator.clone();
+        let remaining = self.len.value() as usize;
+
+        let mut first_leaf: *mut LeafNode<NODE_CAPACITY, TKey, TValue> = core::ptr::null_mut();
+        if !root.is_null() {
+            let mut current_node = root.clone();
+            while !current_node.is_leaf() {
+                let internal_node = unsafe { current_node.as_internal() };
+                current_node = internal_node.edges()[0].clone();
+            }
+            first_leaf = current_node.as_leaf_ptr();
+        }
+        deallocate_internal_recursive(&mut root, &mut allocator);
+
+        BPlusTreeIntoIter {
+            allocator,
+            next_leaf: first_leaf,
+            keys: ManuallyDrop::new(FixedArray::new()),
+            values: ManuallyDrop::new(FixedArray::new()),
+            index: 0,
+            remaining,
+        }
+    }
+}
@@ -0,0 +1,245 @@
+#![allow(clippy::cast_sign_loss, clippy::cast_possible_truncation, clippy::cast_possible_wrap)]
+
+use osom_lib_alloc::Allocator;
+
+use super::nodes::{InternalNode, LeafItem, LeafNode, NodeTaggedPtr};
+use super::{BPlusTree, helpers};
+
+impl<TKey, TValue, TAllocator, const NODE_CAPACITY: usize> BPlusTree<TKey, TValue, TAllocator, NODE_CAPACITY>
+where
+    TKey: Clone + Ord,
+    TAllocator: Allocator,
+{
+    /// Minimal number of items of a non-root leaf.
+    const MIN_LEAF_LEN: usize = NODE_CAPACITY / 2;
+
+    /// Minimal number of keys of a non-root internal node. This is what
+    /// the smaller half of a split internal node gets.
+    const MIN_INTERNAL_LEN: usize = (NODE_CAPACITY - 1) / 2;
+
+    /// Removes the item pointed by `leaf_item` from the tree and returns it.
+    /// After the call `leaf_item` points to the item that followed the removed
+    /// one, or is null if there is no such item.
+    ///
+    /// # Notes
+    ///
+    /// Underfull leaves borrow an item from a sibling, or are merged with
+    /// one, and this is propagated up through the internal nodes. If the root
+    /// leaf becomes empty, then it is deallocated and the tree becomes empty.
+    ///
+    /// # Safety
+    ///
+    /// `leaf_item` has to point to an existing item of this tree.
+    pub(super) unsafe fn remove_leaf_item(
+        &mut self,
+        leaf_item: &mut LeafItem<NODE_CAPACITY, TKey, TValue>,
+    ) -> (TKey, TValue) {
+        debug_assert!(!leaf_item.is_null(), "The leaf item is null.");
+        let leaf_ptr = leaf_item.node;
+        let leaf = unsafe { &mut *leaf_ptr };
+        let index = leaf_item.index as usize;
+        let key = helpers::remove_from_position(leaf.data_mut().keys_mut(), index);
+        let value = helpers::remove_from_position(leaf.values_mut(), index);
+        self.len -= 1;
+
+        let leaf_len = leaf.data().keys().len().value() as usize;
+        if index >= leaf_len {
+            let next = leaf.get_next();
+            *leaf_item = if next.is_null() {
+                LeafItem::null()
+            } else {
+                LeafItem { node: next, index: 0 }
+            };
+        }
+
+        if leaf.data().get_parent().is_null() {
+            if leaf_len == 0 {
+                helpers::deallocate_recursive(&mut self.root, &mut self.allocator);
+                self.root = NodeTaggedPtr::null();
+            }
+        } else if leaf_len < Self::MIN_LEAF_LEN {
+            unsafe { self.rebalance_leaf(leaf_ptr, leaf_item) };
+        }
+
+        (key, value)
+    }
+
+    /// Restores the minimal length of the underfull non-root `leaf_ptr`,
+    /// either by borrowing an item from a sibling, or by merging with it.
+    /// `successor` is kept pointing at the same item.
+    unsafe fn rebalance_leaf(
+        &mut self,
+        leaf_ptr: *mut LeafNode<NODE_CAPACITY, TKey, TValue>,
+        successor: &mut LeafItem<NODE_CAPACITY, TKey, TValue>,
+    ) {
+        let leaf = unsafe { &mut *leaf_ptr };
+        let parent_ptr = leaf.data().get_parent();
+        let parent = unsafe { &mut *parent_ptr };
+        let position = helpers::child_position(parent, leaf.data());
+        let leaf_len = leaf.data().keys().len().value() as usize;
+        let keys_len = parent.data().keys().len().value() as usize;
+
+        // Siblings under the same parent are also neighbours on the leaf list.
+        if position > 0 {
+            let left = unsafe { &mut *leaf.get_prev() };
+            if left.data().keys().len().value() as usize > Self::MIN_LEAF_LEN {
+                let key = left.data_mut().keys_mut().pop().unwrap();
+                let value = left.values_mut().pop().unwrap();
+                helpers::insert_at_position(leaf.data_mut().keys_mut(), 0, key);
+                helpers::insert_at_position(leaf.values_mut(), 0, value);
+                let left_keys = left.data().keys();
+                parent.data_mut().keys_mut()[position - 1] = left_keys[left_keys.len().value() as usize - 1].clone();
+                if successor.node == leaf_ptr {
+                    successor.index += 1;
+                }
+                return;
+            }
+        }
+
+        if position < keys_len {
+            let right_ptr = leaf.get_next();
+            let right = unsafe { &mut *right_ptr };
+            if right.data().keys().len().value() as usize > Self::MIN_LEAF_LEN {
+                let key = helpers::remove_from_position(right.data_mut().keys_mut(), 0);
+                let value = helpers::remove_from_position(right.values_mut(), 0);
+                parent.data_mut().keys_mut()[position] = key.clone();
+                leaf.data_mut().keys_mut().push(key).unwrap();
+                leaf.values_mut().push(value).unwrap();
+                if successor.node == right_ptr {
+                    *successor = if successor.index == 0 {
+                        LeafItem {
+                            node: leaf_ptr,
+                            index: leaf_len as i32,
+                        }
+                    } else {
+                        LeafItem {
+                            node: right_ptr,
+                            index: successor.index - 1,
+                        }
+                    };
+                }
+                return;
+            }
+        }
+
+        let (left_ptr, right_ptr, separator_index) = if position > 0 {
+            (leaf.get_prev(), leaf_ptr, position - 1)
+        } else {
+            (leaf_ptr, leaf.get_next(), position)
+        };
+        if successor.node == right_ptr {
+            let left_len = unsafe { &*left_ptr }.data().keys().len().value();
+            *successor = LeafItem {
+                node: left_ptr,
+                index: left_len + successor.index,
+            };
+        }
+        unsafe { self.merge_leaves(left_ptr, right_ptr, separator_index) };
+    }
+
+    /// Moves all items of `right_ptr` to its left sibling `left_ptr`,
+    /// and removes `right_ptr` together with its separator from the parent.
+    unsafe fn merge_leaves(
+        &mut self,
+        left_ptr: *mut LeafNode<NODE_CAPACITY, TKey, TValue>,
+        right_ptr: *mut LeafNode<NODE_CAPACITY, TKey, TValue>,
+        separator_index: usize,
+    ) {
+        let left = unsafe { &mut *left_ptr };
+        let right = unsafe { &mut *right_ptr };
+        let count = right.data().keys().len().value() as usize;
+        helpers::move_tail(right.data_mut().keys_mut(), left.data_mut().keys_mut(), count);
+        helpers::move_tail(right.values_mut(), left.values_mut(), count);
+
+        let next = right.get_next();
+        if !next.is_null() {
+            unsafe { (*next).set_prev(left_ptr) };
+        }
+        left.set_next(next);
+
+        let parent_ptr = left.data().get_parent();
+        let parent = unsafe { &mut *parent_ptr };
+        drop(helpers::remove_from_position(
+            parent.data_mut().keys_mut(),
+            separator_index,
+        ));
+        let mut right_edge = helpers::remove_from_position(parent.edges_mut(), separator_index + 1);
+        helpers::deallocate_recursive(&mut right_edge, &mut self.allocator);
+        unsafe { self.rebalance_internal(parent_ptr) };
+    }
+
+    /// Restores the minimal length of `node_ptr` after it lost a key, either
+    /// by rotating a key through the parent from a sibling, or by merging with
+    /// it. A root left with a single edge is replaced by that edge.
+    unsafe fn rebalance_internal(&mut self, node_ptr: *mut InternalNode<NODE_CAPACITY, TKey, TValue>) {
+        let node = unsafe { &mut *node_ptr };
+        let parent_ptr = node.data().get_parent();
+        if parent_ptr.is_null() {
+            if node.data().keys().is_empty() {
+                let mut child = node.edges_mut().pop().unwrap();
+                child.node_data_mut().set_parent(core::ptr::null_mut());
+                let mut old_root = core::mem::replace(&mut self.root, child);
+                helpers::deallocate_recursive(&mut old_root, &mut self.allocator);
+            }
+            return;
+        }
+
+        if node.data().keys().len().value() as usize >= Self::MIN_INTERNAL_LEN {
+            return;
+        }
+
+        let parent = unsafe { &mut *parent_ptr };
+        let position = helpers::child_position(parent, node.data());
+        let keys_len = parent.data().keys().len().value() as usize;
+
+        if position > 0 {
+            let left = unsafe { &mut *parent.edges()[position - 1].as_internal_ptr() };
+            if left.data().keys().len().value() as usize > Self::MIN_INTERNAL_LEN {
+                let left_key = left.data_mut().keys_mut().pop().unwrap();
+                let separator = core::mem::replace(&mut parent.data_mut().keys_mut()[position - 1], left_key);
+                let mut edge = left.edges_mut().pop().unwrap();
+                edge.node_data_mut().set_parent(node_ptr);
+                helpers::insert_at_position(node.data_mut().keys_mut(), 0, separator);
+                helpers::insert_at_position(node.edges_mut(), 0, edge);
+                return;
+            }
+        }
+
+        if position < keys_len {
+            let right = unsafe { &mut *parent.edges()[position + 1].as_internal_ptr() };
+            if right.data().keys().len().value() as usize > Self::MIN_INTERNAL_LEN {
+                let right_key = helpers::remove_from_position(right.data_mut().keys_mut(), 0);
+                let separator = core::mem::replace(&mut parent.data_mut().keys_mut()[position], right_key);
+                let mut edge = helpers::remove_from_position(right.edges_mut(), 0);
+                edge.node_data_mut().set_parent(node_ptr);
+                node.data_mut().keys_mut().push(separator).unwrap();
+                node.edges_mut().push(edge).unwrap();
+                return;
+            }
+        }
+
+        let separator_index = if position > 0 { position - 1 } else { position };
+        self.merge_internals(parent, separator_index);
+        unsafe { self.rebalance_internal(parent_ptr) };
+    }
+
+    /// Merges the children of `parent` at `separator_index` and `separator_index + 1`,
+    /// pulling the separator between them down into the merged node.
+    fn merge_internals(&mut self, parent: &mut InternalNode<NODE_CAPACITY, TKey, TValue>, separator_index: usize) {
+        let separator = helpers::remove_from_position(parent.data_mut().keys_mut(), separator_index);
+        let mut right_edge = helpers::remove_from_position(parent.edges_mut(), separator_index + 1);
+        let left_ptr = parent.edges()[separator_index].as_internal_ptr();
+        let left = unsafe { &mut *left_ptr };
+        let right = unsafe { right_edge.as_internal_mut() };
+
+        left.data_mut().keys_mut().push(separator).unwrap();
+        let keys_count = right.data().keys().len().value() as usize;
+        helpers::move_tail(right.data_mut().keys_mut(), left.data_mut().keys_mut(), keys_count);
+        for edge in right.edges_mut().iter_mut() {
+            edge.node_data_mut().set_parent(left_ptr);
+        }
+        let edges_count = right.edges().len().value() as usize;
+        helpers::move_tail(right.edges_mut(), left.edges_mut(), edges_count);
+        helpers::deallocate_recursive(&mut right_edge, &mut self.allocator);
+    }
+}
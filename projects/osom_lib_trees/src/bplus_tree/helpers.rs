@@ -1,33 +1,22 @@
 use core::ptr::NonNull;
 
-use osom_lib_alloc::Allocator;
+use osom_lib_alloc::{AllocationError, Allocator};
+use osom_lib_arrays::{DoubleFixedArray, FixedArray};
 
-use crate::{bplus_tree::nodes::NodeTaggedPtr, traits::Compare};
+use crate::{
+    bplus_tree::nodes::{InternalNode, LeafNode, NodeData, NodeTaggedPtr},
+    traits::Compare,
+};
 
+/// Returns the index of the first key that is greater or equal to `key`,
+/// or the length of the array if there is no such key.
 #[allow(clippy::cast_possible_truncation, clippy::cast_possible_wrap)]
-pub fn scan_node<TKey, K>(key: &K, array: impl AsRef<[TKey]>) -> i32
+pub fn scan_node<TKey, K: ?Sized>(key: &K, array: impl AsRef<[TKey]>) -> i32
 where
     TKey: Compare<K>,
 {
     let array = array.as_ref();
-    let (start, end) = {
-        let middle = array.len() / 2;
-        if array[middle].is_less_or_equal(key) {
-            (middle, array.len() - 1)
-        } else {
-            (0, middle - 1)
-        }
-    };
-
-    let mut idx = start;
-    while idx <= end {
-        if array[idx].is_greater_or_equal(key) {
-            return idx as i32;
-        }
-        idx += 1;
-    }
-
-    idx as i32
+    array.partition_point(|item| item.is_less(key)) as i32
 }
 
 pub fn deallocate_recursive<TKey, TValue, TAllocator, const NODE_CAPACITY: usize>(
@@ -56,6 +45,113 @@ pub fn deallocate_recursive<TKey, TValue, TAllocator, const NODE_CAPACITY: usize
     }
 }
 
+/// Same as [`deallocate_recursive`] but deallocates internal nodes only,
+/// and leaves leaves untouched.
+pub fn deallocate_internal_recursive<TKey, TValue, TAllocator, const NODE_CAPACITY: usize>(
+    node_tagged_ptr: &mut NodeTaggedPtr<NODE_CAPACITY, TKey, TValue>,
+    allocator: &mut TAllocator,
+) where
+    TAllocator: Allocator,
+{
+    if node_tagged_ptr.is_null() || node_tagged_ptr.is_leaf() {
+        return;
+    }
+
+    let internal = unsafe { node_tagged_ptr.as_internal_mut() };
+    for edge in internal.edges_mut().as_mut_slice() {
+        deallocate_internal_recursive(edge, allocator);
+    }
+    unsafe { core::ptr::drop_in_place(internal) };
+    let ptr = unsafe { NonNull::new_unchecked(core::ptr::from_mut(internal)) };
+    unsafe { allocator.deallocate_for_type(ptr) };
+}
+
+/// Deeply clones the subtree rooted at `node_tagged_ptr`, allocating the new
+/// nodes with `allocator`. The cloned nodes get `parent` as their parent, and
+/// cloned leaves are linked to each other in order, starting after `prev_leaf`.
+///
+/// # Errors
+///
+/// Returns [`AllocationError`] if any allocation fails. In that case all
+/// nodes allocated by this call are deallocated.
+pub fn clone_recursive<TKey, TValue, TAllocator, const NODE_CAPACITY: usize>(
+    node_tagged_ptr: &NodeTaggedPtr<NODE_CAPACITY, TKey, TValue>,
+    allocator: &mut TAllocator,
+    parent: *mut InternalNode<NODE_CAPACITY, TKey, TValue>,
+    prev_leaf: &mut *mut LeafNode<NODE_CAPACITY, TKey, TValue>,
+) -> Result<NodeTaggedPtr<NODE_CAPACITY, TKey, TValue>, AllocationError>
+where
+    TKey: Clone,
+    TValue: Clone,
+    TAllocator: Allocator,
+{
+    if node_tagged_ptr.is_null() {
+        return Ok(NodeTaggedPtr::null());
+    }
+
+    if node_tagged_ptr.is_leaf() {
+        let source = unsafe { node_tagged_ptr.as_leaf() };
+        let mut leaf = LeafNode::new();
+        leaf.data_mut().set_parent(parent);
+        leaf.data_mut()
+            .keys_mut()
+            .extend_from_slice(source.data().keys())
+            .unwrap();
+        leaf.values_mut().extend_from_slice(source.values()).unwrap();
+        leaf.set_prev(*prev_leaf);
+        let new_ptr = NodeTaggedPtr::box_leaf(allocator, leaf)?;
+        let new_leaf = new_ptr.as_leaf_ptr();
+        if !prev_leaf.is_null() {
+            unsafe { (**prev_leaf).set_next(new_leaf) };
+        }
+        *prev_leaf = new_leaf;
+        return Ok(new_ptr);
+    }
+
+    let source = unsafe { node_tagged_ptr.as_internal() };
+    let mut internal = InternalNode::new();
+    internal.data_mut().set_parent(parent);
+    internal
+        .data_mut()
+        .keys_mut()
+        .extend_from_slice(source.data().keys())
+        .unwrap();
+    let mut new_ptr = NodeTaggedPtr::box_internal(allocator, internal)?;
+    let new_internal = new_ptr.as_internal_ptr();
+    for edge in source.edges().as_slice() {
+        match clone_recursive(edge, allocator, new_internal, prev_leaf) {
+            Ok(new_edge) => unsafe { (*new_internal).edges_mut().push(new_edge).unwrap() },
+            Err(error) => {
+                deallocate_recursive(&mut new_ptr, allocator);
+                return Err(error);
+            }
+        }
+    }
+    Ok(new_ptr)
+}
+
+/// Counts the nodes of the subtree rooted at `node_tagged_ptr`, and
+/// returns them as `(internal_nodes, leaf_nodes)`.
+pub fn count_nodes<TKey, TValue, const NODE_CAPACITY: usize>(
+    node_tagged_ptr: &NodeTaggedPtr<NODE_CAPACITY, TKey, TValue>,
+) -> (usize, usize) {
+    if node_tagged_ptr.is_null() {
+        return (0, 0);
+    }
+
+    if node_tagged_ptr.is_leaf() {
+        return (0, 1);
+    }
+
+    let internal = unsafe { node_tagged_ptr.as_internal() };
+    internal.edges().as_slice().iter().map(count_nodes).fold(
+        (1, 0),
+        |(internal_count, leaf_count), (edge_internal_count, edge_leaf_count)| {
+            (internal_count + edge_internal_count, leaf_count + edge_leaf_count)
+        },
+    )
+}
+
 pub fn move_last_into_order<T>(arr: &mut [T]) -> usize
 where
     T: Ord,
@@ -97,3 +193,118 @@ pub fn move_last_into_position<T>(arr: &mut [T], position: usize) {
         raw_ptr.add(position).write(data);
     }
 }
+
+/// Common interface of the fixed size arrays stored in nodes, so that
+/// keys, values and edges can be shuffled between nodes the same way.
+pub trait NodeArray<T> {
+    fn node_len(&self) -> usize;
+
+    /// # Panics
+    ///
+    /// When the array is full.
+    fn push_item(&mut self, item: T);
+
+    /// # Panics
+    ///
+    /// When the array is empty.
+    fn pop_item(&mut self) -> T;
+
+    fn items_mut(&mut self) -> &mut [T];
+}
+
+impl<T, const N: usize> NodeArray<T> for FixedArray<T, N> {
+    #[inline(always)]
+    fn node_len(&self) -> usize {
+        self.len().into()
+    }
+
+    #[inline(always)]
+    fn push_item(&mut self, item: T) {
+        self.push(item).unwrap();
+    }
+
+    #[inline(always)]
+    fn pop_item(&mut self) -> T {
+        self.pop().unwrap()
+    }
+
+    #[inline(always)]
+    fn items_mut(&mut self) -> &mut [T] {
+        self.as_mut_slice()
+    }
+}
+
+impl<T, const N: usize, const M: usize> NodeArray<T> for DoubleFixedArray<T, N, M> {
+    #[inline(always)]
+    fn node_len(&self) -> usize {
+        self.len().into()
+    }
+
+    #[inline(always)]
+    fn push_item(&mut self, item: T) {
+        self.push(item).unwrap();
+    }
+
+    #[inline(always)]
+    fn pop_item(&mut self) -> T {
+        self.pop().unwrap()
+    }
+
+    #[inline(always)]
+    fn items_mut(&mut self) -> &mut [T] {
+        self.as_mut_slice()
+    }
+}
+
+/// Inserts `item` at `position` of `arr`, shifting all items after it
+/// to the right.
+///
+/// # Panics
+///
+/// When `arr` is full or `position` is out of bounds.
+pub fn insert_at_position<T>(arr: &mut impl NodeArray<T>, position: usize, item: T) {
+    arr.push_item(item);
+    move_last_into_position(arr.items_mut(), position);
+}
+
+/// Removes the item at `position` from `arr`, shifting all items after it
+/// to the left, and returns it.
+///
+/// # Panics
+///
+/// When `position` is out of bounds.
+pub fn remove_from_position<T>(arr: &mut impl NodeArray<T>, position: usize) -> T {
+    arr.items_mut()[position..].rotate_left(1);
+    arr.pop_item()
+}
+
+/// Moves the last `count` items of `from` to the end of `to`, keeping
+/// their order.
+///
+/// # Panics
+///
+/// When `from` has less than `count` items or `to` has not enough space.
+pub fn move_tail<T>(from: &mut impl NodeArray<T>, to: &mut impl NodeArray<T>, count: usize) {
+    let to_len = to.node_len();
+    for _ in 0..count {
+        to.push_item(from.pop_item());
+    }
+    to.items_mut()[to_len..].reverse();
+}
+
+/// Returns the index of the edge of `parent` that points to the node
+/// with `child_data`.
+///
+/// # Panics
+///
+/// When `child_data` does not belong to a child of `parent`.
+pub fn child_position<TKey, TValue, const NODE_CAPACITY: usize>(
+    parent: &InternalNode<NODE_CAPACITY, TKey, TValue>,
+    child_data: *const NodeData<NODE_CAPACITY, TKey, TValue>,
+) -> usize {
+    parent
+        .edges()
+        .iter()
+        .position(|edge| core::ptr::eq(edge.node_data_ptr(), child_data))
+        .expect("The node is not a child of its parent.")
+}
@@ -6,4 +6,14 @@ mod operation_results;
 
 mod bplus_tree;
 mod bplus_tree_insert;
+mod bplus_tree_remove;
 pub use bplus_tree::*;
+
+mod bplus_tree_into_iter;
+pub use bplus_tree_into_iter::*;
+
+mod bplus_tree_extract_if;
+pub use bplus_tree_extract_if::*;
+
+mod bplus_tree_cursor;
+pub use bplus_tree_cursor::*;
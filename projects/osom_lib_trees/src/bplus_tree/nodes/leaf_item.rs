@@ -29,14 +29,7 @@ impl<const N: usize, TKey, TValue> LeafItem<N, TKey, TValue> {
 
     #[inline(always)]
     pub const unsafe fn key_ptr(&self) -> *mut TKey {
-        let leaf = unsafe { &mut *self.node };
-        unsafe {
-            leaf.data_mut()
-                .keys_mut()
-                .as_mut_slice()
-                .as_mut_ptr()
-                .add(self.index as usize)
-        }
+        unsafe { LeafNode::key_ptr(self.node, self.index as usize) }
     }
 
     #[inline(always)]
@@ -53,8 +46,7 @@ impl<const N: usize, TKey, TValue> LeafItem<N, TKey, TValue> {
 
     #[inline(always)]
     pub const unsafe fn value_ptr(&self) -> *mut TValue {
-        let leaf = unsafe { &mut *self.node };
-        unsafe { leaf.values_mut().as_mut_slice().as_mut_ptr().add(self.index as usize) }
+        unsafe { LeafNode::value_ptr(self.node, self.index as usize) }
     }
 
     #[inline(always)]
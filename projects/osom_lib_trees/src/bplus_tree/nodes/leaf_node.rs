@@ -60,4 +60,28 @@ impl<const N: usize, TKey, TValue> LeafNode<N, TKey, TValue> {
     pub const fn set_prev(&mut self, prev: *mut LeafNode<N, TKey, TValue>) {
         self.prev = prev;
     }
+
+    /// Returns a raw pointer to the key at `index` of the leaf pointed by `this`.
+    /// Only the keys are borrowed, and so the pointer stays valid alongside
+    /// the one returned by [`Self::value_ptr`].
+    ///
+    /// # Safety
+    ///
+    /// `this` has to point to a valid leaf, and `index` has to be less than its capacity.
+    #[inline(always)]
+    pub const unsafe fn key_ptr(this: *mut Self, index: usize) -> *mut TKey {
+        unsafe { (*this).data.keys_mut().as_mut_slice().as_mut_ptr().add(index) }
+    }
+
+    /// Returns a raw pointer to the value at `index` of the leaf pointed by `this`.
+    /// Only the values are borrowed, and so the pointer stays valid alongside
+    /// the one returned by [`Self::key_ptr`].
+    ///
+    /// # Safety
+    ///
+    /// `this` has to point to a valid leaf, and `index` has to be less than its capacity.
+    #[inline(always)]
+    pub const unsafe fn value_ptr(this: *mut Self, index: usize) -> *mut TValue {
+        unsafe { (*this).values.as_mut_slice().as_mut_ptr().add(index) }
+    }
 }
@@ -143,6 +143,30 @@ impl<const N: usize, TKey, TValue> NodeTaggedPtr<N, TKey, TValue> {
         unsafe { &mut *(self.raw_ptr().cast()) }
     }
 
+    /// Turns [`NodeTaggedPtr`] into a raw pointer to a [`LeafNode`]. Unlike
+    /// [`Self::as_leaf_mut()`] it doesn't create a reference, and so the pointer
+    /// stays valid when stored in other nodes, e.g. as a sibling link.
+    ///
+    /// # Notes
+    ///
+    /// The pointer is meaningful only if [`Self::is_leaf()`] returns `true`.
+    #[inline(always)]
+    pub const fn as_leaf_ptr(&self) -> *mut LeafNode<N, TKey, TValue> {
+        self.raw_ptr().cast()
+    }
+
+    /// Turns [`NodeTaggedPtr`] into a raw pointer to an [`InternalNode`]. Unlike
+    /// [`Self::as_internal_mut()`] it doesn't create a reference, and so the pointer
+    /// stays valid when stored in other nodes, e.g. as a parent link.
+    ///
+    /// # Notes
+    ///
+    /// The pointer is meaningful only if [`Self::is_leaf()`] returns `false`.
+    #[inline(always)]
+    pub const fn as_internal_ptr(&self) -> *mut InternalNode<N, TKey, TValue> {
+        self.raw_ptr().cast()
+    }
+
     /// Returns a reference to the [`NodeData`] of the node. This is more efficient
     /// than unwrapping the pointer and taking internally stored [`NodeData`].
     #[inline(always)]
@@ -157,6 +181,14 @@ impl<const N: usize, TKey, TValue> NodeTaggedPtr<N, TKey, TValue> {
         unsafe { &mut *(self.raw_ptr().cast()) }
     }
 
+    /// Returns a raw pointer to the [`NodeData`] of the node. Unlike
+    /// [`Self::node_data()`] it doesn't create a reference, and so it can be
+    /// used for comparisons while the node is mutably borrowed.
+    #[inline(always)]
+    pub const fn node_data_ptr(&self) -> *const NodeData<N, TKey, TValue> {
+        self.raw_ptr().cast_const().cast()
+    }
+
     #[inline(always)]
     const fn raw_ptr(&self) -> *mut () {
         (self.numeric_ptr & !1) as *mut ()
@@ -1,5 +1,22 @@
 /// Represents a comparer for two values.
-pub trait Compare<TOther> {
+///
+/// # Notes
+///
+/// Implementations have to be consistent with the [`Ord`] implementation
+/// of `Self`. That is, for any `a: Self`, `b: Self` and `other: TOther`,
+/// if `a < b` and `b.is_less_or_equal(other)` then `a.is_less(other)`.
+/// Otherwise trees may return incorrect results.
+///
+/// There is a blanket implementation for every `T` that implements
+/// [`Borrow<K>`][core::borrow::Borrow] for an [`Ord`] `K`. In particular
+/// every [`Ord`] type can be compared with itself, `String` keys can
+/// be compared with `str`, and `Vec<u8>` keys with `[u8]`.
+///
+/// This is a breaking change. The blanket implementation used to go the
+/// other way, i.e. every `K: Borrow<T>` for an [`Ord`] `T`, and so keys
+/// could be queried with e.g. `&&TKey` or `&Box<TKey>`. These queries
+/// have to pass `&TKey` now.
+pub trait Compare<TOther: ?Sized> {
     /// Checks if `self < other`.
     fn is_less(&self, other: &TOther) -> bool;
 
@@ -29,14 +46,14 @@ pub trait Compare<TOther> {
 
 impl<T, K> Compare<K> for T
 where
-    T: Ord,
-    K: core::borrow::Borrow<T>,
+    T: core::borrow::Borrow<K>,
+    K: Ord + ?Sized,
 {
     fn is_less(&self, other: &K) -> bool {
-        self < other.borrow()
+        self.borrow() < other
     }
 
     fn is_equal(&self, other: &K) -> bool {
-        self == other.borrow()
+        self.borrow() == other
     }
 }
@@ -13,6 +13,26 @@ pub enum TreeError {
     TreeTooBig,
 }
 
+impl TreeError {
+    /// Returns `true` if the error was caused by the allocator, `false` otherwise.
+    #[inline(always)]
+    #[must_use]
+    pub const fn is_allocation_error(&self) -> bool {
+        matches!(self, TreeError::AllocationError)
+    }
+}
+
+impl core::fmt::Display for TreeError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            TreeError::AllocationError => f.write_str("failed to allocate memory for a tree node"),
+            TreeError::TreeTooBig => f.write_str("tree size exceeds the maximum size"),
+        }
+    }
+}
+
+impl core::error::Error for TreeError {}
+
 impl<T: Sized> From<DetailedAllocationError<T>> for TreeError {
     fn from(_: DetailedAllocationError<T>) -> Self {
         TreeError::AllocationError
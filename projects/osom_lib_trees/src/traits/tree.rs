@@ -18,19 +18,19 @@ pub trait Tree {
     fn try_insert(&mut self, key: Self::TKey, value: Self::TValue) -> Result<TreeTryInsertResult, TreeError>;
 
     /// Searches the tree for the exact match.
-    fn query_exact<K>(&self, key: &K) -> TreeQueryExactResult<'_, Self::TKey, Self::TValue>
+    fn query_exact<K: ?Sized>(&self, key: &K) -> TreeQueryExactResult<'_, Self::TKey, Self::TValue>
     where
         Self::TKey: Compare<K>;
 
     /// The mutable version of [`Tree::query_exact`].
-    fn query_exact_mut<K>(&mut self, key: &K) -> TreeQueryExactMutResult<'_, Self::TKey, Self::TValue>
+    fn query_exact_mut<K: ?Sized>(&mut self, key: &K) -> TreeQueryExactMutResult<'_, Self::TKey, Self::TValue>
     where
         Self::TKey: Compare<K>;
 
     /// Searches the tree for the key-value pairs contained in the passed range.
     ///
     /// The resulting iterator will be in the order specified by `ordering`.
-    fn query_range<K>(
+    fn query_range<K: ?Sized>(
         &self,
         range: impl RangeBounds<K>,
         ordering: Ordering,
@@ -39,7 +39,7 @@ pub trait Tree {
         Self::TKey: Compare<K>;
 
     /// The mutable version of [`Tree::query_range`].
-    fn query_range_mut<K>(
+    fn query_range_mut<K: ?Sized>(
         &mut self,
         range: impl RangeBounds<K>,
         ordering: Ordering,
@@ -1,6 +1,10 @@
 mod common;
 
+use core::ops::{Bound, RangeBounds};
+
 use osom_lib_trees::bplus_tree::StdBPlusTree;
+use osom_lib_trees::traits::{Ordering, Tree, TreeQueryExactMutResult, TreeQueryExactResult};
+use rstest::rstest;
 
 #[test]
 fn test_bplus_tree_int_string_big_capacity() {
@@ -9,8 +13,973 @@ fn test_bplus_tree_int_string_big_capacity() {
 }
 
 #[test]
-#[ignore = "not yet implemented"]
 fn test_bplus_tree_int_string_small_capacity() {
     let tree = StdBPlusTree::<i32, String, 8>::new();
     common::test_tree_int_string(|| tree);
 }
+
+/// Keys `0..count` in a scrambled, but deterministic order.
+fn scrambled_keys(count: i32) -> impl Iterator<Item = i32> {
+    const MULTIPLIER: i64 = 7919;
+    (0..count).map(move |index| ((i64::from(index) * MULTIPLIER) % i64::from(count)) as i32)
+}
+
+fn assert_tree_contains<const NODE_CAPACITY: usize>(tree: &StdBPlusTree<i32, i32, NODE_CAPACITY>, expected: &[i32]) {
+    assert_eq!(tree.len().value() as usize, expected.len());
+    let ascending: Vec<i32> = tree
+        .query_range::<i32>(.., Ordering::Ascending)
+        .map(|pair| *pair.into_tuple().0)
+        .collect();
+    assert_eq!(ascending, expected);
+    let descending: Vec<i32> = tree
+        .query_range::<i32>(.., Ordering::Descending)
+        .map(|pair| *pair.into_tuple().0)
+        .collect();
+    assert!(descending.iter().rev().eq(expected.iter()));
+    for key in expected {
+        assert!(matches!(tree.query_exact(key), TreeQueryExactResult::Found { value, .. } if *value == key * 2));
+    }
+}
+
+#[rstest]
+#[case(100)]
+#[case(1000)]
+fn test_insert_splits_nodes(#[case] count: i32) {
+    fn check<const NODE_CAPACITY: usize>(count: i32) {
+        let mut tree = StdBPlusTree::<i32, i32, NODE_CAPACITY>::new();
+        for key in scrambled_keys(count) {
+            tree.try_insert(key, key * 2).unwrap();
+        }
+
+        let expected: Vec<i32> = (0..count).collect();
+        assert_tree_contains(&tree, &expected);
+        assert!(matches!(tree.query_exact(&count), TreeQueryExactResult::NotFound));
+    }
+
+    check::<4>(count);
+    check::<5>(count);
+    check::<8>(count);
+}
+
+#[test]
+fn test_insert_ascending_and_descending_splits() {
+    let mut ascending = StdBPlusTree::<i32, i32, 4>::new();
+    let mut descending = StdBPlusTree::<i32, i32, 4>::new();
+    for key in 0..200 {
+        ascending.try_insert(key, key * 2).unwrap();
+        descending.try_insert(199 - key, (199 - key) * 2).unwrap();
+    }
+
+    let expected: Vec<i32> = (0..200).collect();
+    assert_tree_contains(&ascending, &expected);
+    assert_tree_contains(&descending, &expected);
+}
+
+#[test]
+fn test_bplus_tree_keys_and_values_mut() {
+    let mut tree = StdBPlusTree::<i32, i32, 64>::new();
+    assert_eq!(tree.keys().count(), 0);
+    assert_eq!(tree.values_mut().count(), 0);
+
+    for key in [5, -3, 12, 0, 7, -8] {
+        tree.try_insert(key, key * 10).unwrap();
+    }
+
+    let keys: Vec<i32> = tree.keys().copied().collect();
+    assert_eq!(keys, [-8, -3, 0, 5, 7, 12]);
+
+    for value in tree.values_mut() {
+        *value *= 2;
+    }
+
+    let values: Vec<i32> = tree
+        .query_range::<i32>(.., Ordering::Ascending)
+        .map(|pair| **pair.value())
+        .collect();
+    assert_eq!(values, [-160, -60, 0, 100, 140, 240]);
+}
+
+#[test]
+fn test_bplus_tree_into_iter() {
+    let empty = StdBPlusTree::<i32, String, 64>::new();
+    assert_eq!(empty.into_iter().count(), 0);
+
+    let mut tree = StdBPlusTree::<i32, String, 64>::new();
+    for key in [5, -3, 12, 0, 7, -8] {
+        tree.try_insert(key, key.to_string()).unwrap();
+    }
+
+    let iter = tree.into_iter();
+    assert_eq!(iter.len(), 6);
+    let pairs: Vec<(i32, String)> = iter.collect();
+    let expected: Vec<(i32, String)> = [-8, -3, 0, 5, 7, 12].into_iter().map(|k| (k, k.to_string())).collect();
+    assert_eq!(pairs, expected);
+}
+
+#[test]
+fn test_bplus_tree_into_iter_partial_drop() {
+    use std::rc::Rc;
+
+    let marker = Rc::new(());
+    let mut tree = StdBPlusTree::<i32, Rc<()>, 64>::new();
+    for key in 0..10 {
+        tree.try_insert(key, marker.clone()).unwrap();
+    }
+    assert_eq!(Rc::strong_count(&marker), 11);
+
+    let mut iter = tree.into_iter();
+    let (key, value) = iter.next().unwrap();
+    assert_eq!(key, 0);
+    assert_eq!(iter.len(), 9);
+    drop(value);
+    assert_eq!(Rc::strong_count(&marker), 10);
+
+    drop(iter);
+    assert_eq!(Rc::strong_count(&marker), 1);
+}
+
+#[test]
+fn test_bplus_tree_into_iter_spanning_many_levels() {
+    let tree = new_scrambled_tree::<4>(500);
+    assert!(tree.height() > 3);
+
+    let iter = tree.into_iter();
+    assert_eq!(iter.len(), 500);
+    assert!(iter.eq((0..500).map(|key| (key, key * 2))));
+}
+
+#[test]
+fn test_bplus_tree_into_iter_partial_drop_spanning_many_levels() {
+    use std::rc::Rc;
+
+    let marker = Rc::new(());
+    let mut tree = StdBPlusTree::<i32, Rc<()>, 4>::new();
+    for key in scrambled_keys(300) {
+        tree.try_insert(key, marker.clone()).unwrap();
+    }
+
+    let mut iter = tree.into_iter();
+    for expected in 0..100 {
+        assert_eq!(iter.next().unwrap().0, expected);
+    }
+    assert_eq!(iter.len(), 200);
+    assert_eq!(Rc::strong_count(&marker), 201);
+
+    drop(iter);
+    assert_eq!(Rc::strong_count(&marker), 1);
+}
+
+fn new_tens_tree() -> StdBPlusTree<i32, i32, 64> {
+    let mut tree = StdBPlusTree::<i32, i32, 64>::new();
+    for key in [50, 0, 90, 30, 10, 70, 20, 80, 40, 60] {
+        tree.try_insert(key, key * 2).unwrap();
+    }
+    tree
+}
+
+#[rstest]
+#[case(Bound::Included(20), Bound::Included(50))]
+#[case(Bound::Excluded(20), Bound::Included(50))]
+#[case(Bound::Included(20), Bound::Excluded(50))]
+#[case(Bound::Excluded(20), Bound::Excluded(50))]
+#[case(Bound::Included(15), Bound::Included(55))]
+#[case(Bound::Excluded(15), Bound::Excluded(55))]
+#[case(Bound::Excluded(15), Bound::Included(50))]
+#[case(Bound::Included(-100), Bound::Included(100))]
+#[case(Bound::Excluded(-100), Bound::Excluded(100))]
+#[case(Bound::Excluded(0), Bound::Excluded(90))]
+#[case(Bound::Included(90), Bound::Unbounded)]
+#[case(Bound::Excluded(90), Bound::Unbounded)]
+#[case(Bound::Unbounded, Bound::Included(0))]
+#[case(Bound::Unbounded, Bound::Excluded(0))]
+#[case(Bound::Unbounded, Bound::Excluded(5))]
+#[case(Bound::Included(95), Bound::Unbounded)]
+#[case(Bound::Included(30), Bound::Included(30))]
+#[case(Bound::Included(30), Bound::Excluded(30))]
+#[case(Bound::Excluded(30), Bound::Included(30))]
+#[case(Bound::Included(31), Bound::Included(39))]
+#[case(Bound::Included(60), Bound::Included(20))]
+#[case(Bound::Unbounded, Bound::Unbounded)]
+fn test_bplus_tree_query_range_bounds(
+    #[case] start: Bound<i32>,
+    #[case] end: Bound<i32>,
+    #[values(Ordering::Ascending, Ordering::Descending)] ordering: Ordering,
+) {
+    let mut tree = new_tens_tree();
+    let mut expected: Vec<i32> = (0..10)
+        .map(|key| key * 10)
+        .filter(|key| (start, end).contains(key))
+        .collect();
+    if ordering == Ordering::Descending {
+        expected.reverse();
+    }
+
+    let keys: Vec<i32> = tree
+        .query_range::<i32>((start, end), ordering)
+        .map(|pair| {
+            let (key, value) = pair.into_tuple();
+            assert_eq!(*value, key * 2);
+            *key
+        })
+        .collect();
+    assert_eq!(keys, expected);
+
+    let keys_mut: Vec<i32> = tree
+        .query_range_mut::<i32>((start, end), ordering)
+        .map(|pair| *pair.into_tuple().0)
+        .collect();
+    assert_eq!(keys_mut, expected);
+}
+
+#[test]
+fn test_bplus_tree_query_range_empty_tree() {
+    let tree = StdBPlusTree::<i32, i32, 64>::new();
+    assert_eq!(tree.query_range::<i32>(.., Ordering::Ascending).count(), 0);
+    assert_eq!(tree.query_range::<i32>(5..10, Ordering::Descending).count(), 0);
+}
+
+#[rstest]
+#[case(-5, None)]
+#[case(0, Some(0))]
+#[case(35, None)]
+#[case(90, Some(180))]
+#[case(95, None)]
+fn test_bplus_tree_query_exact_bounds(#[case] key: i32, #[case] expected: Option<i32>) {
+    let mut tree = new_tens_tree();
+    let found = match tree.query_exact(&key) {
+        TreeQueryExactResult::Found { value, .. } => Some(*value),
+        TreeQueryExactResult::NotFound => None,
+    };
+    assert_eq!(found, expected);
+    let found_mut = match tree.query_exact_mut(&key) {
+        TreeQueryExactMutResult::Found { value, .. } => Some(*value),
+        TreeQueryExactMutResult::NotFound => None,
+    };
+    assert_eq!(found_mut, expected);
+
+    let empty = StdBPlusTree::<i32, i32, 64>::new();
+    assert!(matches!(empty.query_exact(&key), TreeQueryExactResult::NotFound));
+}
+
+#[test]
+fn test_bplus_tree_height_and_node_count() {
+    let mut tree = StdBPlusTree::<i32, i32, 64>::new();
+    assert_eq!(tree.height(), 0);
+    assert_eq!(tree.node_count(), (0, 0));
+
+    for key in 0..10 {
+        tree.try_insert(key, key).unwrap();
+    }
+    assert_eq!(tree.height(), 1);
+    assert_eq!(tree.node_count(), (0, 1));
+}
+
+#[test]
+fn test_bplus_tree_height_and_node_count_spanning_many_levels() {
+    let mut tree = StdBPlusTree::<i32, i32, 4>::new();
+    let mut previous_height = 0;
+    for key in 0..500 {
+        tree.try_insert(key, key).unwrap();
+        let height = tree.height();
+        assert!(height == previous_height || height == previous_height + 1);
+        previous_height = height;
+    }
+
+    let height = tree.height();
+    assert!(height > 3);
+    let (internal_count, leaf_count) = tree.node_count();
+    // Every node except the root is an edge of exactly one internal node,
+    // which has between 2 and 5 edges.
+    let edge_count = internal_count + leaf_count - 1;
+    assert!(edge_count >= 2 * internal_count);
+    assert!(edge_count <= 5 * internal_count);
+    assert!(leaf_count >= 500 / 4);
+    assert!(leaf_count >= 1 << (height - 1));
+    assert!(leaf_count <= 5usize.pow(height as u32 - 1));
+
+    assert_eq!(tree.remove_range(..), 500);
+    assert_eq!(tree.height(), 0);
+    assert_eq!(tree.node_count(), (0, 0));
+}
+
+#[test]
+fn test_bplus_tree_borrowed_key_lookups() {
+    let mut strings = StdBPlusTree::<String, i32, 64>::new();
+    strings.try_insert("beta".to_string(), 2).unwrap();
+    strings.try_insert("alpha".to_string(), 1).unwrap();
+    assert!(matches!(
+        strings.query_exact("alpha"),
+        TreeQueryExactResult::Found { value: 1, .. }
+    ));
+    assert!(matches!(strings.query_exact("gamma"), TreeQueryExactResult::NotFound));
+    let keys: Vec<&String> = strings
+        .query_range::<str>(
+            (Bound::Included("alpha"), Bound::Excluded("gamma")),
+            Ordering::Descending,
+        )
+        .map(|pair| pair.into_tuple().0)
+        .collect();
+    assert_eq!(keys, ["beta", "alpha"]);
+
+    let mut vectors = StdBPlusTree::<Vec<u8>, i32, 64>::new();
+    vectors.try_insert(b"abc".to_vec(), 3).unwrap();
+    assert!(matches!(
+        vectors.query_exact(&b"abc"[..]),
+        TreeQueryExactResult::Found { value: 3, .. }
+    ));
+    assert!(matches!(
+        vectors.query_exact(&b"abd"[..]),
+        TreeQueryExactResult::NotFound
+    ));
+}
+
+#[test]
+fn test_bplus_tree_immutable_key_lookups() {
+    use osom_lib_arrays::StdDynamicArray;
+    use osom_lib_strings::StdImmutableString;
+
+    let mut immutable_strings = StdBPlusTree::<StdImmutableString, i32, 64>::new();
+    immutable_strings
+        .try_insert(StdImmutableString::new("key").unwrap(), 7)
+        .unwrap();
+    assert!(matches!(
+        immutable_strings.query_exact("key"),
+        TreeQueryExactResult::Found { value: 7, .. }
+    ));
+    assert!(matches!(
+        immutable_strings.query_exact("other"),
+        TreeQueryExactResult::NotFound
+    ));
+
+    let mut bytes = StdBPlusTree::<StdDynamicArray<u8>, i32, 64>::new();
+    let mut key = StdDynamicArray::new();
+    key.extend_from_slice(b"abc").unwrap();
+    bytes.try_insert(key, 3).unwrap();
+    assert!(matches!(
+        bytes.query_exact(&b"abc"[..]),
+        TreeQueryExactResult::Found { value: 3, .. }
+    ));
+    assert!(matches!(bytes.query_exact(&b"abd"[..]), TreeQueryExactResult::NotFound));
+}
+
+#[test]
+fn test_tree_error_display() {
+    use osom_lib_trees::traits::TreeError;
+
+    assert_eq!(
+        TreeError::AllocationError.to_string(),
+        "failed to allocate memory for a tree node"
+    );
+    assert_eq!(TreeError::TreeTooBig.to_string(), "tree size exceeds the maximum size");
+}
+
+#[test]
+fn test_tree_error_is_allocation_error() {
+    use osom_lib_trees::traits::TreeError;
+
+    assert!(TreeError::AllocationError.is_allocation_error());
+    assert!(!TreeError::TreeTooBig.is_allocation_error());
+}
+
+#[test]
+fn test_insert_or_replace_inserts_new_keys() {
+    let mut tree = StdBPlusTree::<i32, String, 64>::new();
+    assert_eq!(tree.insert_or_replace(5, "five".to_string()).unwrap(), None);
+    assert_eq!(tree.insert_or_replace(1, "one".to_string()).unwrap(), None);
+    assert_eq!(tree.len().value(), 2);
+    assert!(matches!(tree.query_exact(&1), TreeQueryExactResult::Found { value, .. } if value == "one"));
+    assert_eq!(tree.keys().copied().collect::<Vec<_>>(), [1, 5]);
+}
+
+#[test]
+fn test_insert_or_replace_replaces_existing_value() {
+    let mut tree = new_tens_tree();
+    let old_len = tree.len();
+    assert_eq!(tree.insert_or_replace(30, -30).unwrap(), Some(60));
+    assert_eq!(tree.insert_or_replace(30, -300).unwrap(), Some(-30));
+    assert_eq!(tree.len(), old_len);
+    assert!(matches!(
+        tree.query_exact(&30),
+        TreeQueryExactResult::Found { value: -300, .. }
+    ));
+    assert!(matches!(
+        tree.query_exact(&20),
+        TreeQueryExactResult::Found { value: 40, .. }
+    ));
+}
+
+#[test]
+fn test_update_range() {
+    let mut tree = new_tens_tree();
+    let mut visited = Vec::new();
+    tree.update_range(25..=60, |key, value| {
+        visited.push(*key);
+        *value *= 100;
+    });
+    assert_eq!(visited, [30, 40, 50, 60]);
+
+    let values: Vec<i32> = tree.values_mut().map(|value| *value).collect();
+    assert_eq!(values, [0, 20, 40, 6000, 8000, 10000, 12000, 140, 160, 180]);
+}
+
+#[test]
+fn test_update_range_empty() {
+    let mut tree = new_tens_tree();
+    tree.update_range(41..50, |_, _| panic!("Range should be empty"));
+
+    let mut empty_tree = StdBPlusTree::<i32, i32, 64>::new();
+    empty_tree.update_range(.., |_, _| panic!("Tree should be empty"));
+}
+
+#[test]
+fn test_to_sorted_array() {
+    let tree = new_tens_tree();
+    let array = tree.to_sorted_array().unwrap();
+    assert_eq!(array.len(), tree.len());
+
+    let expected: Vec<(i32, i32)> = tree
+        .query_range(.., Ordering::Ascending)
+        .map(|pair| {
+            let (key, value) = pair.into_tuple();
+            (*key, *value)
+        })
+        .collect();
+    let actual: Vec<(i32, i32)> = array.iter().map(|pair| (*pair.key(), *pair.value())).collect();
+    assert_eq!(actual, expected);
+    assert!(array.is_sorted_by_key(|pair| *pair.key()));
+
+    let empty_tree = StdBPlusTree::<i32, i32, 64>::new();
+    assert!(empty_tree.to_sorted_array().unwrap().is_empty());
+}
+
+#[test]
+fn test_clone_is_independent() {
+    let mut tree = new_tens_tree();
+    let cloned = tree.clone();
+    assert_eq!(cloned.len(), tree.len());
+    assert_eq!(cloned.height(), tree.height());
+    assert_eq!(cloned.node_count(), tree.node_count());
+
+    tree.insert_or_replace(10, -1).unwrap();
+    tree.try_insert(15, 30).unwrap();
+
+    assert_eq!(cloned.len().value(), 10);
+    let keys: Vec<i32> = cloned.keys().copied().collect();
+    assert_eq!(keys, (0..100).step_by(10).collect::<Vec<_>>());
+    let TreeQueryExactResult::Found { value, .. } = cloned.query_exact(&10) else {
+        panic!("Key 10 should be present");
+    };
+    assert_eq!(*value, 20);
+    assert!(matches!(cloned.query_exact(&15), TreeQueryExactResult::NotFound));
+}
+
+#[test]
+fn test_snapshot_of_string_values() {
+    let mut tree = StdBPlusTree::<i32, String, 64>::new();
+    for key in 0..20 {
+        tree.try_insert(key, key.to_string()).unwrap();
+    }
+
+    let snapshot = tree.snapshot().unwrap();
+    tree.values_mut().for_each(String::clear);
+
+    let values: Vec<String> = snapshot
+        .query_range(.., Ordering::Descending)
+        .map(|pair| pair.into_tuple().1.clone())
+        .collect();
+    let expected: Vec<String> = (0..20).rev().map(|key: i32| key.to_string()).collect();
+    assert_eq!(values, expected);
+    assert!(tree.values_mut().all(|value| value.is_empty()));
+
+    let empty_tree = StdBPlusTree::<i32, String, 64>::new();
+    let empty_snapshot = empty_tree.snapshot().unwrap();
+    assert_eq!(empty_snapshot.len().value(), 0);
+    assert_eq!(empty_snapshot.height(), 0);
+}
+
+#[test]
+fn test_get_many_mut() {
+    let mut tree = new_tens_tree();
+    let [first, missing, second] = tree.get_many_mut([&10, &15, &90]);
+    assert!(missing.is_none());
+    let first = first.unwrap();
+    let second = second.unwrap();
+    core::mem::swap(first, second);
+
+    let values: Vec<i32> = tree
+        .query_range(.., Ordering::Ascending)
+        .map(|pair| *pair.into_tuple().1)
+        .collect();
+    assert_eq!(values, [0, 180, 40, 60, 80, 100, 120, 140, 160, 20]);
+}
+
+#[test]
+fn test_get_many_mut_duplicates() {
+    let mut tree = new_tens_tree();
+    let [first, duplicate, other] = tree.get_many_mut([&30, &30, &40]);
+    assert_eq!(first.copied(), Some(60));
+    assert!(duplicate.is_none());
+    assert_eq!(other.copied(), Some(80));
+
+    let mut empty_tree = StdBPlusTree::<i32, i32, 64>::new();
+    assert!(empty_tree.get_many_mut([&1, &2]).iter().all(Option::is_none));
+}
+
+#[test]
+fn test_get_many_mut_zero_sized_values() {
+    let mut tree = StdBPlusTree::<i32, (), 64>::new();
+    for key in [1, 2, 3] {
+        tree.try_insert(key, ()).unwrap();
+    }
+
+    let [first, second, duplicate, missing] = tree.get_many_mut([&1, &3, &1, &4]);
+    assert!(first.is_some());
+    assert!(second.is_some());
+    assert!(duplicate.is_none());
+    assert!(missing.is_none());
+}
+
+#[test]
+fn test_get_many_mut_spanning_many_levels() {
+    let mut tree = new_scrambled_tree::<4>(500);
+    assert!(tree.height() > 3);
+
+    let [first, duplicate, middle, missing, last] = tree.get_many_mut([&0, &0, &250, &500, &499]);
+    assert!(duplicate.is_none());
+    assert!(missing.is_none());
+    let first = first.unwrap();
+    let middle = middle.unwrap();
+    let last = last.unwrap();
+    assert_eq!((*first, *middle, *last), (0, 500, 998));
+    core::mem::swap(first, last);
+    *middle = -1;
+
+    let expected: Vec<i32> = (0..500)
+        .map(|key| match key {
+            0 => 998,
+            250 => -1,
+            499 => 0,
+            _ => key * 2,
+        })
+        .collect();
+    assert!(tree.keys().copied().eq(0..500));
+    assert_eq!(tree.values_mut().map(|value| *value).collect::<Vec<_>>(), expected);
+}
+
+#[test]
+fn test_extract_if() {
+    let mut tree = new_tens_tree();
+    let extracted: Vec<(i32, i32)> = tree.extract_if(|key, _| key % 20 == 0).collect();
+    assert_eq!(extracted, [(0, 0), (20, 40), (40, 80), (60, 120), (80, 160)]);
+    assert_eq!(tree.len().value(), 5);
+
+    let keys: Vec<i32> = tree.keys().copied().collect();
+    assert_eq!(keys, [10, 30, 50, 70, 90]);
+    assert!(matches!(tree.query_exact(&20), TreeQueryExactResult::NotFound));
+}
+
+#[test]
+fn test_extract_if_modifies_kept_values() {
+    let mut tree = new_tens_tree();
+    let extracted: Vec<(i32, i32)> = tree
+        .extract_if(|key, value| {
+            *value += 1;
+            *key >= 50
+        })
+        .collect();
+    assert_eq!(extracted.len(), 5);
+    assert!(extracted.iter().all(|(key, value)| *key >= 50 && *value == key * 2 + 1));
+
+    let values: Vec<i32> = tree
+        .query_range(.., Ordering::Ascending)
+        .map(|pair| *pair.into_tuple().1)
+        .collect();
+    assert_eq!(values, [1, 21, 41, 61, 81]);
+}
+
+#[test]
+fn test_extract_if_everything() {
+    let mut tree = StdBPlusTree::<i32, String, 64>::new();
+    for key in 0..10 {
+        tree.try_insert(key, key.to_string()).unwrap();
+    }
+
+    let extracted: Vec<String> = tree.extract_if(|_, _| true).map(|(_, value)| value).collect();
+    assert_eq!(extracted.len(), 10);
+    assert_eq!(tree.len().value(), 0);
+    assert_eq!(tree.height(), 0);
+    assert_eq!(tree.keys().count(), 0);
+
+    tree.try_insert(5, "five".to_string()).unwrap();
+    assert_eq!(tree.keys().copied().collect::<Vec<_>>(), [5]);
+}
+
+#[test]
+fn test_extract_if_partial_consumption() {
+    let mut tree = new_tens_tree();
+    let first_two: Vec<(i32, i32)> = tree.extract_if(|key, _| *key > 20).take(2).collect();
+    assert_eq!(first_two, [(30, 60), (40, 80)]);
+
+    let keys: Vec<i32> = tree.keys().copied().collect();
+    assert_eq!(keys, [0, 10, 20, 50, 60, 70, 80, 90]);
+    assert_eq!(tree.len().value(), 8);
+}
+
+fn new_scrambled_tree<const NODE_CAPACITY: usize>(count: i32) -> StdBPlusTree<i32, i32, NODE_CAPACITY> {
+    let mut tree = StdBPlusTree::<i32, i32, NODE_CAPACITY>::new();
+    for key in scrambled_keys(count) {
+        tree.try_insert(key, key * 2).unwrap();
+    }
+    tree
+}
+
+#[rstest]
+#[case(2)]
+#[case(3)]
+#[case(7)]
+fn test_extract_if_spanning_many_leaves(#[case] modulus: i32) {
+    fn check<const NODE_CAPACITY: usize>(modulus: i32) {
+        const COUNT: i32 = 500;
+        let mut tree = new_scrambled_tree::<NODE_CAPACITY>(COUNT);
+        let (_, initial_leaf_count) = tree.node_count();
+        assert!(initial_leaf_count > 1);
+
+        let extracted: Vec<(i32, i32)> = tree.extract_if(|key, _| key % modulus != 0).collect();
+        let expected_extracted: Vec<(i32, i32)> = (0..COUNT)
+            .filter(|key| key % modulus != 0)
+            .map(|key| (key, key * 2))
+            .collect();
+        assert_eq!(extracted, expected_extracted);
+
+        let expected: Vec<i32> = (0..COUNT).filter(|key| key % modulus == 0).collect();
+        assert_tree_contains(&tree, &expected);
+        assert!(tree.node_count().1 < initial_leaf_count);
+
+        // The tree stays fully usable after rebalancing.
+        for key in (0..COUNT).filter(|key| key % modulus != 0) {
+            tree.try_insert(key, key * 2).unwrap();
+        }
+        assert_tree_contains(&tree, &(0..COUNT).collect::<Vec<_>>());
+
+        let everything: Vec<(i32, i32)> = tree.extract_if(|_, _| true).collect();
+        assert_eq!(everything.len(), COUNT as usize);
+        assert_eq!(tree.len().value(), 0);
+        assert_eq!(tree.height(), 0);
+        assert_eq!(tree.node_count(), (0, 0));
+    }
+
+    check::<4>(modulus);
+    check::<5>(modulus);
+    check::<8>(modulus);
+    check::<16>(modulus);
+}
+
+#[test]
+fn test_extract_if_spanning_many_leaves_drops() {
+    use std::rc::Rc;
+
+    let marker = Rc::new(());
+    let mut tree = StdBPlusTree::<i32, Rc<()>, 4>::new();
+    for key in scrambled_keys(300) {
+        tree.try_insert(key, marker.clone()).unwrap();
+    }
+
+    let mut iter = tree.extract_if(|key, _| key % 3 == 0);
+    for _ in 0..50 {
+        drop(iter.next().unwrap());
+    }
+    drop(iter);
+    assert_eq!(Rc::strong_count(&marker), 251);
+    assert_eq!(tree.len().value(), 250);
+    assert_eq!(tree.keys().filter(|key| *key % 3 == 0).count(), 50);
+
+    drop(tree);
+    assert_eq!(Rc::strong_count(&marker), 1);
+}
+
+#[test]
+fn test_cursor_at() {
+    let tree = new_tens_tree();
+
+    let cursor = tree.cursor_at(&25);
+    assert_eq!(cursor.key(), Some(&30));
+    assert_eq!(cursor.value(), Some(&60));
+
+    let cursor = tree.cursor_at(&30);
+    assert_eq!(cursor.key(), Some(&30));
+
+    let cursor = tree.cursor_at(&91);
+    assert_eq!(cursor.key(), None);
+    assert_eq!(cursor.value(), None);
+}
+
+#[test]
+fn test_cursor_moves() {
+    let tree = new_tens_tree();
+    let mut cursor = tree.cursor_at(&80);
+
+    cursor.move_next();
+    assert_eq!(cursor.key(), Some(&90));
+    cursor.move_next();
+    assert_eq!(cursor.key(), None);
+    cursor.move_next();
+    assert_eq!(cursor.key(), Some(&0));
+    cursor.move_prev();
+    assert_eq!(cursor.key(), None);
+    cursor.move_prev();
+    assert_eq!(cursor.key(), Some(&90));
+    cursor.move_prev();
+    assert_eq!(cursor.key(), Some(&80));
+}
+
+#[test]
+fn test_cursor_lockstep_merge_join() {
+    let left = new_tens_tree();
+    let mut right = StdBPlusTree::<i32, i32, 64>::new();
+    for key in (0..100).step_by(15) {
+        right.insert_or_replace(key, key).unwrap();
+    }
+
+    let mut left_cursor = left.cursor_at(&0);
+    let mut right_cursor = right.cursor_at(&0);
+    let mut common = Vec::new();
+    while let (Some(left_key), Some(right_key)) = (left_cursor.key(), right_cursor.key()) {
+        match left_key.cmp(right_key) {
+            core::cmp::Ordering::Less => left_cursor.move_next(),
+            core::cmp::Ordering::Greater => right_cursor.move_next(),
+            core::cmp::Ordering::Equal => {
+                common.push(*left_key);
+                left_cursor.move_next();
+                right_cursor.move_next();
+            }
+        }
+    }
+    assert_eq!(common, vec![0, 30, 60, 90]);
+}
+
+#[test]
+fn test_cursor_empty_tree() {
+    let tree = StdBPlusTree::<i32, i32, 64>::new();
+    let mut cursor = tree.cursor_at(&0);
+    assert_eq!(cursor.key(), None);
+    cursor.move_next();
+    assert_eq!(cursor.key(), None);
+    cursor.move_prev();
+    assert_eq!(cursor.value(), None);
+}
+
+#[test]
+fn test_cursor_spanning_many_levels() {
+    let tree = new_scrambled_tree::<4>(500);
+    assert!(tree.height() > 3);
+
+    let cursor = tree.cursor_at(&250);
+    assert_eq!(cursor.key(), Some(&250));
+    assert_eq!(cursor.value(), Some(&500));
+
+    // Walks across every leaf boundary in both directions.
+    let mut cursor = tree.cursor_at(&0);
+    for expected in 0..500 {
+        assert_eq!(cursor.key(), Some(&expected));
+        cursor.move_next();
+    }
+    assert_eq!(cursor.key(), None);
+    for expected in (0..500).rev() {
+        cursor.move_prev();
+        assert_eq!(cursor.key(), Some(&expected));
+    }
+    cursor.move_prev();
+    assert_eq!(cursor.key(), None);
+
+    let mut tree = new_scrambled_tree::<4>(1000);
+    assert_eq!(tree.remove_range(100..900), 800);
+    let mut cursor = tree.cursor_at(&50);
+    for _ in 0..50 {
+        cursor.move_next();
+    }
+    assert_eq!(cursor.key(), Some(&900));
+    cursor.move_prev();
+    assert_eq!(cursor.key(), Some(&99));
+    assert_eq!(tree.cursor_at(&100).key(), Some(&900));
+}
+
+#[rstest]
+#[case(20..50, Ordering::Ascending, vec![(20, 40), (30, 60), (40, 80)])]
+#[case(20..50, Ordering::Descending, vec![(40, 80), (30, 60), (20, 40)])]
+#[case(85..200, Ordering::Ascending, vec![(90, 180)])]
+#[case(91..200, Ordering::Ascending, vec![])]
+fn test_range_cloned(
+    #[case] range: core::ops::Range<i32>,
+    #[case] ordering: Ordering,
+    #[case] expected: Vec<(i32, i32)>,
+) {
+    let mut tree = new_tens_tree();
+    let cloned: Vec<_> = tree.range_cloned(range, ordering).collect();
+    assert_eq!(cloned, expected);
+
+    // The clones are independent of the tree.
+    tree.insert_or_replace(20, -1).unwrap();
+    assert!(cloned.iter().all(|(key, value)| *value == key * 2));
+}
+
+#[rstest]
+#[case(100..400, Ordering::Ascending)]
+#[case(100..400, Ordering::Descending)]
+#[case(0..500, Ordering::Ascending)]
+#[case(0..500, Ordering::Descending)]
+#[case(137..138, Ordering::Ascending)]
+fn test_range_cloned_spanning_many_levels(#[case] range: core::ops::Range<i32>, #[case] ordering: Ordering) {
+    let tree = new_scrambled_tree::<4>(500);
+    assert!(tree.height() > 3);
+
+    let cloned: Vec<(i32, i32)> = tree.range_cloned(range.clone(), ordering).collect();
+    let mut expected: Vec<(i32, i32)> = range.map(|key| (key, key * 2)).collect();
+    if matches!(ordering, Ordering::Descending) {
+        expected.reverse();
+    }
+    assert_eq!(cloned, expected);
+}
+
+#[rstest]
+#[case(20..50, 3, vec![0, 10, 50, 60, 70, 80, 90])]
+#[case(..=10, 2, vec![20, 30, 40, 50, 60, 70, 80, 90])]
+#[case(85.., 1, vec![0, 10, 20, 30, 40, 50, 60, 70, 80])]
+#[case(41..49, 0, vec![0, 10, 20, 30, 40, 50, 60, 70, 80, 90])]
+#[case(.., 10, vec![])]
+fn test_remove_range(#[case] range: impl RangeBounds<i32>, #[case] expected_count: usize, #[case] expected: Vec<i32>) {
+    let mut tree = new_tens_tree();
+    assert_eq!(tree.remove_range(range), expected_count);
+    assert_eq!(tree.len().value() as usize, expected.len());
+    assert_eq!(tree.keys().copied().collect::<Vec<_>>(), expected);
+    for key in expected {
+        assert!(matches!(tree.query_exact(&key), TreeQueryExactResult::Found { value, .. } if *value == key * 2));
+    }
+}
+
+#[test]
+fn test_remove_range_empty_tree() {
+    let mut tree = StdBPlusTree::<i32, i32, 64>::new();
+    assert_eq!(tree.remove_range(..), 0);
+    tree.insert_or_replace(1, 1).unwrap();
+    assert_eq!(tree.remove_range(..), 1);
+    assert_eq!(tree.len().value(), 0);
+    assert_eq!(tree.height(), 0);
+}
+
+#[test]
+fn test_eq_differently_built_trees() {
+    let tree = new_tens_tree();
+
+    let mut reversed = StdBPlusTree::<i32, i32, 16>::new();
+    for key in (0..100).step_by(10).rev() {
+        reversed.insert_or_replace(key, key * 2).unwrap();
+    }
+    assert!(tree == reversed);
+    assert!(reversed == tree);
+
+    let mut rebuilt = StdBPlusTree::<i32, i32, 64>::new();
+    for key in (0..100).step_by(5) {
+        rebuilt.insert_or_replace(key, key * 2).unwrap();
+    }
+    assert!(tree != rebuilt);
+    assert_eq!(rebuilt.remove_range(0..100), 20);
+    assert!(tree != rebuilt);
+    for key in (0..100).step_by(10) {
+        rebuilt.insert_or_replace(key, key * 2).unwrap();
+    }
+    assert!(tree == rebuilt);
+    assert!(tree == tree.clone());
+}
+
+#[test]
+fn test_eq_different_values() {
+    let tree = new_tens_tree();
+    let mut other = tree.clone();
+    other.insert_or_replace(50, 0).unwrap();
+    assert!(tree != other);
+
+    let empty = StdBPlusTree::<i32, i32, 64>::new();
+    assert!(empty == StdBPlusTree::<i32, i32, 8>::new());
+    assert!(tree != empty);
+}
+
+#[test]
+fn test_eq_spanning_many_levels() {
+    let tree = new_scrambled_tree::<4>(500);
+    assert!(tree.height() > 3);
+
+    // Same pairs, but a different shape and node capacity.
+    let mut ascending = StdBPlusTree::<i32, i32, 16>::new();
+    for key in 0..500 {
+        ascending.insert_or_replace(key, key * 2).unwrap();
+    }
+    assert!(tree == ascending);
+    assert!(ascending == tree);
+    assert!(tree == tree.try_clone().unwrap());
+
+    let mut other = new_scrambled_tree::<4>(500);
+    other.insert_or_replace(499, 0).unwrap();
+    assert!(tree != other);
+
+    let mut other = new_scrambled_tree::<4>(1000);
+    assert!(tree != other);
+    assert_eq!(other.remove_range(500..), 500);
+    assert!(tree == other);
+}
+
+#[rstest]
+#[case(100..400)]
+#[case(0..250)]
+#[case(250..500)]
+#[case(1..499)]
+#[case(137..138)]
+fn test_remove_range_spanning_many_leaves(#[case] range: core::ops::Range<i32>) {
+    fn check<const NODE_CAPACITY: usize>(range: core::ops::Range<i32>) {
+        const COUNT: i32 = 500;
+        let mut tree = new_scrambled_tree::<NODE_CAPACITY>(COUNT);
+        let (_, initial_leaf_count) = tree.node_count();
+        assert!(initial_leaf_count > 1);
+
+        assert_eq!(tree.remove_range(range.clone()), range.len());
+        let expected: Vec<i32> = (0..COUNT).filter(|key| !range.contains(key)).collect();
+        assert_tree_contains(&tree, &expected);
+        assert_eq!(tree.remove_range(range.clone()), 0);
+
+        // The tree stays fully usable after rebalancing.
+        for key in range.clone() {
+            tree.try_insert(key, key * 2).unwrap();
+        }
+        assert_tree_contains(&tree, &(0..COUNT).collect::<Vec<_>>());
+
+        assert_eq!(tree.remove_range(..), COUNT as usize);
+        assert_eq!(tree.len().value(), 0);
+        assert_eq!(tree.height(), 0);
+        assert_eq!(tree.node_count(), (0, 0));
+    }
+
+    check::<4>(range.clone());
+    check::<5>(range.clone());
+    check::<8>(range.clone());
+    check::<16>(range);
+}
+
+#[test]
+fn test_remove_range_in_chunks_shrinks_tree() {
+    let mut tree = new_scrambled_tree::<4>(1000);
+    let initial_height = tree.height();
+    for start in (0..1000).step_by(50) {
+        assert_eq!(tree.remove_range(start..start + 25), 25);
+    }
+
+    let expected: Vec<i32> = (0..1000).filter(|key| key % 50 >= 25).collect();
+    assert_tree_contains(&tree, &expected);
+    for start in (0..1000).step_by(50) {
+        assert_eq!(tree.remove_range(start + 25..start + 45), 20);
+    }
+
+    let expected: Vec<i32> = (0..1000).filter(|key| key % 50 >= 45).collect();
+    assert_tree_contains(&tree, &expected);
+    assert_eq!(tree.remove_range(..990), 95);
+    assert_tree_contains(&tree, &[995, 996, 997, 998, 999]);
+    assert!(tree.height() < initial_height);
+}